@@ -0,0 +1,408 @@
+//! Generate keyboard layout lookup tables from the layout definitions in
+//! `layouts/*.kbd` (see "Keyboard Layouts" in README.md for the format)
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+/// Directory of layout definitions
+const LAYOUTS_DIR: &str = "layouts";
+
+/// Keys in each layer, in key index order (must match kbd::MAP_SIZE). This
+/// is also the source of scancode::Key, so hosts share the same key names.
+const KEY_NAMES: [&str; 54] = [
+    "P2", "P5", "PC", "P6", "P3", "P4", "P9", "P7", "P8", "P13", "P14", "P15", "P16", "P17", "P18",
+    "P19", "P20", "P21", "P22", "P23", "P24", "P25", "P26", "P27", "P28", "P29", "P30", "P31",
+    "P32", "P33", "P34", "P35", "P36", "P37", "P38", "P39", "P40", "P41", "P42", "P43", "P44",
+    "P45", "P46", "P47", "P48", "P49", "P50", "P51", "P52", "P53", "P54", "P55", "P56", "P57",
+];
+
+/// Keystroke results other than characters (kbd::R variants)
+const ACTIONS: [&str; 23] = [
+    "Nop", "Up", "Left", "Click", "Right", "Down", "Home", "End", "F1", "F2", "F3", "F4", "Undo",
+    "Redo", "Compose", "Bksp", "Delete", "Enter", "AltL", "Symbol", "Emoji", "AltR", "Shift",
+];
+
+/// Modifier key states (kbd::ModKey variants)
+const MODKEYS: [&str; 5] = ["Base", "Shift", "AltL", "AltR", "AltRS"];
+
+/// Keystroke results that change the modifier key state
+const MODIFIER_KEYS: [&str; 3] = ["AltL", "AltR", "Shift"];
+
+/// What tapping a modifier key does (kbd::Latch variants)
+const LATCHES: [&str; 2] = ["Toggle", "OneShot"];
+
+/// Modifier key press rule: press `key` in state `from` (None for any state)
+/// to go to state `to`
+struct Press {
+    key: String,
+    from: Option<String>,
+    to: String,
+}
+
+/// Layer of key results (Rust expressions for kbd::R values)
+struct Layer {
+    name: String,
+    keys: Vec<String>,
+}
+
+/// Parsed layout definition
+struct Layout {
+    name: String,
+    modkeys: Vec<(String, String)>,
+    presses: Vec<Press>,
+    latches: Vec<(String, String)>,
+    layers: Vec<Layer>,
+}
+
+impl Layout {
+    /// Name of the kbd::Map variant for a layer (the base layer takes the
+    /// layout's name)
+    fn map_variant(&self, layer: &str) -> String {
+        match layer {
+            "Base" => self.name.clone(),
+            _ => format!("{}{}", self.name, layer),
+        }
+    }
+
+    /// Name of the lookup table constant for a layer
+    fn lut_name(&self, layer: &str) -> String {
+        format!("MAP_{}_{}", self.name.to_uppercase(), layer.to_uppercase())
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", LAYOUTS_DIR);
+    let mut paths: Vec<PathBuf> = fs::read_dir(LAYOUTS_DIR)
+        .unwrap_or_else(|e| fail(LAYOUTS_DIR, 0, &e.to_string()))
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|ext| ext == "kbd"))
+        .collect();
+    paths.sort();
+    let mut layouts: Vec<Layout> = Vec::new();
+    for path in paths.iter() {
+        println!("cargo:rerun-if-changed={}", path.display());
+        let layout = parse(path);
+        if layouts.iter().any(|lo| lo.name == layout.name) {
+            let msg = format!("layout {} is defined more than once", layout.name);
+            fail(&path.display().to_string(), 0, &msg);
+        }
+        layouts.push(layout);
+    }
+    if layouts.is_empty() {
+        fail(LAYOUTS_DIR, 0, "no .kbd layout definitions");
+    }
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("layouts.rs"), generate(&layouts)).unwrap();
+    fs::write(Path::new(&out_dir).join("keys.rs"), generate_keys()).unwrap();
+}
+
+/// Generate the Rust source for the physical key enum and its tables
+fn generate_keys() -> String {
+    let mut s = String::new();
+    let _ = writeln!(s, "// Generated by build.rs from KEY_NAMES\n");
+    let _ = writeln!(
+        s,
+        "/// Physical keys, with the key index as discriminant (see layouts/*.kbd for"
+    );
+    let _ = writeln!(s, "/// what each key types)");
+    let _ = writeln!(s, "#[derive(Copy, Clone, Debug, PartialEq)]");
+    let variants: Vec<String> = KEY_NAMES
+        .iter()
+        .enumerate()
+        .map(|(i, n)| format!("{} = {}", n, i))
+        .collect();
+    let _ = writeln!(s, "pub enum Key {{ {} }}\n", variants.join(", "));
+    let _ = writeln!(s, "/// Keys in key index order");
+    let keys: Vec<String> = KEY_NAMES.iter().map(|n| format!("Key::{}", n)).collect();
+    let _ = writeln!(
+        s,
+        "pub const KEYS: [Key; kbd::MAP_SIZE] = [{}];\n",
+        keys.join(", ")
+    );
+    let _ = writeln!(s, "/// Key names in key index order, as used in scancodes");
+    let quoted: Vec<String> = KEY_NAMES.iter().map(|n| format!("{:?}", n)).collect();
+    let _ = writeln!(
+        s,
+        "pub const KEY_NAMES: [&str; kbd::MAP_SIZE] = [{}];",
+        quoted.join(", ")
+    );
+    s
+}
+
+/// Stop the build with an error message that points at a layout file line
+fn fail(file: &str, line: usize, msg: &str) -> ! {
+    match line {
+        0 => eprintln!("error: {}: {}", file, msg),
+        _ => eprintln!("error: {}:{}: {}", file, line, msg),
+    }
+    process::exit(1);
+}
+
+/// Check that a name is a valid Rust identifier in CamelCase
+fn is_camel_ident(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(|c| c.is_ascii_uppercase()) && chars.all(|c| c.is_ascii_alphanumeric())
+}
+
+/// Convert a layer token to a kbd::R expression
+fn key_result(tok: &str) -> Result<String, String> {
+    if ACTIONS.contains(&tok) {
+        return Ok(format!("R::{}", tok));
+    }
+    if tok == "Space" {
+        return Ok("R::C(' ')".into());
+    }
+    if let Some(hex) = tok.strip_prefix("U+") {
+        return match u32::from_str_radix(hex, 16).ok().and_then(char::from_u32) {
+            Some(c) => Ok(format!("R::C({:?})", c)),
+            None => Err(format!("bad code point {}", tok)),
+        };
+    }
+    if let Some(accent) = tok.strip_prefix("Dead") {
+        let mut chars = accent.chars();
+        return match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(format!("R::Dead({:?})", c)),
+            _ => Err(format!("dead key needs one accent character: {}", tok)),
+        };
+    }
+    let mut chars = tok.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(format!("R::C({:?})", c)),
+        _ => Err(format!("unknown key result {}", tok)),
+    }
+}
+
+/// Parse a layout definition file
+fn parse(path: &Path) -> Layout {
+    let file = path.display().to_string();
+    let text = fs::read_to_string(path).unwrap_or_else(|e| fail(&file, 0, &e.to_string()));
+    let mut lo = Layout {
+        name: String::new(),
+        modkeys: Vec::new(),
+        presses: Vec::new(),
+        latches: Vec::new(),
+        layers: Vec::new(),
+    };
+    // Layer being read, and the line number where it started
+    let mut layer: Option<(Layer, usize)> = None;
+    for (i, line) in text.lines().enumerate() {
+        let n = i + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with("//") {
+            continue;
+        }
+        let words: Vec<&str> = line.split_whitespace().collect();
+        if let Some((ref mut l, start)) = layer {
+            if words == ["end"] {
+                if l.keys.len() != KEY_NAMES.len() {
+                    let msg = format!(
+                        "layer {} has {} keys (expected {})",
+                        l.name,
+                        l.keys.len(),
+                        KEY_NAMES.len()
+                    );
+                    fail(&file, start, &msg);
+                }
+                lo.layers.push(layer.take().unwrap().0);
+                continue;
+            }
+            for w in words {
+                let r = key_result(w).unwrap_or_else(|msg| fail(&file, n, &msg));
+                l.keys.push(r);
+            }
+            continue;
+        }
+        match words[..] {
+            ["layout", name] if is_camel_ident(name) && lo.name.is_empty() => {
+                lo.name = name.into();
+            }
+            ["modkey", state, name] if MODKEYS.contains(&state) && is_camel_ident(name) => {
+                if lo.modkeys.iter().any(|(s, _)| s == state) {
+                    let msg = format!("modkey {} is given more than once", state);
+                    fail(&file, n, &msg);
+                }
+                lo.modkeys.push((state.into(), name.into()));
+            }
+            ["press", key, from, to] if MODIFIER_KEYS.contains(&key) && MODKEYS.contains(&to) => {
+                let from = match from {
+                    "*" => None,
+                    s if MODKEYS.contains(&s) => Some(String::from(s)),
+                    s => fail(&file, n, &format!("unknown modkey state {}", s)),
+                };
+                // An earlier rule for any state would hide this one
+                if lo.presses.iter().any(|p| p.key == key && p.from.is_none()) {
+                    fail(&file, n, "press rule follows a * rule for the same key");
+                }
+                lo.presses.push(Press {
+                    key: key.into(),
+                    from,
+                    to: to.into(),
+                });
+            }
+            ["latch", key, latch] if MODIFIER_KEYS.contains(&key) && LATCHES.contains(&latch) => {
+                if lo.latches.iter().any(|(k, _)| k == key) {
+                    let msg = format!("latch {} is given more than once", key);
+                    fail(&file, n, &msg);
+                }
+                lo.latches.push((key.into(), latch.into()));
+            }
+            ["layer", name] if is_camel_ident(name) => {
+                if lo.layers.iter().any(|l| l.name == name) {
+                    let msg = format!("layer {} is defined more than once", name);
+                    fail(&file, n, &msg);
+                }
+                let l = Layer {
+                    name: name.into(),
+                    keys: Vec::new(),
+                };
+                layer = Some((l, n));
+            }
+            _ => fail(&file, n, &format!("cannot parse: {}", line)),
+        }
+    }
+    if let Some((l, start)) = layer {
+        fail(&file, start, &format!("layer {} has no end", l.name));
+    }
+    if lo.name.is_empty() {
+        fail(&file, 0, "missing layout name");
+    }
+    for state in MODKEYS.iter() {
+        match lo.modkeys.iter().find(|(s, _)| s == state) {
+            Some((_, name)) if !lo.layers.iter().any(|l| &l.name == name) => {
+                let msg = format!("modkey {} uses undefined layer {}", state, name);
+                fail(&file, 0, &msg);
+            }
+            Some(_) => (),
+            None => fail(&file, 0, &format!("missing modkey {}", state)),
+        }
+    }
+    lo
+}
+
+/// Generate Rust source for the layout enums, lookup tables, and the
+/// functions that kbd.rs uses to pick a table and handle modifier keys
+fn generate(layouts: &[Layout]) -> String {
+    let mut s = String::new();
+    let names: Vec<&str> = layouts.iter().map(|lo| lo.name.as_str()).collect();
+    let _ = writeln!(s, "// Generated by build.rs from {}/*.kbd\n", LAYOUTS_DIR);
+    let _ = writeln!(
+        s,
+        "/// Possible keyboard layouts (relates to labels on physical keys)"
+    );
+    let _ = writeln!(s, "#[derive(Copy, Clone, PartialEq)]");
+    let _ = writeln!(s, "pub enum Layout {{ {} }}\n", names.join(", "));
+    let _ = writeln!(s, "/// Keyboard layouts in definition order");
+    let variants: Vec<String> = names.iter().map(|n| format!("Layout::{}", n)).collect();
+    let _ = writeln!(
+        s,
+        "pub const LAYOUTS: [Layout; {}] = [{}];\n",
+        names.len(),
+        variants.join(", ")
+    );
+    let _ = writeln!(s, "/// Keyboard layout names, indexed like LAYOUTS");
+    let quoted: Vec<String> = names.iter().map(|n| format!("{:?}", n)).collect();
+    let _ = writeln!(
+        s,
+        "pub const LAYOUT_NAMES: [&str; {}] = [{}];\n",
+        names.len(),
+        quoted.join(", ")
+    );
+
+    // One Map variant and lookup table per layer
+    let _ = writeln!(
+        s,
+        "/// Possible mappings of keystroke to resulting character or action"
+    );
+    let _ = writeln!(s, "pub enum Map {{");
+    let mut index = 0;
+    for lo in layouts {
+        for l in lo.layers.iter() {
+            let _ = writeln!(s, "    {} = {},", lo.map_variant(&l.name), index);
+            index += 1;
+        }
+    }
+    let _ = writeln!(s, "}}\n");
+    for lo in layouts {
+        for l in lo.layers.iter() {
+            let _ = writeln!(s, "/// Keyboard map for {} layer {}", lo.name, l.name);
+            let _ = writeln!(s, "pub const {}: MapResultLUT = [", lo.lut_name(&l.name));
+            for (r, key) in l.keys.iter().zip(KEY_NAMES.iter()) {
+                let _ = writeln!(s, "    {}, // {}", r, key);
+            }
+            let _ = writeln!(s, "];\n");
+        }
+    }
+
+    // Modifier key state to layer
+    let _ = writeln!(s, "/// Map for a layout in a modifier key state");
+    let _ = writeln!(s, "fn layout_map(lo: Layout, mk: ModKey) -> Map {{");
+    let _ = writeln!(s, "    match (lo, mk) {{");
+    for lo in layouts {
+        for (state, layer) in lo.modkeys.iter() {
+            let _ = writeln!(
+                s,
+                "        (Layout::{}, ModKey::{}) => Map::{},",
+                lo.name,
+                state,
+                lo.map_variant(layer)
+            );
+        }
+    }
+    let _ = writeln!(s, "    }}\n}}\n");
+
+    // Layer to lookup table
+    let _ = writeln!(s, "/// Lookup table for a map");
+    let _ = writeln!(s, "fn map_lut(m: Map) -> &'static MapResultLUT {{");
+    let _ = writeln!(s, "    match m {{");
+    for lo in layouts {
+        for l in lo.layers.iter() {
+            let variant = lo.map_variant(&l.name);
+            let _ = writeln!(s, "        Map::{} => &{},", variant, lo.lut_name(&l.name));
+        }
+    }
+    let _ = writeln!(s, "    }}\n}}\n");
+
+    // Modifier key press rules
+    let _ = writeln!(s, "/// Modifier key state after pressing a modifier key");
+    let _ = writeln!(
+        s,
+        "fn modkey_after(lo: Layout, mk: ModKey, r: &R) -> ModKey {{"
+    );
+    let _ = writeln!(s, "    match lo {{");
+    for lo in layouts {
+        let _ = writeln!(s, "        Layout::{} => match (r, mk) {{", lo.name);
+        for p in lo.presses.iter() {
+            let from = match &p.from {
+                Some(state) => format!("ModKey::{}", state),
+                None => "_".into(),
+            };
+            let _ = writeln!(
+                s,
+                "            (R::{}, {}) => ModKey::{},",
+                p.key, from, p.to
+            );
+        }
+        let _ = writeln!(s, "            _ => mk,\n        }},");
+    }
+    let _ = writeln!(s, "    }}\n}}\n");
+
+    // Modifier key latch policies (Toggle unless given)
+    let _ = writeln!(s, "/// What tapping a modifier key does in a layout");
+    let _ = writeln!(s, "fn modkey_latch(lo: Layout, r: &R) -> Latch {{");
+    let _ = writeln!(s, "    match (lo, r) {{");
+    for lo in layouts {
+        for (key, latch) in lo.latches.iter() {
+            let _ = writeln!(
+                s,
+                "        (Layout::{}, R::{}) => Latch::{},",
+                lo.name, key, latch
+            );
+        }
+    }
+    let _ = writeln!(s, "        _ => Latch::Toggle,\n    }}\n}}");
+    s
+}
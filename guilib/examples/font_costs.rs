@@ -0,0 +1,17 @@
+//! Report the flash footprint of each font and Unicode block in this build.
+//! Usage: cargo run --example font_costs [--no-default-features --features ...]
+use guilib::api::FONT_COSTS;
+
+fn main() {
+    let mut total = 0;
+    for font in FONT_COSTS.iter() {
+        let state = if font.enabled { "" } else { " (disabled)" };
+        println!("{}{}: {} bytes", font.font, state, font.bytes());
+        for b in font.blocks.iter() {
+            let mark = if font.enabled && b.enabled { "+" } else { "-" };
+            println!("  {} {:<20} {:>6} bytes", mark, b.block, b.bytes);
+        }
+        total += font.bytes();
+    }
+    println!("Total: {} bytes", total);
+}
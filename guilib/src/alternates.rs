@@ -0,0 +1,218 @@
+//! Long-press alternates: holding a letter key pops up a strip of accented
+//! and related characters to replace the one it typed
+
+use super::kbd;
+use super::nav;
+use super::softkeys::{Action, SoftKey, SoftKeys, NONE};
+use super::state::{self, View};
+
+/// Ticks that a key must be held to open the alternates strip (see
+/// api::tick for the tick rate)
+pub const LONG_PRESS_TICKS: usize = 5;
+
+/// Alternates for characters that have them, in strip order
+const ALTERNATES: [(char, &[char]); 34] = [
+    ('a', &['à', 'á', 'â', 'ä', 'æ', 'ã', 'å', 'ª']),
+    ('c', &['ç', '©', '¢']),
+    ('e', &['é', 'è', 'ê', 'ë', '€']),
+    ('i', &['î', 'ï', 'í', 'ì']),
+    ('n', &['ñ']),
+    ('o', &['ô', 'ö', 'ó', 'ò', 'œ', 'õ', 'ø', 'º']),
+    ('s', &['ß', '§']),
+    ('u', &['ù', 'û', 'ü', 'ú']),
+    ('y', &['ÿ', 'ý', '¥']),
+    ('A', &['À', 'Á', 'Â', 'Ä', 'Æ', 'Ã', 'Å']),
+    ('C', &['Ç']),
+    ('E', &['É', 'È', 'Ê', 'Ë']),
+    ('I', &['Î', 'Ï', 'Í', 'Ì']),
+    ('N', &['Ñ']),
+    ('O', &['Ô', 'Ö', 'Ó', 'Ò', 'Œ', 'Õ', 'Ø']),
+    ('U', &['Ù', 'Û', 'Ü', 'Ú']),
+    ('Y', &['Ÿ', 'Ý']),
+    ('0', &['°']),
+    ('1', &['¹', '½', '¼']),
+    ('2', &['²']),
+    ('3', &['³', '¾']),
+    ('!', &['¡']),
+    ('?', &['¿']),
+    ('.', &['·', '•']),
+    ('-', &['±', '¬']),
+    ('*', &['×', '†', '‡']),
+    ('/', &['÷']),
+    ('$', &['€', '£', '¥', '¢', '¤']),
+    ('"', &['“', '”', '„', '«', '»']),
+    ('\'', &['‘', '’', '‚']),
+    ('<', &['«']),
+    ('>', &['»']),
+    ('%', &['‰']),
+    ('&', &['¶']),
+];
+
+/// Key being held, the character it typed, and the open alternates strip
+pub struct AlternatesState {
+    /// Key index and character of the held key, ticks it has been held, and
+    /// the text cursor from before the key typed its character
+    pub held: Option<(usize, char)>,
+    pub ticks: usize,
+    pub cursor: usize,
+    /// Alternates strip for the held key (open while the view is active),
+    /// and whether the held key's character is just before the text cursor
+    /// so that an alternate should replace it
+    pub key: usize,
+    pub base: char,
+    pub typed: bool,
+    pub chars: &'static [char],
+    pub selected: usize,
+}
+impl AlternatesState {
+    /// Initialize with no key held and no strip open
+    pub const fn new() -> AlternatesState {
+        AlternatesState {
+            held: None,
+            ticks: 0,
+            cursor: 0,
+            key: 0,
+            base: ' ',
+            typed: false,
+            chars: &[],
+            selected: 0,
+        }
+    }
+}
+
+/// Look up the alternates for a character
+pub fn lookup(c: char) -> Option<&'static [char]> {
+    let found = ALTERNATES.iter().find(|(k, _)| *k == c);
+    found.map(|(_, alts)| *alts)
+}
+
+/// Start timing a key press that typed c on the home screen (only keys
+/// with alternates can be long-pressed)
+pub fn key_down(ctx: &mut state::Context, key_index: usize, r: &kbd::R) {
+    ctx.alternates.held = match r {
+        kbd::R::C(c) if ctx.views.active() == View::Home && lookup(*c).is_some() => {
+            Some((key_index, *c))
+        }
+        _ => None,
+    };
+    ctx.alternates.ticks = 0;
+    ctx.alternates.cursor = ctx.text.cursor();
+}
+
+/// Stop timing a key press
+pub fn key_up(ctx: &mut state::Context, key_index: usize) {
+    if let Some((k, _)) = ctx.alternates.held {
+        if k == key_index {
+            ctx.alternates.held = None;
+        }
+    }
+}
+
+/// Count a tick for the held key, opening its alternates strip once it has
+/// been held long enough.
+/// Return: true if the strip opened (needs repaint)
+pub fn tick(ctx: &mut state::Context) -> bool {
+    let (key, base) = match ctx.alternates.held {
+        Some(held) => held,
+        None => return false,
+    };
+    ctx.alternates.ticks += 1;
+    if ctx.alternates.ticks < LONG_PRESS_TICKS {
+        return false;
+    }
+    ctx.alternates.held = None;
+    let chars = match lookup(base) {
+        Some(chars) => chars,
+        None => return false,
+    };
+    if ctx.views.active() != View::Home || !ctx.views.push(View::Alternates) {
+        return false;
+    }
+    ctx.alternates.key = key;
+    ctx.alternates.base = base;
+    ctx.alternates.typed = typed(ctx, base);
+    ctx.alternates.chars = chars;
+    ctx.alternates.selected = 0;
+    true
+}
+
+/// Check if the text from the cursor position saved at key down up to the
+/// text cursor is just the character c (the key typed it, and nothing
+/// moved the cursor since)
+fn typed(ctx: &state::Context, c: char) -> bool {
+    let start = ctx.alternates.cursor;
+    let s = ctx.text.as_str();
+    let typed = s.get(start..ctx.text.cursor());
+    typed.and_then(|t| t.strip_prefix(c)) == Some("")
+}
+
+/// Close the strip, leaving the character that the key typed
+pub fn close(ctx: &mut state::Context) -> bool {
+    ctx.alternates.chars = &[];
+    ctx.views.pop()
+}
+
+/// Close the strip, replacing the character that the key typed with the
+/// selected alternate (or inserting the alternate if the key typed nothing)
+pub fn accept(ctx: &mut state::Context) -> bool {
+    let c = match ctx.alternates.chars.get(ctx.alternates.selected) {
+        Some(c) => *c,
+        None => return close(ctx),
+    };
+    close(ctx);
+    if ctx.alternates.typed {
+        ctx.text.backspace();
+    }
+    ctx.text.insert(c);
+    true
+}
+
+/// Title of the alternates view
+pub fn title(_ctx: &state::Context) -> &str {
+    "alternates"
+}
+
+/// Soft keys for the alternates view
+static SOFT_KEYS: SoftKeys = [
+    SoftKey {
+        label: "cancel",
+        action: Action::Run(close),
+    },
+    NONE,
+    NONE,
+    SoftKey {
+        label: "insert",
+        action: Action::Run(accept),
+    },
+];
+
+/// Soft keys of the alternates view
+pub fn soft_keys(_ctx: &state::Context) -> &'static SoftKeys {
+    &SOFT_KEYS
+}
+
+/// Handle a keystroke while the alternates strip is open. Left and Right
+/// (or the long-pressed key again) move through the alternates, and Click
+/// or Enter picks one. Any other character picks the selected alternate and
+/// is then typed as usual.
+/// Return: true if the keystroke was used (needs repaint)
+pub fn handle_key(ctx: &mut state::Context, r: &kbd::R) -> bool {
+    let n = ctx.alternates.chars.len();
+    let i = ctx.alternates.selected;
+    if n == 0 {
+        return false;
+    }
+    match r {
+        kbd::R::Left => ctx.alternates.selected = (i + n - 1) % n,
+        kbd::R::Right => ctx.alternates.selected = (i + 1) % n,
+        kbd::R::C(c) if *c == ctx.alternates.base => ctx.alternates.selected = (i + 1) % n,
+        kbd::R::Click | kbd::R::Enter => return accept(ctx),
+        kbd::R::Bksp => return close(ctx),
+        kbd::R::C(_) => {
+            accept(ctx);
+            (nav::active(ctx).handle_key)(ctx, r);
+        }
+        _ => return false,
+    }
+    true
+}
@@ -1,5 +1,5 @@
 use super::fonts;
-use super::fonts::{Font, GlyphHeader};
+use super::fonts::{Font, GlyphBuf, GlyphHeader};
 
 /// LCD Frame buffer bounds
 pub const LCD_WORDS_PER_LINE: usize = 11;
@@ -52,14 +52,49 @@ pub fn string_small_left(fb: &mut LcdFB, mut cr: ClipRegion, s: &str) {
     }
 }
 
+/// Blit string with: XOR, any font, align xr left yr top. Chars that would
+/// not fit before cr.x1 are left out.
+pub fn string_left(fb: &mut LcdFB, mut cr: ClipRegion, s: &str, f: Font) {
+    for c in s.chars() {
+        if cr.x0 + char_width(c, f) + 3 > cr.x1 {
+            break;
+        }
+        cr.x0 += xor_char(fb, cr, c, f);
+    }
+}
+
+/// Blit string with: XOR, mono font, align xr left yr top. Every char
+/// advances by the fixed cell width so that text lines up in columns. Chars
+/// whose cell (after xor_char's 1px left pad) would not fit before cr.x1
+/// are left out. Without the mono feature, this draws proportional regular
+/// text like string_left.
+pub fn string_mono_left(fb: &mut LcdFB, mut cr: ClipRegion, s: &str) {
+    let f = Font::new(fonts::GlyphSet::Mono);
+    let cell_w = match f.cell {
+        Some(cell) => cell.w,
+        None => return string_left(fb, cr, s, f),
+    };
+    for c in s.chars() {
+        if cr.x0 + 1 + cell_w > cr.x1 {
+            break;
+        }
+        xor_char(fb, cr, c, f);
+        cr.x0 += cell_w;
+    }
+}
+
 /// Calculate the width of all glpyhs and padding for a string
+/// (cell width times number of chars for monospace fonts)
 pub fn string_width(s: &str, f: Font) -> usize {
+    if let Some(cell) = f.cell {
+        return s.chars().count() * cell.w;
+    }
     let mut w = 0;
     for c in s.chars() {
         w += char_width(c, f) + 3;
     }
     // Subtle padding math: 3px between chars, 1px at left and right ends
-    w - 1
+    w.saturating_sub(1)
 }
 
 /// Blit a char with: XOR, align left:xr.0 top:yr.0, pad L:1px R:2px
@@ -96,6 +131,11 @@ pub fn xor_char(fb: &mut LcdFB, cr: ClipRegion, c: char, f: Font) -> usize {
     if gh.w > 32 {
         return 0;
     }
+    // Compressed glyphs get unpacked into a stack buffer first
+    let px = match GlyphPixels::new(f, gpo, &gh) {
+        Some(px) => px,
+        None => return 0,
+    };
     // Add 1px pad to left
     let x0 = cr.x0 + 1;
     // Calculate word alignment for destination buffer
@@ -111,25 +151,7 @@ pub fn xor_char(fb: &mut LcdFB, cr: ClipRegion, c: char, f: Font) -> usize {
         cr.y1 - y0
     };
     for y in 0..y_max {
-        // Unpack pixels for this glyph row.
-        // px_in_low_word can include some or all of the pixels for this row of
-        // the pattern. It may also include pixels for the next row, or, in the
-        // case of the last row, it may include padding bits.
-        let px_offset = y * gh.w;
-        let low_word = gpo + 1 + (px_offset >> 5);
-        let px_in_low_word = 32 - (px_offset & 0x1f);
-        let mut pattern = (f.glyph_data)(low_word);
-        // Mask and align pixels from low word of glyph data array
-        pattern <<= 32 - px_in_low_word;
-        pattern >>= 32 - gh.w;
-        if gh.w > px_in_low_word {
-            // When pixels for this row span two words in the glyph data array,
-            // get pixels from the high word too
-            let px_in_high_word = gh.w - px_in_low_word;
-            let mut pattern_h = (f.glyph_data)(low_word + 1);
-            pattern_h >>= 32 - px_in_high_word;
-            pattern |= pattern_h;
-        }
+        let pattern = px.row(gh.w, y);
         // XOR glyph pixels onto destination buffer
         let base = (y0 + y) * LCD_WORDS_PER_LINE;
         fb[base + dest_low_word] ^= pattern << (32 - px_in_dest_low_word);
@@ -141,8 +163,137 @@ pub fn xor_char(fb: &mut LcdFB, cr: ClipRegion, c: char, f: Font) -> usize {
     return width_of_blitted_pixels;
 }
 
-/// Calculate the width of glpyh for a char
+/// Blit string with: XOR, any font scaled by integer pixel replication, align
+/// xr left yr top. Scale of 1 matches the unscaled string_*_left functions,
+/// and the scaled width is string_width(s, f) * scale.
+pub fn string_scaled_left(fb: &mut LcdFB, mut cr: ClipRegion, s: &str, f: Font, scale: usize) {
+    for c in s.chars() {
+        cr.x0 += xor_char_scaled(fb, cr, c, f, scale);
+        if cr.x0 >= cr.x1 {
+            break;
+        }
+    }
+}
+
+/// Blit a char with: XOR, integer scale, align left:xr.0 top:yr.0, pad
+/// L:1*scale px R:2*scale px. Each glyph pixel becomes a scale x scale block.
+/// Unlike xor_char, pixels beyond cr.x1 or cr.y1 are clipped.
+/// Return: width in pixels of scaled character + padding (0 for error)
+pub fn xor_char_scaled(fb: &mut LcdFB, cr: ClipRegion, c: char, f: Font, scale: usize) -> usize {
+    if scale == 0 || cr.y1 > LCD_LINES || cr.x1 > LCD_PX_PER_LINE || cr.x0 >= cr.x1 {
+        return 0;
+    }
+    // Look up glyph and unpack its header
+    let gpo = (f.glyph_pattern_offset)(c);
+    let gh = GlyphHeader::new((f.glyph_data)(gpo));
+    if gh.w > 32 {
+        return 0;
+    }
+    let px = match GlyphPixels::new(f, gpo, &gh) {
+        Some(px) => px,
+        None => return 0,
+    };
+    let x0 = cr.x0 + scale;
+    let y0 = cr.y0 + gh.y_offset * scale;
+    for y in 0..gh.h {
+        let pattern = px.row(gh.w, y);
+        // Replicate each set pixel into a scale x scale block
+        for x in 0..gh.w {
+            if (pattern >> x) & 1 == 0 {
+                continue;
+            }
+            let bx0 = x0 + x * scale;
+            let bx1 = core::cmp::min(bx0 + scale, cr.x1);
+            if bx0 >= bx1 {
+                break;
+            }
+            for sy in 0..scale {
+                let dest_y = y0 + y * scale + sy;
+                if dest_y >= cr.y1 {
+                    break;
+                }
+                xor_hline(fb, dest_y, bx0, bx1);
+            }
+        }
+    }
+    (gh.w + 3) * scale
+}
+
+/// Source of packed glyph pixels for blitting a glyph
+enum GlyphPixels {
+    /// Uncompressed pattern in font data, starting at this offset
+    Font(Font, usize),
+    /// Pattern that was decompressed into a stack buffer
+    Buf(GlyphBuf),
+}
+impl GlyphPixels {
+    /// Locate pixels for glyph at gpo, decompressing it if needed
+    /// Return: None if a compressed glyph is too big for a GlyphBuf
+    fn new(f: Font, gpo: usize, gh: &GlyphHeader) -> Option<GlyphPixels> {
+        if !gh.rle {
+            return Some(GlyphPixels::Font(f, gpo + 1));
+        }
+        fonts::unpack_rle(f, gpo, gh).map(GlyphPixels::Buf)
+    }
+
+    /// Get word i of packed pixels
+    fn word(&self, i: usize) -> u32 {
+        match self {
+            GlyphPixels::Font(f, offset) => (f.glyph_data)(offset + i),
+            GlyphPixels::Buf(buf) => buf[i],
+        }
+    }
+
+    /// Unpack pixels for row y of a glyph pattern that is w px wide.
+    /// px_in_low_word can include some or all of the pixels for this row of
+    /// the pattern. It may also include pixels for the next row, or, in the
+    /// case of the last row, it may include padding bits.
+    fn row(&self, w: usize, y: usize) -> u32 {
+        let px_offset = y * w;
+        let low_word = px_offset >> 5;
+        let px_in_low_word = 32 - (px_offset & 0x1f);
+        let mut pattern = self.word(low_word);
+        // Mask and align pixels from low word of glyph data array
+        pattern <<= 32 - px_in_low_word;
+        pattern >>= 32 - w;
+        if w > px_in_low_word {
+            // When pixels for this row span two words in the glyph data array,
+            // get pixels from the high word too
+            let px_in_high_word = w - px_in_low_word;
+            let mut pattern_h = self.word(low_word + 1);
+            pattern_h >>= 32 - px_in_high_word;
+            pattern |= pattern_h;
+        }
+        pattern
+    }
+}
+
+/// XOR a horizontal run of pixels x0..x1 on line y (x0 included, x1 excluded)
+fn xor_hline(fb: &mut LcdFB, y: usize, x0: usize, x1: usize) {
+    let base = y * LCD_WORDS_PER_LINE;
+    let mut x = x0;
+    while x < x1 {
+        let n = core::cmp::min(32 - (x & 0x1f), x1 - x);
+        fb[base + (x >> 5)] ^= hline_mask(x, n);
+        x += n;
+    }
+}
+
+/// Mask for n pixels starting at x within the frame buffer word holding x
+fn hline_mask(x: usize, n: usize) -> u32 {
+    let bit = x & 0x1f;
+    if n == 32 {
+        0xffffffff
+    } else {
+        ((1u32 << n) - 1) << bit
+    }
+}
+
+/// Calculate the width of glpyh for a char (cell width for monospace fonts)
 pub fn char_width(c: char, f: Font) -> usize {
+    if let Some(cell) = f.cell {
+        return cell.w;
+    }
     let gpo = (f.glyph_pattern_offset)(c);
     let gh = GlyphHeader::new((f.glyph_data)(gpo));
     gh.w
@@ -153,20 +304,13 @@ pub fn clear_region(fb: &mut LcdFB, cr: ClipRegion) {
     if cr.y1 > LCD_LINES || cr.y0 >= cr.y1 || cr.x1 > LCD_PX_PER_LINE || cr.x0 >= cr.x1 {
         return;
     }
-    // Calculate word alignment for destination buffer
-    let dest_low_word = cr.x0 >> 5;
-    let dest_high_word = cr.x1 >> 5;
-    let px_in_dest_low_word = 32 - (cr.x0 & 0x1f);
-    let px_in_dest_high_word = cr.x1 & 0x1f;
-    // Blit it
     for y in cr.y0..cr.y1 {
         let base = y * LCD_WORDS_PER_LINE;
-        fb[base + dest_low_word] |= 0xffffffff << (32 - px_in_dest_low_word);
-        for w in dest_low_word + 1..dest_high_word {
-            fb[base + w] = 0xffffffff;
-        }
-        if dest_low_word < dest_high_word {
-            fb[base + dest_high_word] |= 0xffffffff >> (32 - px_in_dest_high_word);
+        let mut x = cr.x0;
+        while x < cr.x1 {
+            let n = core::cmp::min(32 - (x & 0x1f), cr.x1 - x);
+            fb[base + (x >> 5)] |= hline_mask(x, n);
+            x += n;
         }
     }
 }
@@ -176,21 +320,8 @@ pub fn invert_region(fb: &mut LcdFB, cr: ClipRegion) {
     if cr.y1 > LCD_LINES || cr.y0 >= cr.y1 || cr.x1 > LCD_PX_PER_LINE || cr.x0 >= cr.x1 {
         return;
     }
-    // Calculate word alignment for destination buffer
-    let dest_low_word = cr.x0 >> 5;
-    let dest_high_word = cr.x1 >> 5;
-    let px_in_dest_low_word = 32 - (cr.x0 & 0x1f);
-    let px_in_dest_high_word = cr.x1 & 0x1f;
-    // Blit it
     for y in cr.y0..cr.y1 {
-        let base = y * LCD_WORDS_PER_LINE;
-        fb[base + dest_low_word] ^= 0xffffffff << (32 - px_in_dest_low_word);
-        for w in dest_low_word + 1..dest_high_word {
-            fb[base + w] ^= 0xffffffff;
-        }
-        if dest_low_word < dest_high_word {
-            fb[base + dest_high_word] ^= 0xffffffff >> (32 - px_in_dest_high_word);
-        }
+        xor_hline(fb, y, cr.x0, cr.x1);
     }
 }
 
@@ -261,7 +392,11 @@ fn line_fill_padded_border(fb: &mut LcdFB, y: usize) {
 
 #[cfg(test)]
 mod tests {
+    extern crate std;
     use super::fonts;
+    use super::*;
+    use std::cell::RefCell;
+    use std::vec::Vec;
 
     #[test]
     fn bold_font_at_sign() {
@@ -283,4 +418,256 @@ mod tests {
         assert_eq!(offset, 143);
         assert_eq!(fonts::small::DATA[offset], 0x000e1006);
     }
+
+    #[test]
+    fn scale_1_matches_unscaled_blit() {
+        let cr = ClipRegion {
+            x0: 30,
+            x1: LCD_PX_PER_LINE,
+            y0: 7,
+            y1: LCD_LINES,
+        };
+        let f = Font::new(fonts::GlyphSet::Bold);
+        let mut fb_a: LcdFB = [0; LCD_FRAME_BUF_SIZE];
+        let mut fb_b: LcdFB = [0; LCD_FRAME_BUF_SIZE];
+        let w_a = xor_char(&mut fb_a, cr, '@', f);
+        let w_b = xor_char_scaled(&mut fb_b, cr, '@', f, 1);
+        assert_eq!(w_a, w_b);
+        assert!(fb_a.iter().zip(fb_b.iter()).all(|(a, b)| a == b));
+    }
+
+    #[test]
+    fn scale_3_replicates_pixels() {
+        let cr = ClipRegion {
+            x0: 0,
+            x1: LCD_PX_PER_LINE,
+            y0: 0,
+            y1: LCD_LINES,
+        };
+        let f = Font::new(fonts::GlyphSet::Regular);
+        let mut fb_1: LcdFB = [0; LCD_FRAME_BUF_SIZE];
+        let mut fb_3: LcdFB = [0; LCD_FRAME_BUF_SIZE];
+        xor_char_scaled(&mut fb_1, cr, '8', f, 1);
+        xor_char_scaled(&mut fb_3, cr, '8', f, 3);
+        let px_1: u32 = fb_1.iter().map(|w| w.count_ones()).sum();
+        let px_3: u32 = fb_3.iter().map(|w| w.count_ones()).sum();
+        assert_eq!(px_1 * 9, px_3);
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "mono",
+        feature = "mono-latin-1",
+        feature = "mono-box-drawing"
+    ))]
+    fn mono_glyphs_fit_cell() {
+        let f = Font::new(fonts::GlyphSet::Mono);
+        let cell = f.cell.unwrap();
+        let chars = (0x20..=0x7E).chain(0xA0..=0xFF).chain(0x2500..=0x257F);
+        for c in chars.filter_map(core::char::from_u32) {
+            let gh = GlyphHeader::new((f.glyph_data)((f.glyph_pattern_offset)(c)));
+            assert!(gh.w <= cell.w);
+            assert!(gh.h + gh.y_offset <= cell.h);
+        }
+        assert_eq!(string_width("a│W", f), cell.w * 3);
+    }
+
+    #[test]
+    #[cfg(feature = "mono")]
+    fn mono_string_stops_at_last_whole_cell() {
+        let cell_w = Font::new(fonts::GlyphSet::Mono).cell.unwrap().w;
+        let cr = ClipRegion {
+            x0: 0,
+            x1: LCD_PX_PER_LINE,
+            y0: 0,
+            y1: LCD_LINES,
+        };
+        let mut fb_3: LcdFB = [0; LCD_FRAME_BUF_SIZE];
+        string_mono_left(&mut fb_3, cr, "WWW");
+        // Room for 3 cells plus the left pad fits 3 chars, and 1px less
+        // fits only 2
+        for (x1, n) in [(1 + 3 * cell_w, 3), (3 * cell_w, 2)].iter() {
+            let mut fb: LcdFB = [0; LCD_FRAME_BUF_SIZE];
+            let mut fb_n: LcdFB = [0; LCD_FRAME_BUF_SIZE];
+            string_mono_left(&mut fb, ClipRegion { x1: *x1, ..cr }, "WWWW");
+            string_mono_left(&mut fb_n, cr, &"WWW"[..*n]);
+            assert!(fb.iter().zip(fb_n.iter()).all(|(a, b)| a == b));
+        }
+        assert!(fb_3.iter().any(|w| *w != 0));
+    }
+
+    #[test]
+    #[cfg(not(feature = "mono"))]
+    fn mono_string_falls_back_to_regular() {
+        let cr = ClipRegion {
+            x0: 0,
+            x1: LCD_PX_PER_LINE,
+            y0: 0,
+            y1: LCD_LINES,
+        };
+        let mut fb: LcdFB = [0; LCD_FRAME_BUF_SIZE];
+        let mut fb_regular: LcdFB = [0; LCD_FRAME_BUF_SIZE];
+        string_mono_left(&mut fb, cr, "12:34");
+        let regular = Font::new(fonts::GlyphSet::Regular);
+        string_left(&mut fb_regular, cr, "12:34", regular);
+        assert!(fb.iter().zip(fb_regular.iter()).all(|(a, b)| a == b));
+    }
+
+    std::thread_local! {
+        // Glyph data and char to offset table for a font re-encoded at runtime
+        static TEST_FONT: RefCell<(Vec<u32>, Vec<usize>)> = const { RefCell::new((Vec::new(), Vec::new())) };
+    }
+
+    fn test_font_data(index: usize) -> u32 {
+        TEST_FONT.with(|t| t.borrow().0[index])
+    }
+
+    fn test_font_offset(c: char) -> usize {
+        TEST_FONT.with(|t| t.borrow().1[(c as usize) & 0xffff])
+    }
+
+    /// Run length encode one glyph pattern (see fonts::unpack_rle for format)
+    fn pack_rle(f: Font, gpo: usize) -> Vec<u32> {
+        let header = (f.glyph_data)(gpo);
+        let gh = GlyphHeader::new(header);
+        let pixel = |i: usize| ((f.glyph_data)(gpo + 1 + (i >> 5)) >> (31 - (i & 0x1f))) & 1;
+        let mut runs: Vec<u32> = Vec::new();
+        let mut i = 0;
+        while i < gh.w * gh.h {
+            let value = pixel(i);
+            let mut len = 1;
+            while len < 128 && i + len < gh.w * gh.h && pixel(i + len) == value {
+                len += 1;
+            }
+            runs.push((value << 7) | (len as u32 - 1));
+            i += len;
+        }
+        let mut words = std::vec![header | fonts::RLE_FLAG];
+        for chunk in runs.chunks(4) {
+            let word = chunk
+                .iter()
+                .enumerate()
+                .map(|(n, r)| r << (24 - 8 * n))
+                .fold(0, |a, b| a | b);
+            words.push(word);
+        }
+        words
+    }
+
+    /// Copy every glyph of f into TEST_FONT, run length encoding glyphs
+    /// when that makes them smaller if rle is true.
+    /// Return: test font and its size in words
+    fn load_test_font(f: Font, rle: bool) -> (Font, usize) {
+        let mut data: Vec<u32> = Vec::new();
+        let mut offsets: Vec<usize> = std::vec![0; 0x10000];
+        let mut copied: std::collections::BTreeMap<usize, usize> =
+            std::collections::BTreeMap::new();
+        for code in 0..0x10000u32 {
+            let c = match core::char::from_u32(code) {
+                Some(c) => c,
+                None => continue,
+            };
+            let gpo = (f.glyph_pattern_offset)(c);
+            offsets[code as usize] = *copied.entry(gpo).or_insert_with(|| {
+                let gh = GlyphHeader::new((f.glyph_data)(gpo));
+                let raw_len = 1 + (gh.w * gh.h).div_ceil(32);
+                let packed = pack_rle(f, gpo);
+                let start = data.len();
+                if rle && packed.len() < raw_len {
+                    data.extend_from_slice(&packed);
+                } else {
+                    data.extend((gpo..gpo + raw_len).map(|i| (f.glyph_data)(i)));
+                }
+                start
+            });
+        }
+        let words = data.len();
+        TEST_FONT.with(|t| *t.borrow_mut() = (data, offsets));
+        let font = Font {
+            glyph_pattern_offset: test_font_offset,
+            glyph_data: test_font_data,
+            cell: f.cell,
+        };
+        (font, words)
+    }
+
+    const ALL_GLYPH_SETS: [fonts::GlyphSet; 4] = [
+        fonts::GlyphSet::Bold,
+        fonts::GlyphSet::Regular,
+        fonts::GlyphSet::Small,
+        fonts::GlyphSet::Mono,
+    ];
+
+    #[test]
+    fn rle_glyphs_blit_same_as_raw() {
+        let cr = ClipRegion {
+            x0: 5,
+            x1: LCD_PX_PER_LINE,
+            y0: 3,
+            y1: LCD_LINES,
+        };
+        for gs in ALL_GLYPH_SETS.iter() {
+            let f = Font::new(*gs);
+            let (rle_f, _) = load_test_font(f, true);
+            let chars = (0x20..=0x7E)
+                .chain(0xA0..=0xFF)
+                .chain(0x2018..=0x2022)
+                .chain(0x2500..=0x257F);
+            for c in chars.filter_map(core::char::from_u32) {
+                let mut fb_raw: LcdFB = [0; LCD_FRAME_BUF_SIZE];
+                let mut fb_rle: LcdFB = [0; LCD_FRAME_BUF_SIZE];
+                assert_eq!(
+                    xor_char(&mut fb_raw, cr, c, f),
+                    xor_char(&mut fb_rle, cr, c, rle_f)
+                );
+                assert!(fb_raw.iter().zip(fb_rle.iter()).all(|(a, b)| a == b));
+                xor_char_scaled(&mut fb_raw, cr, c, f, 2);
+                xor_char_scaled(&mut fb_rle, cr, c, rle_f, 2);
+                assert!(fb_raw.iter().zip(fb_rle.iter()).all(|(a, b)| a == b));
+            }
+        }
+    }
+
+    /// Compare size and render time of raw and run length encoded glyphs.
+    /// Run with: cargo test --release -- --ignored --nocapture bench_rle
+    #[test]
+    #[ignore]
+    fn bench_rle_vs_raw() {
+        let names = ["Bold", "Regular", "Small", "Mono"];
+        let text = "The quick brown fox jumps over the lazy dog 0123456789 ┌─┬─┐ «£€»";
+        let cr = ClipRegion {
+            x0: 0,
+            x1: LCD_PX_PER_LINE,
+            y0: 0,
+            y1: LCD_LINES,
+        };
+        let rounds = 2000;
+        let mut fb: LcdFB = [0; LCD_FRAME_BUF_SIZE];
+        for (gs, name) in ALL_GLYPH_SETS.iter().zip(names.iter()) {
+            let mut results = [(0, 0.0); 2];
+            for (rle, result) in [false, true].iter().zip(results.iter_mut()) {
+                // Raw glyphs get copied into the test font too, so both go
+                // through the same data access path
+                let (f, words) = load_test_font(Font::new(*gs), *rle);
+                let start = std::time::Instant::now();
+                for _ in 0..rounds {
+                    let mut cr = cr;
+                    for c in text.chars() {
+                        cr.x0 = (cr.x0 + xor_char(&mut fb, cr, c, f)) % (LCD_PX_PER_LINE - 40);
+                    }
+                }
+                let ns = start.elapsed().as_nanos() as f64;
+                *result = (words * 4, ns / (rounds * text.chars().count()) as f64);
+            }
+            std::println!(
+                "{:<8} raw: {:>6} bytes {:>6.1} ns/char   rle: {:>6} bytes ({:>5.1}%) {:>6.1} ns/char",
+                name,
+                results[0].0,
+                results[0].1,
+                results[1].0,
+                100.0 * results[1].0 as f64 / results[0].0 as f64,
+                results[1].1,
+            );
+        }
+    }
 }
@@ -0,0 +1,283 @@
+//! Dead keys and Compose sequences for typing accented characters
+//!
+//! A dead key arms an accent that combines with the next letter (^ then e
+//! types ê). The Compose key starts a sequence of two characters that names
+//! the character to type (Compose a e types æ, Compose ' e types é). While
+//! an accent or sequence is pending, it shows in the status bar, with a
+//! bullet (the Compose keycap label) in front of a Compose sequence.
+
+use super::kbd::R;
+use super::state;
+
+/// Number of characters in a Compose sequence
+pub const SEQUENCE_LEN: usize = 2;
+
+/// Accent or Compose sequence waiting for more keystrokes
+#[derive(Copy, Clone, PartialEq)]
+pub enum Pending {
+    None,
+    /// Dead key accent (spacing form, like '^' or '¨')
+    Dead(char),
+    /// Compose sequence characters typed so far, and how many there are
+    Compose([char; SEQUENCE_LEN], usize),
+}
+
+/// Dead key and Compose state
+pub struct ComposeState {
+    pub pending: Pending,
+}
+impl ComposeState {
+    /// Initialize with nothing pending
+    pub const fn new() -> ComposeState {
+        ComposeState {
+            pending: Pending::None,
+        }
+    }
+}
+
+/// What to do with a keystroke after feeding it to the compose state
+pub enum Feed {
+    /// Handle the keystroke as usual
+    Pass,
+    /// The keystroke armed or continued a sequence (needs repaint)
+    Used,
+    /// Type this character in place of the keystroke
+    Char(char),
+    /// No composition: type this character, then handle the keystroke as
+    /// usual
+    Both(char),
+}
+
+/// Dead key compositions: accent, base letter, result
+const DEAD_KEYS: [(char, char, char); 56] = [
+    ('^', 'a', 'â'),
+    ('^', 'e', 'ê'),
+    ('^', 'i', 'î'),
+    ('^', 'o', 'ô'),
+    ('^', 'u', 'û'),
+    ('^', 'A', 'Â'),
+    ('^', 'E', 'Ê'),
+    ('^', 'I', 'Î'),
+    ('^', 'O', 'Ô'),
+    ('^', 'U', 'Û'),
+    ('¨', 'a', 'ä'),
+    ('¨', 'e', 'ë'),
+    ('¨', 'i', 'ï'),
+    ('¨', 'o', 'ö'),
+    ('¨', 'u', 'ü'),
+    ('¨', 'y', 'ÿ'),
+    ('¨', 'A', 'Ä'),
+    ('¨', 'E', 'Ë'),
+    ('¨', 'I', 'Ï'),
+    ('¨', 'O', 'Ö'),
+    ('¨', 'U', 'Ü'),
+    ('¨', 'Y', 'Ÿ'),
+    ('´', 'a', 'á'),
+    ('´', 'e', 'é'),
+    ('´', 'i', 'í'),
+    ('´', 'o', 'ó'),
+    ('´', 'u', 'ú'),
+    ('´', 'y', 'ý'),
+    ('´', 'A', 'Á'),
+    ('´', 'E', 'É'),
+    ('´', 'I', 'Í'),
+    ('´', 'O', 'Ó'),
+    ('´', 'U', 'Ú'),
+    ('´', 'Y', 'Ý'),
+    ('`', 'a', 'à'),
+    ('`', 'e', 'è'),
+    ('`', 'i', 'ì'),
+    ('`', 'o', 'ò'),
+    ('`', 'u', 'ù'),
+    ('`', 'A', 'À'),
+    ('`', 'E', 'È'),
+    ('`', 'I', 'Ì'),
+    ('`', 'O', 'Ò'),
+    ('`', 'U', 'Ù'),
+    ('~', 'a', 'ã'),
+    ('~', 'n', 'ñ'),
+    ('~', 'o', 'õ'),
+    ('~', 'A', 'Ã'),
+    ('~', 'N', 'Ñ'),
+    ('~', 'O', 'Õ'),
+    ('¸', 'c', 'ç'),
+    ('¸', 'C', 'Ç'),
+    ('°', 'a', 'å'),
+    ('°', 'A', 'Å'),
+    ('/', 'o', 'ø'),
+    ('/', 'O', 'Ø'),
+];
+
+/// Compose sequences other than an accent followed by a letter
+const SEQUENCES: [([char; SEQUENCE_LEN], char); 20] = [
+    (['a', 'e'], 'æ'),
+    (['A', 'E'], 'Æ'),
+    (['o', 'e'], 'œ'),
+    (['O', 'E'], 'Œ'),
+    (['s', 's'], 'ß'),
+    (['e', '='], '€'),
+    (['l', '-'], '£'),
+    (['y', '='], '¥'),
+    (['c', '|'], '¢'),
+    (['c', 'o'], '©'),
+    (['r', 'o'], '®'),
+    (['s', 'o'], '§'),
+    (['<', '<'], '«'),
+    (['>', '>'], '»'),
+    (['!', '!'], '¡'),
+    (['?', '?'], '¿'),
+    (['+', '-'], '±'),
+    (['x', 'x'], '×'),
+    (['-', ':'], '÷'),
+    (['o', 'o'], '°'),
+];
+
+/// Accent that a Compose sequence starts with, for combining with a letter
+/// from the dead key table (Compose " u types ü)
+fn compose_accent(c: char) -> char {
+    match c {
+        '"' => '¨',
+        '\'' => '´',
+        ',' => '¸',
+        '*' => '°',
+        c => c,
+    }
+}
+
+/// Combine a dead key accent with a letter
+fn dead_key(accent: char, c: char) -> Option<char> {
+    let mut found = DEAD_KEYS.iter().filter(|(a, b, _)| *a == accent && *b == c);
+    found.next().map(|(_, _, r)| *r)
+}
+
+/// Look up a complete Compose sequence
+fn sequence(seq: [char; SEQUENCE_LEN]) -> Option<char> {
+    match SEQUENCES.iter().find(|(s, _)| *s == seq) {
+        Some((_, r)) => Some(*r),
+        None => dead_key(compose_accent(seq[0]), seq[1]),
+    }
+}
+
+/// Check if a Compose sequence can start with c
+fn sequence_starts_with(c: char) -> bool {
+    let accent = compose_accent(c);
+    SEQUENCES.iter().any(|(s, _)| s[0] == c) || DEAD_KEYS.iter().any(|(a, _, _)| *a == accent)
+}
+
+/// Feed a keystroke result to the dead key and Compose state machine.
+/// Modifier keys pass through without disturbing a pending sequence.
+pub fn feed(ctx: &mut state::Context, r: &R) -> Feed {
+    let pending = ctx.compose.pending;
+    if let R::AltL | R::AltR | R::Shift | R::Nop = r {
+        return Feed::Pass;
+    }
+    ctx.compose.pending = Pending::None;
+    match (pending, r) {
+        (Pending::None, R::Dead(accent)) => {
+            ctx.compose.pending = Pending::Dead(*accent);
+            Feed::Used
+        }
+        (Pending::None, R::Compose) => {
+            ctx.compose.pending = Pending::Compose([' '; SEQUENCE_LEN], 0);
+            Feed::Used
+        }
+        (Pending::None, _) => Feed::Pass,
+        // Backspace or a second Compose cancels without typing anything
+        (_, R::Bksp) | (_, R::Compose) => Feed::Used,
+        // The accent alone, for space or the same dead key again
+        (Pending::Dead(accent), R::C(' ')) => Feed::Char(accent),
+        (Pending::Dead(accent), R::Dead(b)) if *b == accent => Feed::Char(accent),
+        // A different dead key types this accent and arms the new one
+        (Pending::Dead(accent), R::Dead(b)) => {
+            ctx.compose.pending = Pending::Dead(*b);
+            Feed::Char(accent)
+        }
+        (Pending::Dead(accent), R::C(c)) => match dead_key(accent, *c) {
+            Some(composed) => Feed::Char(composed),
+            None => Feed::Both(accent),
+        },
+        (Pending::Dead(accent), _) => Feed::Both(accent),
+        (Pending::Compose(mut seq, n), R::C(c)) => {
+            seq[n] = *c;
+            if n + 1 < SEQUENCE_LEN {
+                if !sequence_starts_with(*c) {
+                    return Feed::Pass;
+                }
+                ctx.compose.pending = Pending::Compose(seq, n + 1);
+                return Feed::Used;
+            }
+            match sequence(seq) {
+                Some(composed) => Feed::Char(composed),
+                None => Feed::Both(seq[0]),
+            }
+        }
+        (Pending::Compose(seq, n), _) if n > 0 => Feed::Both(seq[0]),
+        (Pending::Compose(..), _) => Feed::Pass,
+    }
+}
+
+/// Text that shows the pending accent or Compose sequence ("" if none)
+pub fn pending_text<'a>(ctx: &state::Context, buf: &'a mut [u8; 12]) -> &'a str {
+    let mut n = 0;
+    let mut push = |c: char| {
+        n += c.encode_utf8(&mut buf[n..]).len();
+    };
+    match ctx.compose.pending {
+        Pending::None => (),
+        Pending::Dead(accent) => push(accent),
+        Pending::Compose(seq, len) => {
+            push('•');
+            seq[..len].iter().for_each(|c| push(*c));
+        }
+    }
+    core::str::from_utf8(&buf[..n]).unwrap_or("")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Feed a sequence of results, collecting what gets typed
+    fn typed(ctx: &mut state::Context, keys: &[R], out: &mut [char; 8]) -> usize {
+        let mut n = 0;
+        for r in keys.iter() {
+            let (first, pass) = match feed(ctx, r) {
+                Feed::Pass => (None, true),
+                Feed::Used => (None, false),
+                Feed::Char(c) => (Some(c), false),
+                Feed::Both(c) => (Some(c), true),
+            };
+            for c in first.iter() {
+                out[n] = *c;
+                n += 1;
+            }
+            if let (true, R::C(c)) = (pass, r) {
+                out[n] = *c;
+                n += 1;
+            }
+        }
+        n
+    }
+
+    #[test]
+    fn dead_keys_and_compose() {
+        let mut ctx = state::Context::new();
+        let mut out = [' '; 8];
+        let keys = [R::Dead('^'), R::Shift, R::C('E'), R::Dead('^'), R::C('x')];
+        let n = typed(&mut ctx, &keys, &mut out);
+        assert_eq!(out[..n], ['Ê', '^', 'x']);
+        let ae = [R::Compose, R::C('a'), R::C('e')];
+        let n = typed(&mut ctx, &ae, &mut out);
+        assert_eq!(out[..n], ['æ']);
+        let keys = [R::Compose, R::C('\''), R::C('e')];
+        let n = typed(&mut ctx, &keys, &mut out);
+        assert_eq!(out[..n], ['é']);
+        feed(&mut ctx, &R::Compose);
+        feed(&mut ctx, &R::C('o'));
+        let mut buf = [0; 12];
+        assert_eq!(pending_text(&ctx, &mut buf), "•o");
+        let n = typed(&mut ctx, &[R::C('q')], &mut out);
+        assert_eq!(out[..n], ['o', 'q']);
+        assert!(ctx.compose.pending == Pending::None);
+    }
+}
@@ -0,0 +1,152 @@
+//! Modal dialogs with a title, a wrapped message, and up to three buttons
+//! that are bound to soft keys
+
+use super::focus;
+use super::kbd;
+use super::softkeys::{self, Action, SoftKeys};
+use super::state::{self, View};
+
+/// Maximum number of buttons in a dialog
+pub const BUTTONS_MAX: usize = 3;
+
+/// Modal dialog. Each soft key with a label becomes a button, from left to
+/// right, and pressing the soft key or clicking the button closes the
+/// dialog and then runs the soft key's action.
+pub struct Dialog {
+    pub title: &'static str,
+    pub message: &'static str,
+    pub buttons: SoftKeys,
+}
+
+/// Dialog that is open
+pub struct DialogState {
+    pub dialog: Option<&'static Dialog>,
+}
+impl DialogState {
+    /// Initialize with no dialog open
+    pub const fn new() -> DialogState {
+        DialogState { dialog: None }
+    }
+}
+
+/// Open a dialog on top of the active view
+pub fn open(ctx: &mut state::Context, d: &'static Dialog) -> bool {
+    if ctx.dialog.dialog.is_some() || !ctx.views.push(View::Dialog) {
+        return false;
+    }
+    ctx.dialog.dialog = Some(d);
+    true
+}
+
+/// Close the dialog and return to the view it was opened from
+pub fn close(ctx: &mut state::Context) -> bool {
+    ctx.dialog.dialog = None;
+    ctx.views.pop()
+}
+
+/// Soft key numbers of the dialog's buttons, in button order
+pub fn buttons(d: &Dialog) -> impl Iterator<Item = usize> + '_ {
+    let labeled = (0..softkeys::SOFT_KEY_COUNT).filter(move |n| !d.buttons[*n].label.is_empty());
+    labeled.take(BUTTONS_MAX)
+}
+
+/// Close the dialog and run the action of the button on soft key n
+fn press(ctx: &mut state::Context, n: usize) -> bool {
+    let d = match ctx.dialog.dialog {
+        Some(d) => d,
+        None => return false,
+    };
+    if !buttons(d).any(|b| b == n) {
+        return false;
+    }
+    close(ctx);
+    match d.buttons[n].action {
+        Action::Run(run) => {
+            run(ctx);
+        }
+        Action::Menu(_) | Action::None => (),
+    }
+    true
+}
+
+/// Title of the dialog view
+pub fn title(ctx: &state::Context) -> &str {
+    ctx.dialog.dialog.map_or("", |d| d.title)
+}
+
+/// Soft keys of the dialog view
+pub fn soft_keys(ctx: &state::Context) -> &'static SoftKeys {
+    match ctx.dialog.dialog {
+        Some(d) => &d.buttons,
+        None => &softkeys::NO_SOFT_KEYS,
+    }
+}
+
+/// Handle a keystroke while a dialog is open. The dialog is modal, so it
+/// uses every keystroke except arrows and Click (which move focus between
+/// its buttons and press them) and modifier keys.
+/// Return: true if the keystroke was used (needs repaint)
+pub fn handle_key(ctx: &mut state::Context, r: &kbd::R) -> bool {
+    if let Some(n) = softkeys::soft_key_number(r) {
+        return press(ctx, n);
+    }
+    match r {
+        kbd::R::Enter => match focus::focused(ctx) {
+            Some(i) => activate(ctx, i),
+            None => false,
+        },
+        kbd::R::Up | kbd::R::Down | kbd::R::Left | kbd::R::Right | kbd::R::Click => false,
+        kbd::R::AltL | kbd::R::AltR | kbd::R::Shift => false,
+        _ => true,
+    }
+}
+
+/// Press the focused button
+pub fn activate(ctx: &mut state::Context, i: usize) -> bool {
+    match ctx.dialog.dialog.and_then(|d| buttons(d).nth(i)) {
+        Some(n) => press(ctx, n),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use softkeys::SoftKey;
+
+    fn yes(ctx: &mut state::Context) -> bool {
+        ctx.text.insert('y')
+    }
+
+    static CONFIRM: Dialog = Dialog {
+        title: "confirm",
+        message: "Are you sure?",
+        buttons: [
+            SoftKey {
+                label: "no",
+                action: Action::None,
+            },
+            softkeys::NONE,
+            softkeys::NONE,
+            SoftKey {
+                label: "yes",
+                action: Action::Run(yes),
+            },
+        ],
+    };
+
+    #[test]
+    fn buttons_close_dialog_and_run() {
+        let mut ctx = state::Context::new();
+        assert!(buttons(&CONFIRM).eq([0, 3].iter().copied()));
+        assert!(open(&mut ctx, &CONFIRM));
+        assert!(handle_key(&mut ctx, &kbd::R::C('x')));
+        assert!(!handle_key(&mut ctx, &kbd::R::F2));
+        assert!(handle_key(&mut ctx, &kbd::R::F1));
+        assert!(ctx.views.active() == View::Home);
+        assert_eq!(ctx.text.as_str(), "");
+        open(&mut ctx, &CONFIRM);
+        assert!(activate(&mut ctx, 1));
+        assert_eq!(ctx.text.as_str(), "y");
+    }
+}
@@ -0,0 +1,316 @@
+//! Fixed-capacity text editing buffer with a cursor and undo history
+
+use super::kbd;
+use super::state;
+use super::undo::{EditKind, History};
+
+/// Capacity of the text buffer in bytes of UTF-8
+pub const TEXT_BUF_SIZE: usize = 1024;
+
+/// Text editing buffer. Text is stored as UTF-8, and the cursor is a byte
+/// offset that always falls on a char boundary. Edits are remembered for
+/// undo and redo.
+pub struct TextBuf {
+    buf: [u8; TEXT_BUF_SIZE],
+    len: usize,
+    cursor: usize,
+    history: History,
+}
+impl TextBuf {
+    /// Initialize an empty text buffer
+    pub const fn new() -> TextBuf {
+        TextBuf {
+            buf: [0; TEXT_BUF_SIZE],
+            len: 0,
+            cursor: 0,
+            history: History::new(),
+        }
+    }
+
+    /// Get string slice for the whole buffer
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[0..self.len]).unwrap_or_default()
+    }
+
+    /// Byte offset of the cursor
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Move the cursor to byte offset i (clamped to a char boundary)
+    pub fn set_cursor(&mut self, i: usize) {
+        let mut i = core::cmp::min(i, self.len);
+        while i > 0 && i < self.len && (self.buf[i] & 0xc0) == 0x80 {
+            i -= 1;
+        }
+        self.cursor = i;
+        self.history.seal();
+    }
+
+    /// Remove all text and forget the undo history
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.cursor = 0;
+        self.history = History::new();
+    }
+
+    /// Insert a character at the cursor
+    /// Return: false if the buffer is full
+    pub fn insert(&mut self, c: char) -> bool {
+        let mut utf8 = [0; 4];
+        let bytes = c.encode_utf8(&mut utf8).as_bytes();
+        if !self.insert_bytes(self.cursor, bytes) {
+            return false;
+        }
+        self.history.record(EditKind::Insert, self.cursor, bytes);
+        self.cursor += bytes.len();
+        true
+    }
+
+    /// Delete the character before the cursor
+    /// Return: false if the cursor was at the start of the buffer
+    pub fn backspace(&mut self) -> bool {
+        if self.cursor == 0 {
+            return false;
+        }
+        let start = self.prev_boundary(self.cursor);
+        self.record_removal(EditKind::Backspace, start, self.cursor);
+        self.remove(start, self.cursor);
+        self.cursor = start;
+        true
+    }
+
+    /// Delete the character after the cursor
+    /// Return: false if the cursor was at the end of the buffer
+    pub fn delete(&mut self) -> bool {
+        if self.cursor == self.len {
+            return false;
+        }
+        let end = self.next_boundary(self.cursor);
+        self.record_removal(EditKind::Delete, self.cursor, end);
+        self.remove(self.cursor, end);
+        true
+    }
+
+    /// Move cursor back by one character
+    pub fn left(&mut self) -> bool {
+        if self.cursor == 0 {
+            return false;
+        }
+        self.cursor = self.prev_boundary(self.cursor);
+        self.history.seal();
+        true
+    }
+
+    /// Move cursor forward by one character
+    pub fn right(&mut self) -> bool {
+        if self.cursor == self.len {
+            return false;
+        }
+        self.cursor = self.next_boundary(self.cursor);
+        self.history.seal();
+        true
+    }
+
+    /// Move cursor to the start of its line
+    pub fn home(&mut self) -> bool {
+        let start = self.line_start(self.cursor);
+        let moved = start != self.cursor;
+        self.cursor = start;
+        self.history.seal();
+        moved
+    }
+
+    /// Move cursor to the end of its line
+    pub fn end(&mut self) -> bool {
+        let end = self.line_end(self.cursor);
+        let moved = end != self.cursor;
+        self.cursor = end;
+        self.history.seal();
+        moved
+    }
+
+    /// Byte offset of the start of the line containing byte offset i
+    pub fn line_start(&self, i: usize) -> usize {
+        match self.buf[0..i].iter().rposition(|b| *b == b'\n') {
+            Some(nl) => nl + 1,
+            None => 0,
+        }
+    }
+
+    /// Byte offset of the end (newline or end of buffer) of the line
+    /// containing byte offset i
+    pub fn line_end(&self, i: usize) -> usize {
+        match self.buf[i..self.len].iter().position(|b| *b == b'\n') {
+            Some(nl) => i + nl,
+            None => self.len,
+        }
+    }
+
+    /// Undo the most recent group of edits
+    /// Return: false if there was nothing to undo
+    pub fn undo(&mut self) -> bool {
+        let edit = match self.history.undo() {
+            Some(edit) => edit,
+            None => return false,
+        };
+        let n = edit.bytes().len();
+        match edit.kind {
+            EditKind::Insert => {
+                self.remove(edit.pos, edit.pos + n);
+                self.cursor = edit.pos;
+            }
+            EditKind::Backspace => {
+                self.insert_bytes(edit.pos, edit.bytes());
+                self.cursor = edit.pos + n;
+            }
+            EditKind::Delete => {
+                self.insert_bytes(edit.pos, edit.bytes());
+                self.cursor = edit.pos;
+            }
+        }
+        true
+    }
+
+    /// Redo the most recently undone group of edits
+    /// Return: false if there was nothing to redo
+    pub fn redo(&mut self) -> bool {
+        let edit = match self.history.redo() {
+            Some(edit) => edit,
+            None => return false,
+        };
+        let n = edit.bytes().len();
+        match edit.kind {
+            EditKind::Insert => {
+                self.insert_bytes(edit.pos, edit.bytes());
+                self.cursor = edit.pos + n;
+            }
+            EditKind::Backspace | EditKind::Delete => {
+                self.remove(edit.pos, edit.pos + n);
+                self.cursor = edit.pos;
+            }
+        }
+        true
+    }
+
+    /// Insert bytes at byte offset i, shifting the rest of the buffer up
+    /// Return: false if the buffer is too full
+    fn insert_bytes(&mut self, i: usize, bytes: &[u8]) -> bool {
+        let n = bytes.len();
+        if self.len + n > TEXT_BUF_SIZE {
+            return false;
+        }
+        self.buf.copy_within(i..self.len, i + n);
+        self.buf[i..i + n].copy_from_slice(bytes);
+        self.len += n;
+        true
+    }
+
+    /// Remember bytes start..end in the undo history before removing them
+    fn record_removal(&mut self, kind: EditKind, start: usize, end: usize) {
+        let mut bytes = [0; 4];
+        bytes[0..end - start].copy_from_slice(&self.buf[start..end]);
+        self.history.record(kind, start, &bytes[0..end - start]);
+    }
+
+    /// Remove bytes start..end, shifting the rest of the buffer down
+    fn remove(&mut self, start: usize, end: usize) {
+        self.buf.copy_within(end..self.len, start);
+        self.len -= end - start;
+    }
+
+    /// Byte offset of the char boundary before i (i must be > 0)
+    fn prev_boundary(&self, i: usize) -> usize {
+        let mut j = i - 1;
+        // Skip back over UTF-8 continuation bytes (0b10xx_xxxx)
+        while j > 0 && (self.buf[j] & 0xc0) == 0x80 {
+            j -= 1;
+        }
+        j
+    }
+
+    /// Byte offset of the char boundary after i (i must be < len)
+    fn next_boundary(&self, i: usize) -> usize {
+        let mut j = i + 1;
+        while j < self.len && (self.buf[j] & 0xc0) == 0x80 {
+            j += 1;
+        }
+        j
+    }
+}
+
+/// Handle a keystroke result for the text editor.
+/// Return: true if the editor used the keystroke (needs repaint)
+pub fn handle_key(ctx: &mut state::Context, r: &kbd::R) -> bool {
+    let text = &mut ctx.text;
+    match r {
+        kbd::R::C(c) => text.insert(*c),
+        kbd::R::Enter => text.insert('\n'),
+        kbd::R::Bksp => text.backspace(),
+        kbd::R::Delete => text.delete(),
+        kbd::R::Left => text.left(),
+        kbd::R::Right => text.right(),
+        kbd::R::Home => text.home(),
+        kbd::R::End => text.end(),
+        kbd::R::Undo => text.undo(),
+        kbd::R::Redo => text.redo(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextBuf;
+
+    fn typed(s: &str) -> TextBuf {
+        let mut text = TextBuf::new();
+        for c in s.chars() {
+            text.insert(c);
+        }
+        text
+    }
+
+    #[test]
+    fn insert_at_cursor() {
+        let mut text = typed("helo");
+        text.left();
+        text.insert('l');
+        assert_eq!(text.as_str(), "hello");
+        assert_eq!(text.cursor(), 4);
+    }
+
+    #[test]
+    fn backspace_and_delete_multibyte() {
+        let mut text = typed("a€b");
+        text.left();
+        assert!(text.backspace());
+        assert_eq!(text.as_str(), "ab");
+        text.home();
+        assert!(text.delete());
+        assert!(!text.backspace());
+        assert_eq!(text.as_str(), "b");
+        text.end();
+        assert!(!text.delete());
+    }
+
+    #[test]
+    fn home_end_stay_on_line() {
+        let mut text = typed("one\ntwo\nthree");
+        for _ in 0..7 {
+            text.left();
+        }
+        text.home();
+        assert_eq!(text.cursor(), 4);
+        text.end();
+        assert_eq!(text.cursor(), 7);
+    }
+
+    #[test]
+    fn full_buffer_rejects_insert() {
+        let mut text = TextBuf::new();
+        while text.insert('x') {}
+        assert_eq!(text.as_str().len(), super::TEXT_BUF_SIZE);
+        text.home();
+        assert!(!text.insert('y'));
+    }
+}
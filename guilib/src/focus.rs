@@ -0,0 +1,231 @@
+//! Focus ring across the focusable widgets of the active view, with D-pad
+//! traversal in visual order and Click to activate
+
+use super::blit::{self, ClipRegion, LcdFB};
+use super::kbd;
+use super::nav;
+use super::state;
+
+/// Maximum number of focusable widgets in a view
+pub const FOCUS_MAX: usize = 16;
+
+/// Gap in pixels between a focused widget and its focus indicator
+const INDICATOR_GAP: usize = 2;
+
+/// Regions of the focusable widgets that the active view painted, in the
+/// order they were painted (top to bottom, left to right)
+pub struct FocusRing {
+    regions: [ClipRegion; FOCUS_MAX],
+    len: usize,
+}
+impl FocusRing {
+    /// Initialize an empty focus ring
+    pub const fn new() -> FocusRing {
+        FocusRing {
+            regions: [ClipRegion {
+                x0: 0,
+                x1: 0,
+                y0: 0,
+                y1: 0,
+            }; FOCUS_MAX],
+            len: 0,
+        }
+    }
+
+    /// Forget all regions (before the active view repaints)
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Add the region of a focusable widget
+    /// Return: index of the widget in the ring, or None if the ring is full
+    pub fn add(&mut self, cr: ClipRegion) -> Option<usize> {
+        if self.len == FOCUS_MAX {
+            return None;
+        }
+        self.regions[self.len] = cr;
+        self.len += 1;
+        Some(self.len - 1)
+    }
+
+    /// Number of focusable widgets
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return true if there are no focusable widgets
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the region of a focusable widget
+    pub fn region(&self, i: usize) -> Option<ClipRegion> {
+        match i < self.len {
+            true => Some(self.regions[i]),
+            false => None,
+        }
+    }
+
+    /// Find the widget at a screen position
+    pub fn hit(&self, x: usize, y: usize) -> Option<usize> {
+        let mut regions = self.regions[0..self.len].iter();
+        regions.position(|cr| x >= cr.x0 && x < cr.x1 && y >= cr.y0 && y < cr.y1)
+    }
+
+    /// Find the widget to move focus to from widget i for an arrow key. This
+    /// picks the nearest widget whose center lies in the arrow's direction,
+    /// preferring widgets that line up with widget i. When there is none,
+    /// Down and Right go to the next widget in the ring, and Up and Left go
+    /// to the previous one, wrapping around at the ends.
+    pub fn step(&self, i: usize, r: &kbd::R) -> usize {
+        if self.len == 0 {
+            return 0;
+        }
+        let i = core::cmp::min(i, self.len - 1);
+        let (cx, cy) = center(self.regions[i]);
+        let mut best: Option<(usize, usize)> = None;
+        for (j, cr) in self.regions[0..self.len].iter().enumerate() {
+            let (x, y) = center(*cr);
+            // Distance along the arrow's direction, and across it
+            let (along, across) = match r {
+                kbd::R::Up if y < cy => (cy - y, diff(x, cx)),
+                kbd::R::Down if y > cy => (y - cy, diff(x, cx)),
+                kbd::R::Left if x < cx => (cx - x, diff(y, cy)),
+                kbd::R::Right if x > cx => (x - cx, diff(y, cy)),
+                _ => continue,
+            };
+            let score = along + 2 * across;
+            if best.is_none_or(|(_, s)| score < s) {
+                best = Some((j, score));
+            }
+        }
+        match (best, r) {
+            (Some((j, _)), _) => j,
+            (None, kbd::R::Up) | (None, kbd::R::Left) => (i + self.len - 1) % self.len,
+            (None, _) => (i + 1) % self.len,
+        }
+    }
+}
+
+/// Center point of a region
+fn center(cr: ClipRegion) -> (usize, usize) {
+    ((cr.x0 + cr.x1) >> 1, (cr.y0 + cr.y1) >> 1)
+}
+
+/// Absolute difference
+fn diff(a: usize, b: usize) -> usize {
+    a.max(b) - a.min(b)
+}
+
+/// Index of the focused widget in the active view, kept within the ring
+pub fn focused(ctx: &state::Context) -> Option<usize> {
+    match ctx.focus.len() {
+        0 => None,
+        n => Some(core::cmp::min(ctx.views.focus(), n - 1)),
+    }
+}
+
+/// Handle arrow keys and Click for the focusable widgets of the active view.
+/// This runs after the active view's own key handler passes on a keystroke.
+/// Return: true if the keystroke was used (needs repaint)
+pub fn handle_key(ctx: &mut state::Context, r: &kbd::R) -> bool {
+    let i = match focused(ctx) {
+        Some(i) => i,
+        None => return false,
+    };
+    match r {
+        kbd::R::Up | kbd::R::Down | kbd::R::Left | kbd::R::Right => {
+            let j = ctx.focus.step(i, r);
+            ctx.views.set_focus(j);
+            true
+        }
+        kbd::R::Click => (nav::active(ctx).activate)(ctx, i),
+        _ => false,
+    }
+}
+
+/// Draw a dotted border around the focused widget of the active view
+pub fn paint_indicator(fb: &mut LcdFB, ctx: &state::Context) {
+    let cr = match focused(ctx).and_then(|i| ctx.focus.region(i)) {
+        Some(cr) => cr,
+        None => return,
+    };
+    let g = INDICATOR_GAP + 1;
+    let x0 = cr.x0.saturating_sub(g);
+    let y0 = cr.y0.saturating_sub(g);
+    let x1 = core::cmp::min(cr.x1 + INDICATOR_GAP, blit::LCD_PX_PER_LINE - 1);
+    let y1 = core::cmp::min(cr.y1 + INDICATOR_GAP, blit::LCD_LINES - 1);
+    for x in (x0..=x1).step_by(2) {
+        invert_px(fb, x, y0);
+        invert_px(fb, x, y1);
+    }
+    for y in (y0 + 2..y1).step_by(2) {
+        invert_px(fb, x0, y);
+        invert_px(fb, x1, y);
+    }
+}
+
+/// Invert one pixel
+fn invert_px(fb: &mut LcdFB, x: usize, y: usize) {
+    let px = ClipRegion {
+        x0: x,
+        x1: x + 1,
+        y0: y,
+        y1: y + 1,
+    };
+    blit::invert_region(fb, px);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ring with a full width widget above a row of two half width widgets
+    fn ring() -> FocusRing {
+        let mut ring = FocusRing::new();
+        for (x0, x1, y0) in [(0, 200, 0), (0, 100, 40), (100, 200, 40)].iter() {
+            ring.add(ClipRegion {
+                x0: *x0,
+                x1: *x1,
+                y0: *y0,
+                y1: *y0 + 30,
+            });
+        }
+        ring
+    }
+
+    #[test]
+    fn arrows_move_in_visual_order() {
+        let ring = ring();
+        assert_eq!(ring.step(0, &kbd::R::Down), 1);
+        assert_eq!(ring.step(1, &kbd::R::Right), 2);
+        assert_eq!(ring.step(2, &kbd::R::Up), 0);
+        assert_eq!(ring.step(2, &kbd::R::Left), 1);
+        // Nothing in that direction, so move along the ring and wrap
+        assert_eq!(ring.step(2, &kbd::R::Right), 0);
+        assert_eq!(ring.step(0, &kbd::R::Up), 2);
+        assert_eq!(ring.step(1, &kbd::R::Left), 0);
+        assert_eq!(ring.hit(150, 45), Some(2));
+        assert_eq!(ring.hit(150, 35), None);
+    }
+
+    #[test]
+    fn focus_follows_arrow_keys() {
+        let mut ctx = state::Context::new();
+        assert!(!handle_key(&mut ctx, &kbd::R::Down));
+        ctx.focus = ring();
+        assert_eq!(focused(&ctx), Some(0));
+        assert!(handle_key(&mut ctx, &kbd::R::Down));
+        assert!(handle_key(&mut ctx, &kbd::R::Right));
+        assert_eq!(focused(&ctx), Some(2));
+        // Focus stays in range when the view paints fewer widgets
+        ctx.focus.clear();
+        ctx.focus.add(ClipRegion {
+            x0: 0,
+            x1: 10,
+            y0: 0,
+            y1: 10,
+        });
+        assert_eq!(focused(&ctx), Some(0));
+    }
+}
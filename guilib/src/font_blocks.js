@@ -0,0 +1,231 @@
+"use strict";
+// This rewrites a generated font source file (src/fonts/*.rs) so that each
+// Unicode block gets its own glyph pattern array, and optional blocks are
+// only compiled into DATA[] when their cargo feature is enabled.
+//
+// Usage (from the guilib directory), after pasting code from www_codegen:
+//   node src/font_blocks.js src/fonts/bold.rs
+//
+// With --rle, glyph patterns are run length encoded when that makes them
+// smaller (see fonts::unpack_rle). This is meant for big fonts with large
+// glyphs; the current fonts are stored raw.
+//
+// The input may be either the single DATA[] array format produced by
+// www_codegen or the per-block format produced by this script, so it is safe
+// to run more than once. src/mono_codegen.js uses emit() directly.
+const fs = require('fs');
+
+// Unicode blocks in codepoint order. Blocks with a feature are optional; the
+// cargo feature name is `<font>-<feature>` (e.g. small-latin-1).
+const BLOCKS = [
+    {name: 'BASIC_LATIN', title: 'Unicode Basic Latin', first: 0x20, last: 0x7E, feature: null},
+    {name: 'LATIN_1', title: 'Unicode Latin 1', first: 0xA0, last: 0xFF, feature: 'latin-1'},
+    {name: 'LATIN_EXTENDED_A', title: 'Unicode Latin Extended A', first: 0x152, last: 0x153, feature: 'latin-extended-a'},
+    {name: 'GENERAL_PUNCTUATION', title: 'General Punctuation', first: 0x2018, last: 0x2022, feature: 'general-punctuation'},
+    {name: 'CURRENCY_SYMBOLS', title: 'Unicode Currency Symbols', first: 0x20AC, last: 0x20AC, feature: 'currency-symbols'},
+    {name: 'BOX_DRAWING', title: 'Unicode Box Drawing', first: 0x2500, last: 0x257F, feature: 'box-drawing'},
+    {name: 'PRIVATE_USE_AREA', title: 'Unicode Private Use Area', first: 0xE700, last: 0xE70C, feature: null},
+    {name: 'SPECIALS', title: 'Unicode Specials', first: 0xFFFD, last: 0xFFFD, feature: null},
+];
+
+// Parse a font source file into its name, extra constants, and glyph records
+function parse(src) {
+    let name = src.match(/^\/\/! (\w+) Font/m)[1];
+    let maxHeight = parseInt(src.match(/pub const MAX_HEIGHT: u8 = (\d+);/)[1]);
+    // Keep any extra metrics (e.g. mono cell size) between MAX_HEIGHT and DATA
+    let afterMax = src.slice(src.indexOf('\n', src.indexOf('pub const MAX_HEIGHT')) + 1);
+    let extra = afterMax.slice(0, afterMax.indexOf('/// Packed glyph pattern data')).trim();
+    let data = src.slice(src.indexOf('/// Packed glyph pattern data'));
+    let records = [];
+    let re = /^    \/\/ \[\d+\]: ([0-9A-F]+) (.*)\n((?:    0x[0-9a-f, x]*\n)+)/gm;
+    for (let m of data.matchAll(re)) {
+        let words = m[3].match(/0x[0-9a-f]{8}/g).map(h => parseInt(h, 16) >>> 0);
+        records.push({code: parseInt(m[1], 16), desc: m[2], words});
+    }
+    return {name, maxHeight, extra, records};
+}
+
+// Run length encode a glyph record if that makes it smaller. Runs are packed
+// one per byte, MSB first: bit 7 is pixel value, bits 0..=6 are length - 1.
+const RLE_FLAG = 0x01000000;
+function compress(rec) {
+    let header = rec.words[0];
+    if (header & RLE_FLAG) {
+        return rec;
+    }
+    let w = (header >>> 16) & 0xff;
+    let h = (header >>> 8) & 0xff;
+    let pixel = i => (rec.words[1 + (i >>> 5)] >>> (31 - (i & 31))) & 1;
+    let runs = [];
+    for (let i = 0; i < w * h;) {
+        let value = pixel(i);
+        let len = 1;
+        while (len < 128 && i + len < w * h && pixel(i + len) === value) {
+            len++;
+        }
+        runs.push((value << 7) | (len - 1));
+        i += len;
+    }
+    let words = [(header | RLE_FLAG) >>> 0];
+    for (let i = 0; i < runs.length; i += 4) {
+        let word = 0;
+        for (let n = 0; n < 4 && i + n < runs.length; n++) {
+            word |= runs[i + n] << (24 - 8 * n);
+        }
+        words.push(word >>> 0);
+    }
+    return words.length < rec.words.length ? Object.assign({}, rec, {words}) : rec;
+}
+
+function toHexWord(n) {
+    let hex = (n >>> 0).toString(16);
+    return "0".repeat(Math.max(8 - hex.length, 0)) + hex;
+}
+
+function hex(n) {
+    return n.toString(16).toUpperCase();
+}
+
+// Index comments show the raw character (data comments escape it)
+function indexComment(desc) {
+    return desc.replace(/^'\\(.)'$/, "'$1'");
+}
+
+// Emit rust source for a font with one glyph pattern array per block
+function emit(font) {
+    let prefix = font.name.toLowerCase();
+    let blocks = [];
+    for (let b of BLOCKS) {
+        let recs = font.records.filter(r => b.first <= r.code && r.code <= b.last);
+        if (recs.length > 0) {
+            blocks.push(Object.assign({recs}, b));
+        }
+    }
+    let always = blocks.filter(b => b.feature === null);
+    let optional = blocks.filter(b => b.feature !== null);
+    let specials = blocks.find(b => b.name === 'SPECIALS');
+    // Match arms in codepoint order, with the Specials block as the fallback
+    let arms = [];
+    for (let b of blocks) {
+        if (b === specials) {
+            continue;
+        }
+        let base = b.name === 'BASIC_LATIN' ? '' : `${b.name}_BASE + `;
+        if (b.feature !== null) {
+            arms.push(`        #[cfg(feature = "${prefix}-${b.feature}")]`);
+        }
+        arms.push(`        0x${hex(b.first)}..=0x${hex(b.last)} => ${base}${b.name}[(c as usize) - 0x${hex(b.first)}] as usize,`);
+    }
+    arms.push(`        _ => SPECIALS_BASE + SPECIALS[0] as usize,`);
+    // Block offsets within DATA[]: always-on blocks first, then optional ones
+    let order = always.concat(optional);
+    let bases = [];
+    for (let i = 1; i <= order.length; i++) {
+        let prev = order[i - 1];
+        let name = i < order.length ? `${order[i].name}_BASE` : 'DATA_LEN';
+        let prevBase = i === 1 ? '' : `${prev.name}_BASE + `;
+        let prevLen = prev.feature === null
+            ? `${prev.name}_DATA.len()`
+            : `super::block_len(${prev.name}_ENABLED, &${prev.name}_DATA)`;
+        bases.push(`const ${name}: usize = ${prevBase}${prevLen};`);
+    }
+    let enabled = optional.map(b =>
+        `const ${b.name}_ENABLED: bool = cfg!(feature = "${prefix}-${b.feature}");`);
+    let indexes = blocks.map(b => {
+        let offset = 0;
+        let lines = b.recs.map(r => {
+            let line = `${offset}, // ${indexComment(r.desc)}`;
+            offset += r.words.length;
+            return line;
+        });
+        let title = b.name === 'PRIVATE_USE_AREA' ? `${b.title} block glyph patterns (UI sprites)` : `${b.title} block glyph patterns`;
+        return `// Index to ${title}
+const ${b.name}: [u16; ${b.recs.length}] = [
+    ${lines.join("\n    ")}
+];`;
+    });
+    let concat = order.map(b => b.feature === null
+        ? `    &${b.name}_DATA,`
+        : `    super::block_if(${b.name}_ENABLED, &${b.name}_DATA),`);
+    let costs = blocks.map(b => {
+        let on = b.feature === null ? 'true' : `${b.name}_ENABLED`;
+        let title = b.title.replace(/^Unicode /, '');
+        return `    super::BlockCost::new("${title}", ${on}, &${b.name}_DATA, &${b.name}),`;
+    });
+    let dataArrays = blocks.map(b => {
+        let offset = 0;
+        let recs = b.recs.map(r => {
+            let lines = [];
+            for (let i = 0; i < r.words.length; i += 8) {
+                lines.push(r.words.slice(i, i + 8).map(n => `0x${toHexWord(n)}`).join(", ") + ",");
+            }
+            let s = `    // [${offset}]: ${hex(r.code)} ${r.desc}\n    ${lines.join("\n    ")}`;
+            offset += r.words.length;
+            return s;
+        });
+        return `// Packed glyph patterns for ${b.title} block
+const ${b.name}_DATA: [u32; ${offset}] = [
+${recs.join("\n")}
+];`;
+    });
+    let extra = font.extra.length > 0 ? `\n\n${font.extra}` : '';
+    return `#![allow(dead_code)]
+//! ${font.name} Font
+
+/// Return offset into DATA[] for start of pattern depicting glyph for character c.
+/// Characters from optional blocks that were left out of the build by cargo
+/// features get the replacement character glyph.
+pub fn get_glyph_pattern_offset(c: char) -> usize {
+    match c as u32 {
+${arms.join("\n")}
+    }
+}
+
+// Optional blocks are compiled in when their cargo feature is enabled
+${enabled.join("\n")}
+
+// Offsets of each block's glyph patterns within DATA[]
+${bases.join("\n")}
+
+${indexes.join("\n\n")}
+
+/// Maximum height of glyph patterns in this bitmap typeface.
+/// This will be true: h + yOffset <= MAX_HEIGHT
+pub const MAX_HEIGHT: u8 = ${font.maxHeight};${extra}
+
+/// Packed glyph pattern data for all compiled in blocks.
+/// Record format:
+///  [offset+0]: ((w as u8) << 16) | ((h as u8) << 8) | (yOffset as u8)
+///  [offset+1..=ceil(w*h/32)]: packed 1-bit pixels; 0=clear, 1=set
+/// Pixels are packed in top to bottom, left to right order with MSB of first
+/// pixel word containing the top left pixel.
+///  w: Width of pattern in pixels
+///  h: Height of pattern in pixels
+///  yOffset: Vertical offset (pixels downward from top of line) to position
+///     glyph pattern properly relative to text baseline
+pub const DATA: [u32; DATA_LEN] = super::concat_blocks(&[
+${concat.join("\n")}
+]);
+
+/// Flash footprint of each block (glyph patterns plus index)
+pub const BLOCK_COSTS: [super::BlockCost; ${blocks.length}] = [
+${costs.join("\n")}
+];
+
+${dataArrays.join("\n\n")}
+`;
+}
+
+module.exports = {parse, emit, compress};
+
+if (require.main === module) {
+    let args = process.argv.slice(2);
+    let rle = args.includes('--rle');
+    for (let file of args.filter(a => a !== '--rle')) {
+        let font = parse(fs.readFileSync(file, 'utf8'));
+        if (rle) {
+            font.records = font.records.map(compress);
+        }
+        fs.writeFileSync(file, emit(font));
+    }
+}
@@ -0,0 +1,99 @@
+//! Font costs view: flash footprint of each font, by Unicode block
+
+use super::fonts::{FontCost, FONT_COSTS};
+use super::kbd;
+use super::state::{self, View};
+use super::widgets::List;
+
+/// Number of fonts visible at once in the font list
+pub const LIST_ROWS: usize = 2;
+
+/// Width in chars of a right aligned byte count
+pub const BYTES_W: usize = 6;
+
+/// Font names in FONT_COSTS order, for the font list
+pub const FONT_NAMES: [&str; FONT_COSTS.len()] = font_names();
+
+const fn font_names() -> [&'static str; FONT_COSTS.len()] {
+    let mut names = [""; FONT_COSTS.len()];
+    let mut i = 0;
+    while i < names.len() {
+        names[i] = FONT_COSTS[i].font;
+        i += 1;
+    }
+    names
+}
+
+/// Initialize the font list with the first font selected
+pub const fn new_list() -> List<'static> {
+    List {
+        items: &FONT_NAMES,
+        selected: 0,
+        top: 0,
+        rows: LIST_ROWS,
+    }
+}
+
+/// Open the font costs view on top of the active view
+pub fn open(ctx: &mut state::Context) -> bool {
+    if ctx.views.active() == View::FontCosts {
+        return false;
+    }
+    ctx.views.push(View::FontCosts)
+}
+
+/// Close the font costs view and return to the previous view
+pub fn close(ctx: &mut state::Context) -> bool {
+    ctx.views.pop()
+}
+
+/// Get the cost of the font selected in the font list
+pub fn selected(ctx: &state::Context) -> &'static FontCost {
+    &FONT_COSTS[ctx.font_list.selected]
+}
+
+/// Handle a keystroke while the font costs view is active. Up and Down move
+/// through the font list.
+/// Return: true if the keystroke was used (needs repaint)
+pub fn handle_key(ctx: &mut state::Context, r: &kbd::R) -> bool {
+    match r {
+        kbd::R::Up => ctx.font_list.select_prev(),
+        kbd::R::Down => ctx.font_list.select_next(),
+        _ => false,
+    }
+}
+
+/// Format a byte count right aligned in BYTES_W chars, or "off" for a block
+/// that was left out of the build
+pub fn format_bytes(bytes: Option<usize>, buf: &mut [u8; BYTES_W]) -> &str {
+    // Clamp to the largest count that fits
+    let mut n = match bytes {
+        Some(n) => core::cmp::min(n, 999_999),
+        None => return "   off",
+    };
+    for c in buf.iter_mut().rev() {
+        *c = match n {
+            0 => b' ',
+            _ => b'0' + (n % 10) as u8,
+        };
+        n /= 10;
+    }
+    if buf[BYTES_W - 1] == b' ' {
+        buf[BYTES_W - 1] = b'0';
+    }
+    core::str::from_utf8(&buf[..]).unwrap_or("")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_are_right_aligned() {
+        let mut buf = [0; BYTES_W];
+        assert_eq!(format_bytes(Some(1234), &mut buf), "  1234");
+        assert_eq!(format_bytes(Some(0), &mut buf), "     0");
+        assert_eq!(format_bytes(Some(1234567), &mut buf), "999999");
+        assert_eq!(format_bytes(None, &mut buf), "   off");
+    }
+}
@@ -1,4 +1,5 @@
 pub mod bold;
+pub mod mono;
 pub mod regular;
 pub mod small;
 
@@ -19,27 +20,99 @@ pub mod pua {
     pub const ENTER_SYMBOL: &str = &"\u{E70C}";
 }
 
+/// Glyph header flag for patterns stored with run length encoding
+pub const RLE_FLAG: u32 = 0x01000000;
+
+/// Size of stack buffer for decompressing a glyph (up to 32x32px)
+pub const GLYPH_BUF_WORDS: usize = 32;
+
+/// For holding packed pixels of a decompressed glyph
+pub type GlyphBuf = [u32; GLYPH_BUF_WORDS];
+
 /// Holds header data for a font glyph
 pub struct GlyphHeader {
     pub w: usize,
     pub h: usize,
     pub y_offset: usize,
+    pub rle: bool,
 }
 impl GlyphHeader {
-    /// Unpack glyph header of format: (w:u8)<<16 | (h:u8)<<8 | yOffset:u8
+    /// Unpack glyph header of format: rle<<24 | (w:u8)<<16 | (h:u8)<<8 | yOffset:u8
     pub fn new(header: u32) -> GlyphHeader {
         let w = ((header << 8) >> 24) as usize;
         let h = ((header << 16) >> 24) as usize;
         let y_offset = (header & 0x000000ff) as usize;
-        GlyphHeader { w, h, y_offset }
+        let rle = (header & RLE_FLAG) != 0;
+        GlyphHeader {
+            w,
+            h,
+            y_offset,
+            rle,
+        }
+    }
+}
+
+/// Decompress a run length encoded glyph pattern, using the same packing as
+/// uncompressed patterns. Runs are stored one per byte, MSB first within
+/// each word: bit 7 is the pixel value, bits 0..=6 are run length - 1.
+/// Return: None if the glyph is too big for a GlyphBuf
+pub fn unpack_rle(f: Font, gpo: usize, gh: &GlyphHeader) -> Option<GlyphBuf> {
+    let px_total = gh.w * gh.h;
+    if px_total > GLYPH_BUF_WORDS * 32 {
+        return None;
+    }
+    let mut buf: GlyphBuf = [0; GLYPH_BUF_WORDS];
+    let mut px = 0;
+    let mut src = gpo + 1;
+    while px < px_total {
+        let runs = (f.glyph_data)(src);
+        src += 1;
+        for shift in [24, 16, 8, 0].iter() {
+            let run = (runs >> shift) & 0xff;
+            let len = core::cmp::min((run & 0x7f) as usize + 1, px_total - px);
+            if run & 0x80 != 0 {
+                set_bits(&mut buf, px, len);
+            }
+            px += len;
+            if px >= px_total {
+                break;
+            }
+        }
+    }
+    Some(buf)
+}
+
+/// Set len bits of buf starting at bit px (bit 0 is MSB of buf[0])
+fn set_bits(buf: &mut GlyphBuf, mut px: usize, mut len: usize) {
+    while len > 0 {
+        let bit = px & 0x1f;
+        let n = core::cmp::min(32 - bit, len);
+        let mask = if n == 32 {
+            0xffffffff
+        } else {
+            ((1u32 << n) - 1) << (32 - bit - n)
+        };
+        buf[px >> 5] |= mask;
+        px += n;
+        len -= n;
     }
 }
 
 /// Available typeface glyph sets
+#[derive(Copy, Clone)]
 pub enum GlyphSet {
     Bold,
     Regular,
     Small,
+    Mono,
+}
+
+/// Fixed character cell size for monospace glyph sets
+#[derive(Copy, Clone)]
+#[allow(dead_code)]
+pub struct CellSize {
+    pub w: usize,
+    pub h: usize,
 }
 
 /// Abstraction for working with typeface glyph sets
@@ -47,29 +120,60 @@ pub enum GlyphSet {
 pub struct Font {
     pub glyph_pattern_offset: GlyphPatternOffsetFnPtr,
     pub glyph_data: GlyphDataFnPtr,
+    /// Fixed cell size for monospace glyph sets (None for proportional)
+    pub cell: Option<CellSize>,
 }
 pub type GlyphPatternOffsetFnPtr = fn(char) -> usize;
 pub type GlyphDataFnPtr = fn(usize) -> u32;
 impl Font {
     pub fn new(gs: GlyphSet) -> Font {
         match gs {
+            #[cfg(feature = "bold")]
             GlyphSet::Bold => Font {
                 glyph_pattern_offset: bold::get_glyph_pattern_offset,
                 glyph_data: bold_data,
+                cell: None,
             },
             GlyphSet::Regular => Font {
                 glyph_pattern_offset: regular::get_glyph_pattern_offset,
                 glyph_data: regular_data,
+                cell: None,
             },
+            #[cfg(feature = "small")]
             GlyphSet::Small => Font {
                 glyph_pattern_offset: small::get_glyph_pattern_offset,
                 glyph_data: small_data,
+                cell: None,
             },
+            #[cfg(feature = "mono")]
+            GlyphSet::Mono => Font {
+                glyph_pattern_offset: mono::get_glyph_pattern_offset,
+                glyph_data: mono_data,
+                cell: Some(CellSize {
+                    w: mono::CELL_W as usize,
+                    h: mono::CELL_H as usize,
+                }),
+            },
+            // Fonts left out of the build by cargo features use regular
+            #[allow(unreachable_patterns)]
+            _ => Font::new(GlyphSet::Regular),
         }
     }
 }
 
+/// Line height (maximum glyph height) of a glyph set
+pub fn max_height(gs: GlyphSet) -> usize {
+    let h = match gs {
+        GlyphSet::Bold => bold::MAX_HEIGHT,
+        GlyphSet::Regular => regular::MAX_HEIGHT,
+        GlyphSet::Small => small::MAX_HEIGHT,
+        GlyphSet::Mono => mono::MAX_HEIGHT,
+    };
+    h as usize
+}
+
 /// Get word of packed glyph data for bold
+#[cfg(feature = "bold")]
 pub fn bold_data(index: usize) -> u32 {
     bold::DATA[index]
 }
@@ -80,6 +184,112 @@ pub fn regular_data(index: usize) -> u32 {
 }
 
 /// Get word of packed glyph data for small
+#[cfg(feature = "small")]
 pub fn small_data(index: usize) -> u32 {
     small::DATA[index]
 }
+
+/// Get word of packed glyph data for mono
+#[cfg(feature = "mono")]
+pub fn mono_data(index: usize) -> u32 {
+    mono::DATA[index]
+}
+
+/// Flash footprint of one Unicode block of a font
+#[derive(Copy, Clone)]
+pub struct BlockCost {
+    pub block: &'static str,
+    pub enabled: bool,
+    pub bytes: usize,
+}
+impl BlockCost {
+    /// Size of a block's glyph patterns plus its index table
+    pub const fn new(block: &'static str, enabled: bool, data: &[u32], index: &[u16]) -> BlockCost {
+        let bytes = data.len() * 4 + index.len() * 2;
+        BlockCost {
+            block,
+            enabled,
+            bytes,
+        }
+    }
+}
+
+/// Flash footprint of a font, broken down by Unicode block
+#[derive(Copy, Clone)]
+pub struct FontCost {
+    pub font: &'static str,
+    pub enabled: bool,
+    pub blocks: &'static [BlockCost],
+}
+impl FontCost {
+    /// Total bytes of glyph data and index tables compiled into the build
+    pub fn bytes(&self) -> usize {
+        match self.enabled {
+            true => self
+                .blocks
+                .iter()
+                .filter(|b| b.enabled)
+                .map(|b| b.bytes)
+                .sum(),
+            false => 0,
+        }
+    }
+}
+
+/// Flash footprint of all fonts, for checking a build against its budget
+pub const FONT_COSTS: [FontCost; 4] = [
+    FontCost {
+        font: "Bold",
+        enabled: cfg!(feature = "bold"),
+        blocks: &bold::BLOCK_COSTS,
+    },
+    FontCost {
+        font: "Regular",
+        enabled: true,
+        blocks: &regular::BLOCK_COSTS,
+    },
+    FontCost {
+        font: "Small",
+        enabled: cfg!(feature = "small"),
+        blocks: &small::BLOCK_COSTS,
+    },
+    FontCost {
+        font: "Mono",
+        enabled: cfg!(feature = "mono"),
+        blocks: &mono::BLOCK_COSTS,
+    },
+];
+
+/// Length of a block's glyph patterns within DATA[] (0 if left out)
+pub const fn block_len(enabled: bool, data: &[u32]) -> usize {
+    match enabled {
+        true => data.len(),
+        false => 0,
+    }
+}
+
+/// A block's glyph patterns, or nothing if the block was left out
+pub const fn block_if(enabled: bool, data: &'static [u32]) -> &'static [u32] {
+    match enabled {
+        true => data,
+        false => &[],
+    }
+}
+
+/// Concatenate glyph pattern blocks into one DATA[] array at compile time
+pub const fn concat_blocks<const N: usize>(blocks: &[&[u32]]) -> [u32; N] {
+    let mut data = [0; N];
+    let mut n = 0;
+    let mut i = 0;
+    while i < blocks.len() {
+        let mut j = 0;
+        while j < blocks[i].len() {
+            data[n] = blocks[i][j];
+            n += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    assert!(n == N);
+    data
+}
@@ -1,19 +1,40 @@
 #![allow(dead_code)]
 //! Bold Font
 
-/// Return offset into DATA[] for start of pattern depicting glyph for character c
+/// Return offset into DATA[] for start of pattern depicting glyph for character c.
+/// Characters from optional blocks that were left out of the build by cargo
+/// features get the replacement character glyph.
 pub fn get_glyph_pattern_offset(c: char) -> usize {
     match c as u32 {
         0x20..=0x7E => BASIC_LATIN[(c as usize) - 0x20] as usize,
-        0xA0..=0xFF => LATIN_1[(c as usize) - 0xA0] as usize,
-        0x152..=0x153 => LATIN_EXTENDED_A[(c as usize) - 0x152] as usize,
-        0x2018..=0x2022 => GENERAL_PUNCTUATION[(c as usize) - 0x2018] as usize,
-        0x20AC..=0x20AC => CURRENCY_SYMBOLS[(c as usize) - 0x20AC] as usize,
-        0xE700..=0xE70C => PRIVATE_USE_AREA[(c as usize) - 0xE700] as usize,
-        _ => SPECIALS[(0xFFFD as usize) - 0xFFFD] as usize,
+        #[cfg(feature = "bold-latin-1")]
+        0xA0..=0xFF => LATIN_1_BASE + LATIN_1[(c as usize) - 0xA0] as usize,
+        #[cfg(feature = "bold-latin-extended-a")]
+        0x152..=0x153 => LATIN_EXTENDED_A_BASE + LATIN_EXTENDED_A[(c as usize) - 0x152] as usize,
+        #[cfg(feature = "bold-general-punctuation")]
+        0x2018..=0x2022 => GENERAL_PUNCTUATION_BASE + GENERAL_PUNCTUATION[(c as usize) - 0x2018] as usize,
+        #[cfg(feature = "bold-currency-symbols")]
+        0x20AC..=0x20AC => CURRENCY_SYMBOLS_BASE + CURRENCY_SYMBOLS[(c as usize) - 0x20AC] as usize,
+        0xE700..=0xE70C => PRIVATE_USE_AREA_BASE + PRIVATE_USE_AREA[(c as usize) - 0xE700] as usize,
+        _ => SPECIALS_BASE + SPECIALS[0] as usize,
     }
 }
 
+// Optional blocks are compiled in when their cargo feature is enabled
+const LATIN_1_ENABLED: bool = cfg!(feature = "bold-latin-1");
+const LATIN_EXTENDED_A_ENABLED: bool = cfg!(feature = "bold-latin-extended-a");
+const GENERAL_PUNCTUATION_ENABLED: bool = cfg!(feature = "bold-general-punctuation");
+const CURRENCY_SYMBOLS_ENABLED: bool = cfg!(feature = "bold-currency-symbols");
+
+// Offsets of each block's glyph patterns within DATA[]
+const PRIVATE_USE_AREA_BASE: usize = BASIC_LATIN_DATA.len();
+const SPECIALS_BASE: usize = PRIVATE_USE_AREA_BASE + PRIVATE_USE_AREA_DATA.len();
+const LATIN_1_BASE: usize = SPECIALS_BASE + SPECIALS_DATA.len();
+const LATIN_EXTENDED_A_BASE: usize = LATIN_1_BASE + super::block_len(LATIN_1_ENABLED, &LATIN_1_DATA);
+const GENERAL_PUNCTUATION_BASE: usize = LATIN_EXTENDED_A_BASE + super::block_len(LATIN_EXTENDED_A_ENABLED, &LATIN_EXTENDED_A_DATA);
+const CURRENCY_SYMBOLS_BASE: usize = GENERAL_PUNCTUATION_BASE + super::block_len(GENERAL_PUNCTUATION_ENABLED, &GENERAL_PUNCTUATION_DATA);
+const DATA_LEN: usize = CURRENCY_SYMBOLS_BASE + super::block_len(CURRENCY_SYMBOLS_ENABLED, &CURRENCY_SYMBOLS_DATA);
+
 // Index to Unicode Basic Latin block glyph patterns
 const BASIC_LATIN: [u16; 95] = [
     0, // ' '
@@ -115,157 +136,157 @@ const BASIC_LATIN: [u16; 95] = [
 
 // Index to Unicode Latin 1 block glyph patterns
 const LATIN_1: [u16; 96] = [
-    659, // ' '
-    661, // '¡'
-    665, // '¢'
-    671, // '£'
-    680, // '¤'
-    688, // '¥'
-    698, // '¦'
-    701, // '§'
-    710, // '¨'
-    712, // '©'
-    722, // 'ª'
-    728, // '«'
-    736, // '¬'
-    739, // '­'
-    741, // '®'
-    751, // '¯'
-    753, // '°'
-    756, // '±'
-    762, // '²'
-    765, // '³'
-    768, // '´'
-    771, // 'µ'
-    781, // '¶'
-    790, // '·'
-    792, // '¸'
-    794, // '¹'
-    797, // 'º'
-    803, // '»'
-    811, // '¼'
-    824, // '½'
-    837, // '¾'
-    850, // '¿'
-    858, // 'À'
-    868, // 'Á'
-    878, // 'Â'
-    888, // 'Ã'
-    898, // 'Ä'
-    908, // 'Å'
-    918, // 'Æ'
-    930, // 'Ç'
-    940, // 'È'
-    949, // 'É'
-    958, // 'Ê'
-    967, // 'Ë'
-    975, // 'Ì'
-    979, // 'Í'
-    983, // 'Î'
-    990, // 'Ï'
-    997, // 'Ð'
-    1006, // 'Ñ'
-    1018, // 'Ò'
-    1028, // 'Ó'
-    1038, // 'Ô'
-    1048, // 'Õ'
-    1058, // 'Ö'
-    1068, // '×'
-    1073, // 'Ø'
-    1085, // 'Ù'
-    1095, // 'Ú'
-    1105, // 'Û'
-    1115, // 'Ü'
-    1125, // 'Ý'
-    1135, // 'Þ'
-    1144, // 'ß'
-    1153, // 'à'
-    1162, // 'á'
-    1171, // 'â'
-    1180, // 'ã'
-    1189, // 'ä'
-    1197, // 'å'
-    1207, // 'æ'
-    1217, // 'ç'
-    1226, // 'è'
-    1235, // 'é'
-    1244, // 'ê'
-    1253, // 'ë'
-    1261, // 'ì'
-    1265, // 'í'
-    1269, // 'î'
-    1275, // 'ï'
-    1281, // 'ð'
-    1289, // 'ñ'
-    1298, // 'ò'
-    1307, // 'ó'
-    1316, // 'ô'
-    1325, // 'õ'
-    1334, // 'ö'
-    1342, // '÷'
-    1347, // 'ø'
-    1355, // 'ù'
-    1364, // 'ú'
-    1373, // 'û'
-    1382, // 'ü'
-    1390, // 'ý'
-    1401, // 'þ'
-    1411, // 'ÿ'
+    0, // ' '
+    2, // '¡'
+    6, // '¢'
+    12, // '£'
+    21, // '¤'
+    29, // '¥'
+    39, // '¦'
+    42, // '§'
+    51, // '¨'
+    53, // '©'
+    63, // 'ª'
+    69, // '«'
+    77, // '¬'
+    80, // '­'
+    82, // '®'
+    92, // '¯'
+    94, // '°'
+    97, // '±'
+    103, // '²'
+    106, // '³'
+    109, // '´'
+    112, // 'µ'
+    122, // '¶'
+    131, // '·'
+    133, // '¸'
+    135, // '¹'
+    138, // 'º'
+    144, // '»'
+    152, // '¼'
+    165, // '½'
+    178, // '¾'
+    191, // '¿'
+    199, // 'À'
+    209, // 'Á'
+    219, // 'Â'
+    229, // 'Ã'
+    239, // 'Ä'
+    249, // 'Å'
+    259, // 'Æ'
+    271, // 'Ç'
+    281, // 'È'
+    290, // 'É'
+    299, // 'Ê'
+    308, // 'Ë'
+    316, // 'Ì'
+    320, // 'Í'
+    324, // 'Î'
+    331, // 'Ï'
+    338, // 'Ð'
+    347, // 'Ñ'
+    359, // 'Ò'
+    369, // 'Ó'
+    379, // 'Ô'
+    389, // 'Õ'
+    399, // 'Ö'
+    409, // '×'
+    414, // 'Ø'
+    426, // 'Ù'
+    436, // 'Ú'
+    446, // 'Û'
+    456, // 'Ü'
+    466, // 'Ý'
+    476, // 'Þ'
+    485, // 'ß'
+    494, // 'à'
+    503, // 'á'
+    512, // 'â'
+    521, // 'ã'
+    530, // 'ä'
+    538, // 'å'
+    548, // 'æ'
+    558, // 'ç'
+    567, // 'è'
+    576, // 'é'
+    585, // 'ê'
+    594, // 'ë'
+    602, // 'ì'
+    606, // 'í'
+    610, // 'î'
+    616, // 'ï'
+    622, // 'ð'
+    630, // 'ñ'
+    639, // 'ò'
+    648, // 'ó'
+    657, // 'ô'
+    666, // 'õ'
+    675, // 'ö'
+    683, // '÷'
+    688, // 'ø'
+    696, // 'ù'
+    705, // 'ú'
+    714, // 'û'
+    723, // 'ü'
+    731, // 'ý'
+    742, // 'þ'
+    752, // 'ÿ'
 ];
 
 // Index to Unicode Latin Extended A block glyph patterns
 const LATIN_EXTENDED_A: [u16; 2] = [
-    1421, // 'Œ'
-    1433, // 'œ'
+    0, // 'Œ'
+    12, // 'œ'
 ];
 
 // Index to General Punctuation block glyph patterns
 const GENERAL_PUNCTUATION: [u16; 11] = [
-    1443, // '‘'
-    1445, // '’'
-    1447, // '‚'
-    1449, // '‛'
-    1451, // '“'
-    1455, // '”'
-    1459, // '„'
-    1463, // '‟'
-    1467, // '†'
-    1470, // '‡'
-    1474, // '•'
+    0, // '‘'
+    2, // '’'
+    4, // '‚'
+    6, // '‛'
+    8, // '“'
+    12, // '”'
+    16, // '„'
+    20, // '‟'
+    24, // '†'
+    27, // '‡'
+    31, // '•'
 ];
 
 // Index to Unicode Currency Symbols block glyph patterns
 const CURRENCY_SYMBOLS: [u16; 1] = [
-    1479, // '€'
+    0, // '€'
 ];
 
 // Index to Unicode Private Use Area block glyph patterns (UI sprites)
 const PRIVATE_USE_AREA: [u16; 13] = [
-    1488, // Battery_05
-    1498, // Battery_25
-    1508, // Battery_50
-    1518, // Battery_75
-    1528, // Battery_99
-    1538, // Radio_3
-    1551, // Radio_2
-    1564, // Radio_1
-    1577, // Radio_0
-    1590, // Radio_Off
-    1603, // Shift_Arrow
-    1611, // Backspace_Symbol
-    1627, // Enter_Symbol
+    0, // Battery_05
+    10, // Battery_25
+    20, // Battery_50
+    30, // Battery_75
+    40, // Battery_99
+    50, // Radio_3
+    63, // Radio_2
+    76, // Radio_1
+    89, // Radio_0
+    102, // Radio_Off
+    115, // Shift_Arrow
+    123, // Backspace_Symbol
+    139, // Enter_Symbol
 ];
 
 // Index to Unicode Specials block glyph patterns
 const SPECIALS: [u16; 1] = [
-    1639, // '�'
+    0, // '�'
 ];
 
 /// Maximum height of glyph patterns in this bitmap typeface.
 /// This will be true: h + yOffset <= MAX_HEIGHT
 pub const MAX_HEIGHT: u8 = 30;
 
-/// Packed glyph pattern data.
+/// Packed glyph pattern data for all compiled in blocks.
 /// Record format:
 ///  [offset+0]: ((w as u8) << 16) | ((h as u8) << 8) | (yOffset as u8)
 ///  [offset+1..=ceil(w*h/32)]: packed 1-bit pixels; 0=clear, 1=set
@@ -275,7 +296,29 @@ pub const MAX_HEIGHT: u8 = 30;
 ///  h: Height of pattern in pixels
 ///  yOffset: Vertical offset (pixels downward from top of line) to position
 ///     glyph pattern properly relative to text baseline
-pub const DATA: [u32; 1652] = [
+pub const DATA: [u32; DATA_LEN] = super::concat_blocks(&[
+    &BASIC_LATIN_DATA,
+    &PRIVATE_USE_AREA_DATA,
+    &SPECIALS_DATA,
+    super::block_if(LATIN_1_ENABLED, &LATIN_1_DATA),
+    super::block_if(LATIN_EXTENDED_A_ENABLED, &LATIN_EXTENDED_A_DATA),
+    super::block_if(GENERAL_PUNCTUATION_ENABLED, &GENERAL_PUNCTUATION_DATA),
+    super::block_if(CURRENCY_SYMBOLS_ENABLED, &CURRENCY_SYMBOLS_DATA),
+]);
+
+/// Flash footprint of each block (glyph patterns plus index)
+pub const BLOCK_COSTS: [super::BlockCost; 7] = [
+    super::BlockCost::new("Basic Latin", true, &BASIC_LATIN_DATA, &BASIC_LATIN),
+    super::BlockCost::new("Latin 1", LATIN_1_ENABLED, &LATIN_1_DATA, &LATIN_1),
+    super::BlockCost::new("Latin Extended A", LATIN_EXTENDED_A_ENABLED, &LATIN_EXTENDED_A_DATA, &LATIN_EXTENDED_A),
+    super::BlockCost::new("General Punctuation", GENERAL_PUNCTUATION_ENABLED, &GENERAL_PUNCTUATION_DATA, &GENERAL_PUNCTUATION),
+    super::BlockCost::new("Currency Symbols", CURRENCY_SYMBOLS_ENABLED, &CURRENCY_SYMBOLS_DATA, &CURRENCY_SYMBOLS),
+    super::BlockCost::new("Private Use Area", true, &PRIVATE_USE_AREA_DATA, &PRIVATE_USE_AREA),
+    super::BlockCost::new("Specials", true, &SPECIALS_DATA, &SPECIALS),
+];
+
+// Packed glyph patterns for Unicode Basic Latin block
+const BASIC_LATIN_DATA: [u32; 659] = [
     // [0]: 20 ' '
     0x0004020e, 0x00000000,
     // [2]: 21 '!'
@@ -481,325 +524,349 @@ pub const DATA: [u32; 1652] = [
     0x00061604, 0x0c330c30, 0xc30c30cc, 0x3030c30c, 0x30c30c0c, 0x30000000,
     // [656]: 7E '~'
     0x000c040a, 0xc3cc3c3c, 0x33c30000,
-    // [659]: A0 ' '
+];
+
+// Packed glyph patterns for Unicode Latin 1 block
+const LATIN_1_DATA: [u32; 762] = [
+    // [0]: A0 ' '
     0x0004020e, 0x00000000,
-    // [661]: A1 '¡'
+    // [2]: A1 '¡'
     0x00041206, 0xffff00ff, 0xffffffff, 0xff000000,
-    // [665]: A2 '¢'
+    // [6]: A2 '¢'
     0x000a1004, 0x0c0303f0, 0xfcccf330, 0xcc330cc3, 0x3ccf333f, 0x0fc0c030,
-    // [671]: A3 '£'
+    // [12]: A3 '£'
     0x000e1206, 0x0fc03f03, 0x0f0c3c00, 0xf003c00f, 0x003c03fc, 0x0ff00f00, 0x3c00f003, 0xcc0f303c,
     0x3ffcfff0,
-    // [680]: A4 '¤'
+    // [21]: A4 '¤'
     0x000e0e08, 0x400bbf77, 0xff8e1c70, 0x39806601, 0x98066019, 0xc0e3871f, 0xfeefdd00, 0x20000000,
-    // [688]: A5 '¥'
+    // [29]: A5 '¥'
     0x00101206, 0xf00ff00f, 0x3c3c3c3c, 0xffffffff, 0x03c003c0, 0xffffffff, 0x03c003c0, 0x03c003c0,
     0x03c003c0, 0x03c003c0,
-    // [698]: A6 '¦'
+    // [39]: A6 '¦'
     0x00021604, 0xfffff0ff, 0xfff00000,
-    // [701]: A7 '§'
+    // [42]: A7 '§'
     0x000a1804, 0x3f0fcc0f, 0x0303c0f0, 0xf03c3ccf, 0x3f0fc3c3, 0xf0fcf33c, 0x3c0f0f03, 0xc0c0f033,
     0xf0fc0000,
-    // [710]: A8 '¨'
+    // [51]: A8 '¨'
     0x00080206, 0xc3c30000,
-    // [712]: A9 '©'
+    // [53]: A9 '©'
     0x00101206, 0x0ff00ff0, 0x300c300c, 0xc3c3c3c3, 0xcc33cc33, 0xc033c033, 0xcc33cc33, 0xc3c3c3c3,
     0x300c300c, 0x0ff00ff0,
-    // [722]: AA 'ª'
+    // [63]: AA 'ª'
     0x000a1006, 0x3f0fcf0f, 0xc3ff3fcf, 0x3fcff3fc, 0xfff3fc00, 0x000fffff,
-    // [728]: AB '«'
+    // [69]: AB '«'
     0x000e0e0a, 0xc3030c03, 0x0c0c300c, 0x3030c030, 0xc0c30c30, 0x30c30c0c, 0x30c3030c, 0x00000000,
-    // [736]: AC '¬'
+    // [77]: AC '¬'
     0x000a060e, 0xfffffc03, 0x00c03000,
-    // [739]: AD '­'
+    // [80]: AD '­'
     0x000a020e, 0xfffff000,
-    // [741]: AE '®'
+    // [82]: AE '®'
     0x00101206, 0x0ff00ff0, 0x300c300c, 0xc3f3c3f3, 0xcc33cc33, 0xc3f3c3f3, 0xcc33cc33, 0xcc33cc33,
     0x300c300c, 0x0ff00ff0,
-    // [751]: AF '¯'
+    // [92]: AF '¯'
     0x00080204, 0xffff0000,
-    // [753]: B0 '°'
+    // [94]: B0 '°'
     0x00080806, 0x3c3cc3c3, 0xc3c33c3c,
-    // [756]: B1 '±'
+    // [97]: B1 '±'
     0x000a0e0a, 0x0c0300c0, 0x30fffff0, 0xc0300c03, 0x000000ff, 0xfff00000,
-    // [762]: B2 '²'
+    // [103]: B2 '²'
     0x00060a02, 0xfffc30ff, 0xf0c3fff0,
-    // [765]: B3 '³'
+    // [106]: B3 '³'
     0x00060a02, 0xfffc30ff, 0xfc30fff0,
-    // [768]: B4 '´'
+    // [109]: B4 '´'
     0x00060606, 0xc3030c0c, 0x30000000,
-    // [771]: B5 'µ'
+    // [112]: B5 'µ'
     0x0010120a, 0x3c3c3c3c, 0x3c3c3c3c, 0x3c3c3c3c, 0x3c3c3c3c, 0x3c3c3c3c, 0x3c3c3c3c, 0xcffccffc,
     0x000c000c, 0x00030003,
-    // [781]: B6 '¶'
+    // [122]: B6 '¶'
     0x000e1206, 0xfff3ffcc, 0xc3f30fcc, 0x3f30fcc3, 0xf30fcff3, 0x3fccc033, 0x00cc0330, 0x0cc03300,
     0xcc033000,
-    // [790]: B7 '·'
+    // [131]: B7 '·'
     0x0004040e, 0xffff0000,
-    // [792]: B8 '¸'
+    // [133]: B8 '¸'
     0x00040618, 0xffcc3300,
-    // [794]: B9 '¹'
+    // [135]: B9 '¹'
     0x00060a02, 0x30c3cf30, 0xc30cfff0,
-    // [797]: BA 'º'
+    // [138]: BA 'º'
     0x000a1006, 0x3f0fcf3f, 0xcff3fcff, 0x3fcff3fc, 0xf3f0fc00, 0x000fffff,
-    // [803]: BB '»'
+    // [144]: BB '»'
     0x000e0e0a, 0x030c0c30, 0xc3030c30, 0xc0c30c30, 0x30c030c0, 0xc300c303, 0x0c030c0c, 0x30000000,
-    // [811]: BC '¼'
+    // [152]: BC '¼'
     0x00121402, 0x0c030300, 0xc0c03c30, 0x0f030300, 0xc0c03030, 0x0c0c00cf, 0xc033fccc, 0x033300cc,
     0x30330c0f, 0xc303f0c0, 0xc00c3003, 0x0c00c300, 0x30000000,
-    // [824]: BD '½'
+    // [165]: BD '½'
     0x00121402, 0x0c030300, 0xc0c03c30, 0x0f030300, 0xc0c03030, 0x0c0c00cf, 0xc033ffcc, 0x03f300c0,
     0x30300c0f, 0xc303f0c0, 0x0c0c0303, 0x0fc0c3f0, 0x30000000,
-    // [837]: BE '¾'
+    // [178]: BE '¾'
     0x00121402, 0x0c0fc303, 0xf0c0c030, 0x30030fc0, 0xc3f030c0, 0x0c3000cf, 0xc033fccc, 0x033300cc,
     0x30330c0f, 0xc303f0c0, 0xc00c3003, 0x0c00c300, 0x30000000,
-    // [850]: BF '¿'
+    // [191]: BF '¿'
     0x000c1206, 0x0f00f00f, 0x00f00000, 0x000f00f0, 0x0f00f003, 0xc03c00f0, 0x0fc0fc0f, 0x3fc3fc00,
-    // [858]: C0 'À'
+    // [199]: C0 'À'
     0x000c1800, 0x0300300c, 0x00c00000, 0x003fc3fc, 0xf0ff0ff0, 0xff0ff0ff, 0x0fffffff, 0xf0ff0ff0,
     0xff0ff0ff, 0x0ff0ff0f,
-    // [868]: C1 'Á'
+    // [209]: C1 'Á'
     0x000c1800, 0x0c00c003, 0x00300000, 0x003fc3fc, 0xf0ff0ff0, 0xff0ff0ff, 0x0fffffff, 0xf0ff0ff0,
     0xff0ff0ff, 0x0ff0ff0f,
-    // [878]: C2 'Â'
+    // [219]: C2 'Â'
     0x000c1800, 0x0f00f030, 0xc30c0000, 0x003fc3fc, 0xf0ff0ff0, 0xff0ff0ff, 0x0fffffff, 0xf0ff0ff0,
     0xff0ff0ff, 0x0ff0ff0f,
-    // [888]: C3 'Ã'
+    // [229]: C3 'Ã'
     0x000c1800, 0xc3cc3c3c, 0x33c30000, 0x003fc3fc, 0xf0ff0ff0, 0xff0ff0ff, 0x0fffffff, 0xf0ff0ff0,
     0xff0ff0ff, 0x0ff0ff0f,
-    // [898]: C4 'Ä'
+    // [239]: C4 'Ä'
     0x000c1602, 0x30c30c00, 0x00003fc3, 0xfcf0ff0f, 0xf0ff0ff0, 0xff0fffff, 0xfff0ff0f, 0xf0ff0ff0,
     0xff0ff0ff, 0x0f000000,
-    // [908]: C5 'Å'
+    // [249]: C5 'Å'
     0x000c1800, 0x0f00f030, 0xc30c30c3, 0x0c3fc3fc, 0xf0ff0ff0, 0xff0ff0ff, 0x0fffffff, 0xf0ff0ff0,
     0xff0ff0ff, 0x0ff0ff0f,
-    // [918]: C6 'Æ'
+    // [259]: C6 'Æ'
     0x00121206, 0xffff3fff, 0xc03c3c0f, 0x0f03c3c0, 0xf0f03c3c, 0x0f0f3fff, 0xcffff03c, 0x3c0f0f03,
     0xc3c0f0f0, 0x3c3c0f0f, 0xffc3fff0, 0xf0000000,
-    // [930]: C7 'Ç'
+    // [271]: C7 'Ç'
     0x000c1806, 0x3fc3fcc0, 0xfc0f00f0, 0x0f00f00f, 0x00f00f00, 0xf00f00f0, 0x0fc0fc0f, 0x3fc3fc0f,
     0x00f00c00, 0xc0030030,
-    // [940]: C8 'È'
+    // [281]: C8 'È'
     0x000a1800, 0x0300c0c0, 0x3000000f, 0xffff03c0, 0xf03c0f03, 0xc0f3fcff, 0x03c0f03c, 0x0f03c0ff,
     0xffff0000,
-    // [949]: C9 'É'
+    // [290]: C9 'É'
     0x000a1800, 0x300c00c0, 0x3000000f, 0xffff03c0, 0xf03c0f03, 0xc0f3fcff, 0x03c0f03c, 0x0f03c0ff,
     0xffff0000,
-    // [958]: CA 'Ê'
+    // [299]: CA 'Ê'
     0x000a1800, 0x0f03c30c, 0xc300000f, 0xffff03c0, 0xf03c0f03, 0xc0f3fcff, 0x03c0f03c, 0x0f03c0ff,
     0xffff0000,
-    // [967]: CB 'Ë'
+    // [308]: CB 'Ë'
     0x000a1602, 0x330cc000, 0x00fffff0, 0x3c0f03c0, 0xf03c0f3f, 0xcff03c0f, 0x03c0f03c, 0x0ffffff0,
-    // [975]: CC 'Ì'
+    // [316]: CC 'Ì'
     0x00041800, 0x33cc00ff, 0xffffffff, 0xffffffff,
-    // [979]: CD 'Í'
+    // [320]: CD 'Í'
     0x00041800, 0xcc3300ff, 0xffffffff, 0xffffffff,
-    // [983]: CE 'Î'
+    // [324]: CE 'Î'
     0x00081800, 0x3c3cc3c3, 0x00003c3c, 0x3c3c3c3c, 0x3c3c3c3c, 0x3c3c3c3c, 0x3c3c3c3c,
-    // [990]: CF 'Ï'
+    // [331]: CF 'Ï'
     0x00081602, 0xc3c30000, 0x3c3c3c3c, 0x3c3c3c3c, 0x3c3c3c3c, 0x3c3c3c3c, 0x3c3c0000,
-    // [997]: D0 'Ð'
+    // [338]: D0 'Ð'
     0x000e1206, 0x3ff0ffcf, 0x0f3c3cf0, 0xf3c3cf0f, 0x3c3cf3ff, 0xcfff0f3c, 0x3cf0f3c3, 0xcf0f3c3c,
     0x3ff0ffc0,
-    // [1006]: D1 'Ñ'
+    // [347]: D1 'Ñ'
     0x000e1800, 0x30f0c3c0, 0xf0c3c300, 0x00000c00, 0xf003c03f, 0x00fc0ff0, 0x3fc3ff0f, 0xfcfcf3f3,
     0xff0ffc3f, 0xc0ff03f0, 0x0fc03c00, 0xf0030000,
-    // [1018]: D2 'Ò'
+    // [359]: D2 'Ò'
     0x000c1800, 0x0300300c, 0x00c00000, 0x003fc3fc, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xf0ff0ff0,
     0xff0ff0ff, 0x0f3fc3fc,
-    // [1028]: D3 'Ó'
+    // [369]: D3 'Ó'
     0x000c1800, 0x0c00c003, 0x00300000, 0x003fc3fc, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xf0ff0ff0,
     0xff0ff0ff, 0x0f3fc3fc,
-    // [1038]: D4 'Ô'
+    // [379]: D4 'Ô'
     0x000c1800, 0x0f00f030, 0xc30c0000, 0x003fc3fc, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xf0ff0ff0,
     0xff0ff0ff, 0x0f3fc3fc,
-    // [1048]: D5 'Õ'
+    // [389]: D5 'Õ'
     0x000c1800, 0xc3cc3c3c, 0x33c30000, 0x003fc3fc, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xf0ff0ff0,
     0xff0ff0ff, 0x0f3fc3fc,
-    // [1058]: D6 'Ö'
+    // [399]: D6 'Ö'
     0x000c1602, 0x30c30c00, 0x00003fc3, 0xfcf0ff0f, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xf0ff0ff0,
     0xff0f3fc3, 0xfc000000,
-    // [1068]: D7 '×'
+    // [409]: D7 '×'
     0x000a0a0e, 0xc0f03330, 0xcc0c0303, 0x30ccc0f0, 0x30000000,
-    // [1073]: D8 'Ø'
+    // [414]: D8 'Ø'
     0x00121206, 0xcff033fc, 0x03c3c0f0, 0xf03c3c0f, 0x0f03f3c0, 0xfcf03cfc, 0x0f3f03c3, 0xc0f0f03c,
     0x3c0f0f03, 0xc3f0f0fc, 0x0ff0c3fc, 0x30000000,
-    // [1085]: D9 'Ù'
+    // [426]: D9 'Ù'
     0x000c1800, 0x0300300c, 0x00c00000, 0x00f0ff0f, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xf0ff0ff0,
     0xff0ff0ff, 0x0f3fc3fc,
-    // [1095]: DA 'Ú'
+    // [436]: DA 'Ú'
     0x000c1800, 0x0c00c003, 0x00300000, 0x00f0ff0f, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xf0ff0ff0,
     0xff0ff0ff, 0x0f3fc3fc,
-    // [1105]: DB 'Û'
+    // [446]: DB 'Û'
     0x000c1800, 0x0f00f030, 0xc30c0000, 0x00f0ff0f, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xf0ff0ff0,
     0xff0ff0ff, 0x0f3fc3fc,
-    // [1115]: DC 'Ü'
+    // [456]: DC 'Ü'
     0x000c1602, 0x30c30c00, 0x0000f0ff, 0x0ff0ff0f, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xf0ff0ff0,
     0xff0f3fc3, 0xfc000000,
-    // [1125]: DD 'Ý'
+    // [466]: DD 'Ý'
     0x000c1800, 0x0c00c003, 0x00300000, 0x00f0ff0f, 0xf0ff0ff0, 0xff0ff0ff, 0x0f3fc3fc, 0x0f00f00f,
     0x00f00f00, 0xf00f00f0,
-    // [1135]: DE 'Þ'
+    // [476]: DE 'Þ'
     0x000e1206, 0x03fc0ff0, 0x0f003c3f, 0xf0ffcf0f, 0x3c3cf0f3, 0xc3cf0f3c, 0x3c3ff0ff, 0xc00f003c,
     0x03fc0ff0,
-    // [1144]: DF 'ß'
+    // [485]: DF 'ß'
     0x000e1206, 0x0ff03fc3, 0xc3cf0f3c, 0x3cf0f0f3, 0xc3cf3c3c, 0xf0ff03fc, 0x0ff03fc0, 0xff03fc0f,
     0x3f3cfcf0,
-    // [1153]: E0 'à'
+    // [494]: E0 'à'
     0x000c1404, 0x0300300c, 0x00c00000, 0x003fc3fc, 0xf03f03ff, 0xcffcf0ff, 0x0ff0ff0f, 0xf0ff0fff,
     0xcffc0000,
-    // [1162]: E1 'á'
+    // [503]: E1 'á'
     0x000c1404, 0x0c00c003, 0x00300000, 0x003fc3fc, 0xf03f03ff, 0xcffcf0ff, 0x0ff0ff0f, 0xf0ff0fff,
     0xcffc0000,
-    // [1171]: E2 'â'
+    // [512]: E2 'â'
     0x000c1404, 0x0f00f030, 0xc30c0000, 0x003fc3fc, 0xf03f03ff, 0xcffcf0ff, 0x0ff0ff0f, 0xf0ff0fff,
     0xcffc0000,
-    // [1180]: E3 'ã'
+    // [521]: E3 'ã'
     0x000c1404, 0xc3cc3c3c, 0x33c30000, 0x003fc3fc, 0xf03f03ff, 0xcffcf0ff, 0x0ff0ff0f, 0xf0ff0fff,
     0xcffc0000,
-    // [1189]: E4 'ä'
+    // [530]: E4 'ä'
     0x000c1206, 0x30c30c00, 0x00003fc3, 0xfcf03f03, 0xffcffcf0, 0xff0ff0ff, 0x0ff0ff0f, 0xffcffc00,
-    // [1197]: E5 'å'
+    // [538]: E5 'å'
     0x000c1800, 0x0f00f030, 0xc30c30c3, 0x0c0f00f0, 0x0000003f, 0xc3fcf03f, 0x03ffcffc, 0xf0ff0ff0,
     0xff0ff0ff, 0x0fffcffc,
-    // [1207]: E6 'æ'
+    // [548]: E6 'æ'
     0x00140e0a, 0x3fffc3ff, 0xfcf0f03f, 0x0f03f0ff, 0xcf0ffcff, 0xf0ffff0f, 0x00f0f00f, 0x0fc0f0fc,
     0x0f0f3fff, 0xc3fffc00,
-    // [1217]: E7 'ç'
+    // [558]: E7 'ç'
     0x000c140a, 0x3fc3fcc0, 0xfc0f00f0, 0x0f00f00f, 0x00f00fc0, 0xfc0f3fc3, 0xfc0f00f0, 0x0c00c003,
     0x00300000,
-    // [1226]: E8 'è'
+    // [567]: E8 'è'
     0x000c1404, 0x0300300c, 0x00c00000, 0x003fc3fc, 0xf0ff0ff0, 0xff0fffff, 0xff00f00f, 0xc0fc0f3f,
     0xc3fc0000,
-    // [1235]: E9 'é'
+    // [576]: E9 'é'
     0x000c1404, 0x0c00c003, 0x00300000, 0x003fc3fc, 0xf0ff0ff0, 0xff0fffff, 0xff00f00f, 0xc0fc0f3f,
     0xc3fc0000,
-    // [1244]: EA 'ê'
+    // [585]: EA 'ê'
     0x000c1404, 0x0f00f030, 0xc30c0000, 0x003fc3fc, 0xf0ff0ff0, 0xff0fffff, 0xff00f00f, 0xc0fc0f3f,
     0xc3fc0000,
-    // [1253]: EB 'ë'
+    // [594]: EB 'ë'
     0x000c1206, 0x30c30c00, 0x00003fc3, 0xfcf0ff0f, 0xf0ff0fff, 0xffff00f0, 0x0fc0fc0f, 0x3fc3fc00,
-    // [1261]: EC 'ì'
+    // [602]: EC 'ì'
     0x00041404, 0x33cc00ff, 0xffffffff, 0xffff0000,
-    // [1265]: ED 'í'
+    // [606]: ED 'í'
     0x00041404, 0xcc3300ff, 0xffffffff, 0xffff0000,
-    // [1269]: EE 'î'
+    // [610]: EE 'î'
     0x00081404, 0x3c3cc3c3, 0x00003c3c, 0x3c3c3c3c, 0x3c3c3c3c, 0x3c3c3c3c,
-    // [1275]: EF 'ï'
+    // [616]: EF 'ï'
     0x00081206, 0xc3c30000, 0x3c3c3c3c, 0x3c3c3c3c, 0x3c3c3c3c, 0x3c3c0000,
-    // [1281]: F0 'ð'
+    // [622]: F0 'ð'
     0x000c1206, 0x03f73f3f, 0x00fc3ce3, 0xc0ff0ff0, 0xf3cf3cf0, 0xff0ff0ff, 0x0f30f30f, 0x0fc0fc00,
-    // [1289]: F1 'ñ'
+    // [630]: F1 'ñ'
     0x000c1404, 0xc3cc3c3c, 0x33c30000, 0x003ff3ff, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xf0ff0ff0,
     0xff0f0000,
-    // [1298]: F2 'ò'
+    // [639]: F2 'ò'
     0x000c1404, 0x0300300c, 0x00c00000, 0x003fc3fc, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xf0ff0f3f,
     0xc3fc0000,
-    // [1307]: F3 'ó'
+    // [648]: F3 'ó'
     0x000c1404, 0x0c00c003, 0x00300000, 0x003fc3fc, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xf0ff0f3f,
     0xc3fc0000,
-    // [1316]: F4 'ô'
+    // [657]: F4 'ô'
     0x000c1404, 0x0f00f030, 0xc30c0000, 0x003fc3fc, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xf0ff0f3f,
     0xc3fc0000,
-    // [1325]: F5 'õ'
+    // [666]: F5 'õ'
     0x000c1404, 0xc3cc3c3c, 0x33c30000, 0x003fc3fc, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xf0ff0f3f,
     0xc3fc0000,
-    // [1334]: F6 'ö'
+    // [675]: F6 'ö'
     0x000c1206, 0x30c30c00, 0x00003fc3, 0xfcf0ff0f, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0x3fc3fc00,
-    // [1342]: F7 '÷'
+    // [683]: F7 '÷'
     0x000a0a0a, 0x0c030000, 0x00fffff0, 0x00000c03, 0x00000000,
-    // [1347]: F8 'ø'
+    // [688]: F8 'ø'
     0x00100e0a, 0xcff0cff0, 0x3c3c3c3c, 0x3c3c3c3c, 0x3f3c3f3c, 0x3cfc3cfc, 0x3c3c3c3c, 0x0ff30ff3,
-    // [1355]: F9 'ù'
+    // [696]: F9 'ù'
     0x000c1404, 0x0300300c, 0x00c00000, 0x00f0ff0f, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xfcffcff3,
     0xcf3c0000,
-    // [1364]: FA 'ú'
+    // [705]: FA 'ú'
     0x000c1404, 0x0c00c003, 0x00300000, 0x00f0ff0f, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xfcffcff3,
     0xcf3c0000,
-    // [1373]: FB 'û'
+    // [714]: FB 'û'
     0x000c1404, 0x0f00f030, 0xc30c0000, 0x00f0ff0f, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xfcffcff3,
     0xcf3c0000,
-    // [1382]: FC 'ü'
+    // [723]: FC 'ü'
     0x000c1206, 0x30c30c00, 0x0000f0ff, 0x0ff0ff0f, 0xf0ff0ff0, 0xff0ff0ff, 0x0ffcffcf, 0xf3cf3c00,
-    // [1390]: FD 'ý'
+    // [731]: FD 'ý'
     0x000c1a04, 0x0c00c003, 0x00300000, 0x00f0ff0f, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xf0ff0fff,
     0xcffcf00f, 0x00f03f03, 0x3fc3fc00,
-    // [1401]: FE 'þ'
+    // [742]: FE 'þ'
     0x000c1606, 0x00f00f00, 0xf00f3ff3, 0xfff0ff0f, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0x3ff3ff00,
     0xf00f00f0, 0x0f000000,
-    // [1411]: FF 'ÿ'
+    // [752]: FF 'ÿ'
     0x000c1806, 0x30c30c00, 0x0000f0ff, 0x0ff0ff0f, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xffcffcf0,
     0x0f00f03f, 0x033fc3fc,
-    // [1421]: 152 'Œ'
+];
+
+// Packed glyph patterns for Unicode Latin Extended A block
+const LATIN_EXTENDED_A_DATA: [u32; 22] = [
+    // [0]: 152 'Œ'
     0x00121206, 0xffff3fff, 0xc03c3c0f, 0x0f03c3c0, 0xf0f03c3c, 0x0f0f3fc3, 0xcff0f03c, 0x3c0f0f03,
     0xc3c0f0f0, 0x3c3c0f0f, 0xffff3fff, 0xc0000000,
-    // [1433]: 153 'œ'
+    // [12]: 153 'œ'
     0x00140e0a, 0x3fffc3ff, 0xfcf0f0ff, 0x0f0ff0f0, 0xff0f0fff, 0xf0ffff0f, 0x00f0f00f, 0x0fc0f0fc,
     0x0f0f3fff, 0xc3fffc00,
-    // [1443]: 2018 '‘'
+];
+
+// Packed glyph patterns for General Punctuation block
+const GENERAL_PUNCTUATION_DATA: [u32; 36] = [
+    // [0]: 2018 '‘'
     0x00040806, 0xcc33ffff,
-    // [1445]: 2019 '’'
+    // [2]: 2019 '’'
     0x00040806, 0xffffcc33,
-    // [1447]: 201A '‚'
+    // [4]: 201A '‚'
     0x00040814, 0xffffcc33,
-    // [1449]: 201B '‛'
+    // [6]: 201B '‛'
     0x00040806, 0xffff33cc,
-    // [1451]: 201C '“'
+    // [8]: 201C '“'
     0x000a0806, 0xc330c30c, 0xc3f3fcff, 0x3fcf0000,
-    // [1455]: 201D '”'
+    // [12]: 201D '”'
     0x000a0806, 0xf3fcff3f, 0xcfc330c3, 0x0cc30000,
-    // [1459]: 201E '„'
+    // [16]: 201E '„'
     0x000a0814, 0xf3fcff3f, 0xcfc330c3, 0x0cc30000,
-    // [1463]: 201F '‟'
+    // [20]: 201F '‟'
     0x000a0806, 0xf3fcff3f, 0xcf30cc3c, 0x330c0000,
-    // [1467]: 2020 '†'
+    // [24]: 2020 '†'
     0x00060a06, 0x30cfff30, 0xc30c30c0,
-    // [1470]: 2021 '‡'
+    // [27]: 2021 '‡'
     0x00060c06, 0x30cfff30, 0xc30cfff3, 0x0c000000,
-    // [1474]: 2022 '•'
+    // [31]: 2022 '•'
     0x000a0a0a, 0x3f0fcfff, 0xffffffff, 0xffff3f0f, 0xc0000000,
-    // [1479]: 20AC '€'
+];
+
+// Packed glyph patterns for Unicode Currency Symbols block
+const CURRENCY_SYMBOLS_DATA: [u32; 9] = [
+    // [0]: 20AC '€'
     0x00101008, 0x3fc03fc0, 0xc030c030, 0x000c000c, 0x3fff3fff, 0x000c000c, 0x0fff0fff, 0xc030c030,
     0x3fc03fc0,
-    // [1488]: E700 Battery_05
+];
+
+// Packed glyph patterns for Unicode Private Use Area block
+const PRIVATE_USE_AREA_DATA: [u32; 151] = [
+    // [0]: E700 Battery_05
     0x00180c0c, 0x3ffffe40, 0x00014000, 0x0dc0000d, 0xc0000dc0, 0x000dc000, 0x0dc0000d, 0xc0000d40,
     0x000d4000, 0x013ffffe,
-    // [1498]: E701 Battery_25
+    // [10]: E701 Battery_25
     0x00180c0c, 0x3ffffe40, 0x00014000, 0x7dc0007d, 0xc0007dc0, 0x007dc000, 0x7dc0007d, 0xc0007d40,
     0x007d4000, 0x013ffffe,
-    // [1508]: E702 Battery_50
+    // [20]: E702 Battery_50
     0x00180c0c, 0x3ffffe40, 0x0001400f, 0xfdc00ffd, 0xc00ffdc0, 0x0ffdc00f, 0xfdc00ffd, 0xc00ffd40,
     0x0ffd4000, 0x013ffffe,
-    // [1518]: E703 Battery_75
+    // [30]: E703 Battery_75
     0x00180c0c, 0x3ffffe40, 0x000141ff, 0xfdc1fffd, 0xc1fffdc1, 0xfffdc1ff, 0xfdc1fffd, 0xc1fffd41,
     0xfffd4000, 0x013ffffe,
-    // [1528]: E704 Battery_99
+    // [40]: E704 Battery_99
     0x00180c0c, 0x3ffffe40, 0x00015fff, 0xfddffffd, 0xdffffddf, 0xfffddfff, 0xfddffffd, 0xdffffd5f,
     0xfffd4000, 0x013ffffe,
-    // [1538]: E705 Radio_3
+    // [50]: E705 Radio_3
     0x00151107, 0x00f8001f, 0xf003e3e0, 0x3c078380, 0x0e387c3b, 0x8ff8e8f1, 0xe20e0380, 0xe10e023e,
     0x2003f800, 0x38e00082, 0x00008000, 0x0e000020, 0x00000000,
-    // [1551]: E706 Radio_2
+    // [63]: E706 Radio_2
     0x00151107, 0x00000000, 0x00000000, 0x00000000, 0x00007c00, 0x0ff800f1, 0xe00e0380, 0xe10e023e,
     0x2003f800, 0x38e00082, 0x00008000, 0x0e000020, 0x00000000,
-    // [1564]: E707 Radio_1
+    // [76]: E707 Radio_1
     0x00151107, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x0100003e,
     0x0003f800, 0x38e00082, 0x00008000, 0x0e000020, 0x00000000,
-    // [1577]: E708 Radio_0
+    // [89]: E708 Radio_0
     0x00151107, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
     0x00000000, 0x00000000, 0x00008000, 0x0e000020, 0x00000000,
-    // [1590]: E709 Radio_Off
+    // [102]: E709 Radio_Off
     0x00151107, 0x00f80018, 0x30030060, 0x20008200, 0x0220000a, 0x00002800, 0x02200020, 0x80020200,
     0x20080200, 0x20200082, 0x00022000, 0x0a000020, 0x00000000,
-    // [1603]: E70A Shift_Arrow
+    // [115]: E70A Shift_Arrow
     0x000a1406, 0x0c0783f1, 0xfefffff0, 0xc0300c03, 0x00c0300c, 0x0300c030, 0x0c0300c0, 0x30000000,
-    // [1611]: E70B Backspace_Symbol
+    // [123]: E70B Backspace_Symbol
     0x001a1206, 0xffffc03f, 0xfff80c00, 0x07030000, 0xe0c6061c, 0x31c3838c, 0x39c07307, 0xe00ec0f0,
     0x01f03c00, 0x7c1f803b, 0x0e701cc7, 0x0e0e3181, 0x870c0003, 0x830001c0, 0xffffe03f, 0xfff00000,
-    // [1627]: E70C Enter_Symbol
+    // [139]: E70C Enter_Symbol
     0x00180e08, 0xc00000c0, 0x0000c000, 0x00c00000, 0xc00030c0, 0x0038c000, 0x3cc0003e, 0xffffffff,
     0xffff0000, 0x3e00003c, 0x00003800, 0x00300000,
-    // [1639]: FFFD '�'
+];
+
+// Packed glyph patterns for Unicode Specials block
+const SPECIALS_DATA: [u32; 13] = [
+    // [0]: FFFD '�'
     0x00121404, 0x00c00030, 0x003f000f, 0xc00f3c03, 0xcf03ccf0, 0xf33cfcff, 0xff3ffff3, 0xfffcff3f,
     0xff0fffc0, 0xf3c03cf0, 0x03f000fc, 0x000c0003, 0x00000000,
 ];
@@ -0,0 +1,1087 @@
+#![allow(dead_code)]
+//! Mono Font
+
+/// Return offset into DATA[] for start of pattern depicting glyph for character c.
+/// Characters from optional blocks that were left out of the build by cargo
+/// features get the replacement character glyph.
+pub fn get_glyph_pattern_offset(c: char) -> usize {
+    match c as u32 {
+        0x20..=0x7E => BASIC_LATIN[(c as usize) - 0x20] as usize,
+        #[cfg(feature = "mono-latin-1")]
+        0xA0..=0xFF => LATIN_1_BASE + LATIN_1[(c as usize) - 0xA0] as usize,
+        #[cfg(feature = "mono-box-drawing")]
+        0x2500..=0x257F => BOX_DRAWING_BASE + BOX_DRAWING[(c as usize) - 0x2500] as usize,
+        _ => SPECIALS_BASE + SPECIALS[0] as usize,
+    }
+}
+
+// Optional blocks are compiled in when their cargo feature is enabled
+const LATIN_1_ENABLED: bool = cfg!(feature = "mono-latin-1");
+const BOX_DRAWING_ENABLED: bool = cfg!(feature = "mono-box-drawing");
+
+// Offsets of each block's glyph patterns within DATA[]
+const SPECIALS_BASE: usize = BASIC_LATIN_DATA.len();
+const LATIN_1_BASE: usize = SPECIALS_BASE + SPECIALS_DATA.len();
+const BOX_DRAWING_BASE: usize = LATIN_1_BASE + super::block_len(LATIN_1_ENABLED, &LATIN_1_DATA);
+const DATA_LEN: usize = BOX_DRAWING_BASE + super::block_len(BOX_DRAWING_ENABLED, &BOX_DRAWING_DATA);
+
+// Index to Unicode Basic Latin block glyph patterns
+const BASIC_LATIN: [u16; 95] = [
+    0, // ' '
+    2, // '!'
+    6, // '"'
+    8, // '#'
+    13, // '$'
+    20, // '%'
+    26, // '&'
+    32, // '''
+    34, // '('
+    40, // ')'
+    46, // '*'
+    51, // '+'
+    56, // ','
+    59, // '-'
+    61, // '.'
+    63, // '/'
+    69, // '0'
+    75, // '1'
+    80, // '2'
+    86, // '3'
+    92, // '4'
+    98, // '5'
+    104, // '6'
+    110, // '7'
+    116, // '8'
+    122, // '9'
+    128, // ':'
+    131, // ';'
+    136, // '<'
+    140, // '='
+    143, // '>'
+    147, // '?'
+    153, // '@'
+    159, // 'A'
+    165, // 'B'
+    171, // 'C'
+    177, // 'D'
+    183, // 'E'
+    189, // 'F'
+    195, // 'G'
+    201, // 'H'
+    207, // 'I'
+    211, // 'J'
+    217, // 'K'
+    223, // 'L'
+    229, // 'M'
+    235, // 'N'
+    241, // 'O'
+    247, // 'P'
+    253, // 'Q'
+    259, // 'R'
+    265, // 'S'
+    271, // 'T'
+    277, // 'U'
+    283, // 'V'
+    289, // 'W'
+    295, // 'X'
+    301, // 'Y'
+    307, // 'Z'
+    313, // '['
+    319, // '\'
+    325, // ']'
+    331, // '^'
+    333, // '_'
+    335, // '`'
+    337, // 'a'
+    342, // 'b'
+    348, // 'c'
+    353, // 'd'
+    359, // 'e'
+    364, // 'f'
+    370, // 'g'
+    376, // 'h'
+    382, // 'i'
+    386, // 'j'
+    392, // 'k'
+    398, // 'l'
+    402, // 'm'
+    407, // 'n'
+    412, // 'o'
+    417, // 'p'
+    423, // 'q'
+    429, // 'r'
+    434, // 's'
+    439, // 't'
+    444, // 'u'
+    449, // 'v'
+    454, // 'w'
+    459, // 'x'
+    464, // 'y'
+    470, // 'z'
+    475, // '{'
+    481, // '|'
+    486, // '}'
+    492, // '~'
+];
+
+// Index to Unicode Latin 1 block glyph patterns
+const LATIN_1: [u16; 96] = [
+    0, // ' '
+    2, // '¡'
+    6, // '¢'
+    12, // '£'
+    18, // '¤'
+    24, // '¥'
+    30, // '¦'
+    35, // '§'
+    42, // '¨'
+    44, // '©'
+    50, // 'ª'
+    55, // '«'
+    60, // '¬'
+    63, // '­'
+    65, // '®'
+    71, // '¯'
+    73, // '°'
+    77, // '±'
+    82, // '²'
+    86, // '³'
+    90, // '´'
+    92, // 'µ'
+    98, // '¶'
+    105, // '·'
+    107, // '¸'
+    109, // '¹'
+    113, // 'º'
+    118, // '»'
+    123, // '¼'
+    130, // '½'
+    137, // '¾'
+    144, // '¿'
+    150, // 'À'
+    158, // 'Á'
+    166, // 'Â'
+    174, // 'Ã'
+    182, // 'Ä'
+    189, // 'Å'
+    197, // 'Æ'
+    203, // 'Ç'
+    210, // 'È'
+    218, // 'É'
+    226, // 'Ê'
+    234, // 'Ë'
+    241, // 'Ì'
+    247, // 'Í'
+    253, // 'Î'
+    259, // 'Ï'
+    265, // 'Ð'
+    271, // 'Ñ'
+    279, // 'Ò'
+    287, // 'Ó'
+    295, // 'Ô'
+    303, // 'Õ'
+    311, // 'Ö'
+    318, // '×'
+    323, // 'Ø'
+    329, // 'Ù'
+    337, // 'Ú'
+    345, // 'Û'
+    353, // 'Ü'
+    360, // 'Ý'
+    368, // 'Þ'
+    374, // 'ß'
+    380, // 'à'
+    386, // 'á'
+    392, // 'â'
+    398, // 'ã'
+    404, // 'ä'
+    410, // 'å'
+    417, // 'æ'
+    422, // 'ç'
+    428, // 'è'
+    434, // 'é'
+    440, // 'ê'
+    446, // 'ë'
+    452, // 'ì'
+    457, // 'í'
+    462, // 'î'
+    467, // 'ï'
+    472, // 'ð'
+    478, // 'ñ'
+    484, // 'ò'
+    490, // 'ó'
+    496, // 'ô'
+    502, // 'õ'
+    508, // 'ö'
+    514, // '÷'
+    519, // 'ø'
+    524, // 'ù'
+    530, // 'ú'
+    536, // 'û'
+    542, // 'ü'
+    548, // 'ý'
+    556, // 'þ'
+    563, // 'ÿ'
+];
+
+// Index to Unicode Box Drawing block glyph patterns
+const BOX_DRAWING: [u16; 128] = [
+    0, // '─'
+    2, // '━'
+    5, // '│'
+    11, // '┃'
+    18, // '┄'
+    20, // '┅'
+    23, // '┆'
+    29, // '┇'
+    35, // '┈'
+    37, // '┉'
+    40, // '┊'
+    46, // '┋'
+    52, // '┌'
+    58, // '┍'
+    64, // '┎'
+    70, // '┏'
+    76, // '┐'
+    80, // '┑'
+    84, // '┒'
+    88, // '┓'
+    92, // '└'
+    99, // '┕'
+    106, // '┖'
+    113, // '┗'
+    120, // '┘'
+    124, // '┙'
+    128, // '┚'
+    133, // '┛'
+    138, // '├'
+    148, // '┝'
+    158, // '┞'
+    168, // '┟'
+    178, // '┠'
+    188, // '┡'
+    198, // '┢'
+    208, // '┣'
+    218, // '┤'
+    224, // '┥'
+    230, // '┦'
+    237, // '┧'
+    244, // '┨'
+    251, // '┩'
+    258, // '┪'
+    265, // '┫'
+    272, // '┬'
+    278, // '┭'
+    284, // '┮'
+    290, // '┯'
+    296, // '┰'
+    302, // '┱'
+    308, // '┲'
+    314, // '┳'
+    320, // '┴'
+    327, // '┵'
+    334, // '┶'
+    341, // '┷'
+    348, // '┸'
+    355, // '┹'
+    362, // '┺'
+    369, // '┻'
+    376, // '┼'
+    386, // '┽'
+    396, // '┾'
+    406, // '┿'
+    416, // '╀'
+    426, // '╁'
+    436, // '╂'
+    446, // '╃'
+    456, // '╄'
+    466, // '╅'
+    476, // '╆'
+    486, // '╇'
+    496, // '╈'
+    506, // '╉'
+    516, // '╊'
+    526, // '╋'
+    536, // '╌'
+    538, // '╍'
+    541, // '╎'
+    547, // '╏'
+    553, // '═'
+    557, // '║'
+    564, // '╒'
+    571, // '╓'
+    577, // '╔'
+    584, // '╕'
+    588, // '╖'
+    592, // '╗'
+    597, // '╘'
+    604, // '╙'
+    611, // '╚'
+    618, // '╛'
+    622, // '╜'
+    627, // '╝'
+    632, // '╞'
+    642, // '╟'
+    652, // '╠'
+    662, // '╡'
+    668, // '╢'
+    675, // '╣'
+    682, // '╤'
+    689, // '╥'
+    695, // '╦'
+    702, // '╧'
+    709, // '╨'
+    716, // '╩'
+    723, // '╪'
+    733, // '╫'
+    743, // '╬'
+    753, // '╭'
+    759, // '╮'
+    763, // '╯'
+    767, // '╰'
+    774, // '╱'
+    784, // '╲'
+    794, // '╳'
+    804, // '╴'
+    806, // '╵'
+    810, // '╶'
+    812, // '╷'
+    816, // '╸'
+    818, // '╹'
+    823, // '╺'
+    826, // '╻'
+    830, // '╼'
+    833, // '╽'
+    840, // '╾'
+    843, // '╿'
+];
+
+// Index to Unicode Specials block glyph patterns
+const SPECIALS: [u16; 1] = [
+    0, // '�'
+];
+
+/// Maximum height of glyph patterns in this bitmap typeface.
+/// This will be true: h + yOffset <= MAX_HEIGHT
+pub const MAX_HEIGHT: u8 = 24;
+
+/// Width of the fixed character cell. Every glyph advances by exactly this
+/// many pixels, and glyph patterns include their own left side bearing.
+pub const CELL_W: u8 = 12;
+
+/// Height of the fixed character cell (line pitch for grids of text)
+pub const CELL_H: u8 = 24;
+
+/// Packed glyph pattern data for all compiled in blocks.
+/// Record format:
+///  [offset+0]: ((w as u8) << 16) | ((h as u8) << 8) | (yOffset as u8)
+///  [offset+1..=ceil(w*h/32)]: packed 1-bit pixels; 0=clear, 1=set
+/// Pixels are packed in top to bottom, left to right order with MSB of first
+/// pixel word containing the top left pixel.
+///  w: Width of pattern in pixels
+///  h: Height of pattern in pixels
+///  yOffset: Vertical offset (pixels downward from top of line) to position
+///     glyph pattern properly relative to text baseline
+pub const DATA: [u32; DATA_LEN] = super::concat_blocks(&[
+    &BASIC_LATIN_DATA,
+    &SPECIALS_DATA,
+    super::block_if(LATIN_1_ENABLED, &LATIN_1_DATA),
+    super::block_if(BOX_DRAWING_ENABLED, &BOX_DRAWING_DATA),
+]);
+
+/// Flash footprint of each block (glyph patterns plus index)
+pub const BLOCK_COSTS: [super::BlockCost; 4] = [
+    super::BlockCost::new("Basic Latin", true, &BASIC_LATIN_DATA, &BASIC_LATIN),
+    super::BlockCost::new("Latin 1", LATIN_1_ENABLED, &LATIN_1_DATA, &LATIN_1),
+    super::BlockCost::new("Box Drawing", BOX_DRAWING_ENABLED, &BOX_DRAWING_DATA, &BOX_DRAWING),
+    super::BlockCost::new("Specials", true, &SPECIALS_DATA, &SPECIALS),
+];
+
+// Packed glyph patterns for Unicode Basic Latin block
+const BASIC_LATIN_DATA: [u32; 495] = [
+    // [0]: 20 ' '
+    0x000c0000, 0x00000000,
+    // [2]: 21 '!'
+    0x00060e06, 0xc30c30c3, 0x0c30c300, 0x00c30000,
+    // [6]: 22 '"'
+    0x00080406, 0xcccccccc,
+    // [8]: 23 '#'
+    0x000a0a04, 0xcc330fff, 0xff330ccf, 0xffff0cc3, 0x30000000,
+    // [13]: 24 '$'
+    0x000a1204, 0x0c0303f0, 0xfcccf330, 0xcc333f0f, 0xccc330cc, 0xf333f0fc, 0x0c030000,
+    // [20]: 25 '%'
+    0x000a0e06, 0xc3f0fc3f, 0x0f300c00, 0xc0300300, 0xcf0fc3f0, 0xfc300000,
+    // [26]: 26 '&'
+    0x000a1004, 0x0f03c30c, 0xc30cc330, 0x300cccf3, 0x330cc330, 0xcc3cf33c,
+    // [32]: 27 '\''
+    0x00060406, 0xc30c3000,
+    // [34]: 28 '('
+    0x00081204, 0xc0c03030, 0x0c0c0c0c, 0x0c0c0c0c, 0x0c0c3030, 0xc0c00000,
+    // [40]: 29 ')'
+    0x00081204, 0x0c0c3030, 0xc0c0c0c0, 0xc0c0c0c0, 0xc0c03030, 0x0c0c0000,
+    // [46]: 2A '*'
+    0x000a0a06, 0x0c030ccf, 0x333f0fc3, 0x30ccc0f0, 0x30000000,
+    // [51]: 2B '+'
+    0x000a0a08, 0x0c0300c0, 0x30fffff0, 0xc0300c03, 0x00000000,
+    // [56]: 2C ','
+    0x00080612, 0xc0c0c0c0, 0x30300000,
+    // [59]: 2D '-'
+    0x000a020c, 0xff3fc000,
+    // [61]: 2E '.'
+    0x00060212, 0xc3000000,
+    // [63]: 2F '/'
+    0x000a1004, 0xc0300c03, 0x00300c03, 0x00c00c03, 0x00c03003, 0x00c0300c,
+    // [69]: 30 '0'
+    0x000a0e06, 0x3f0fcc0f, 0x03c0f03c, 0x0f03c0f0, 0x3c0f033f, 0x0fc00000,
+    // [75]: 31 '1'
+    0x00080e06, 0xc0c0f0f0, 0xc0c0c0c0, 0xc0c0c0c0, 0xc0c00000,
+    // [80]: 32 '2'
+    0x000a0e06, 0x3f0fcc0f, 0x03c03003, 0x00c00c03, 0x00300cff, 0xfff00000,
+    // [86]: 33 '3'
+    0x000a0e06, 0xfffff300, 0xc00c0303, 0xf0fcc030, 0x0c0f033f, 0x0fc00000,
+    // [92]: 34 '4'
+    0x000a0e06, 0x300c03c0, 0xf0330cc3, 0x0cc3ffff, 0xf300c030, 0x0c000000,
+    // [98]: 35 '5'
+    0x000a0e06, 0xfffff00c, 0x033fcffc, 0x0300c030, 0x0c0f033f, 0x0fc00000,
+    // [104]: 36 '6'
+    0x000a0e06, 0x3c0f0030, 0x0c00c033, 0xfcffc0f0, 0x3c0f033f, 0x0fc00000,
+    // [110]: 37 '7'
+    0x000a0e06, 0xfffffc03, 0x00300c03, 0x00c00c03, 0x00c0300c, 0x03000000,
+    // [116]: 38 '8'
+    0x000a0e06, 0x3f0fcc0f, 0x03c0f033, 0xf0fcc0f0, 0x3c0f033f, 0x0fc00000,
+    // [122]: 39 '9'
+    0x000a0e06, 0x3f0fcc0f, 0x03c0f03f, 0xf3fcc030, 0x0300c00f, 0x03c00000,
+    // [128]: 3A ':'
+    0x00060a0a, 0xc3000000, 0x0000c300,
+    // [131]: 3B ';'
+    0x00080e0a, 0xc0c00000, 0x00000000, 0xc0c0c0c0, 0x30300000,
+    // [136]: 3C '<'
+    0x00080a08, 0xc0c03030, 0x0c0c3030, 0xc0c00000,
+    // [140]: 3D '='
+    0x000a060a, 0xfffff000, 0x00fffff0,
+    // [143]: 3E '>'
+    0x00080a08, 0x0c0c3030, 0xc0c03030, 0x0c0c0000,
+    // [147]: 3F '?'
+    0x000a0e06, 0x3c0f0c33, 0x0cc03003, 0x00c00c03, 0x0000000c, 0x03000000,
+    // [153]: 40 '@'
+    0x000a0e06, 0x3f0fcc0f, 0x03fcff3c, 0xcf333ccf, 0x300c033f, 0x0fc00000,
+    // [159]: 41 'A'
+    0x000a0e06, 0x0c0300c0, 0x30330cc3, 0x30ccffff, 0xfc0f03c0, 0xf0300000,
+    // [165]: 42 'B'
+    0x000a0e06, 0x3fcffc0f, 0x03c0f033, 0xfcffc0f0, 0x3c0f033f, 0xcff00000,
+    // [171]: 43 'C'
+    0x000a0e06, 0x3f0fcc0f, 0x0300c030, 0x0c0300c0, 0x3c0f033f, 0x0fc00000,
+    // [177]: 44 'D'
+    0x000a0e06, 0x0fc3f30c, 0xc3c0f03c, 0x0f03c0f0, 0x330cc30f, 0xc3f00000,
+    // [183]: 45 'E'
+    0x000a0e06, 0xff3fc030, 0x0c0300c3, 0xf0fc0300, 0xc0300cff, 0x3fc00000,
+    // [189]: 46 'F'
+    0x000a0e06, 0xff3fc030, 0x0c0300c3, 0xf0fc0300, 0xc0300c03, 0x00c00000,
+    // [195]: 47 'G'
+    0x000a0e06, 0x3f0fcc0f, 0x0300c03f, 0x0fc3c0f0, 0x3c0f033f, 0x0fc00000,
+    // [201]: 48 'H'
+    0x000a0e06, 0xc0f03c0f, 0x03c0f03f, 0xffffc0f0, 0x3c0f03c0, 0xf0300000,
+    // [207]: 49 'I'
+    0x00060e06, 0xc30c30c3, 0x0c30c30c, 0x30c30000,
+    // [211]: 4A 'J'
+    0x000a0e06, 0xc0300c03, 0x00c0300c, 0x0300c0f0, 0x3c0f033f, 0x0fc00000,
+    // [217]: 4B 'K'
+    0x000a0e06, 0xc0f0330c, 0xc30cc330, 0x3c0f0cc3, 0x330cc3c0, 0xf0300000,
+    // [223]: 4C 'L'
+    0x000a0e06, 0x0300c030, 0x0c0300c0, 0x300c0300, 0xc0300cff, 0x3fc00000,
+    // [229]: 4D 'M'
+    0x000a0e06, 0xc0f03f3f, 0xcfccf33c, 0xcf33c0f0, 0x3c0f03c0, 0xf0300000,
+    // [235]: 4E 'N'
+    0x000a0e06, 0xc3f0fc3f, 0x0fccf33c, 0xcf33f0fc, 0x3f0fc3c0, 0xf0300000,
+    // [241]: 4F 'O'
+    0x000a0e06, 0x3f0fcc0f, 0x03c0f03c, 0x0f03c0f0, 0x3c0f033f, 0x0fc00000,
+    // [247]: 50 'P'
+    0x000a0e06, 0x3fcffc0f, 0x03c0f033, 0xfcff00c0, 0x300c0300, 0xc0300000,
+    // [253]: 51 'Q'
+    0x000a1006, 0x3f0fcc0f, 0x03c0f03c, 0x0f03c0f0, 0x3ccf333f, 0x0fc300c0,
+    // [259]: 52 'R'
+    0x000a0e06, 0x3fcffc0f, 0x03c0f033, 0xfcff0cc3, 0x330cc3c0, 0xf0300000,
+    // [265]: 53 'S'
+    0x000a0e06, 0x3f0fcc0f, 0x0300c033, 0xf0fcc030, 0x0c0f033f, 0x0fc00000,
+    // [271]: 54 'T'
+    0x000a0e06, 0xfffff0c0, 0x300c0300, 0xc0300c03, 0x00c0300c, 0x03000000,
+    // [277]: 55 'U'
+    0x000a0e06, 0xc0f03c0f, 0x03c0f03c, 0x0f03c0f0, 0x3c0f033f, 0x0fc00000,
+    // [283]: 56 'V'
+    0x000a0e06, 0xc0f03c0f, 0x03c0f033, 0x30cc330c, 0xc0c0300c, 0x03000000,
+    // [289]: 57 'W'
+    0x000a0e06, 0xc0f03c0f, 0x03c0f03c, 0xcf33ccf3, 0x3f3fcfc0, 0xf0300000,
+    // [295]: 58 'X'
+    0x000a0e06, 0xc0f03c0f, 0x03330cc0, 0xc030330c, 0xcc0f03c0, 0xf0300000,
+    // [301]: 59 'Y'
+    0x000a0e06, 0xc0f03c0f, 0x03330cc0, 0xc0300c03, 0x00c0300c, 0x03000000,
+    // [307]: 5A 'Z'
+    0x000a0e06, 0xff3fcc03, 0x00300c00, 0xc0300300, 0xc0300cff, 0x3fc00000,
+    // [313]: 5B '['
+    0x00081204, 0xf0f03030, 0x30303030, 0x30303030, 0x30303030, 0xf0f00000,
+    // [319]: 5C '\\'
+    0x000a1004, 0x0300c030, 0x0c0c0300, 0xc030300c, 0x0300c0c0, 0x300c0300,
+    // [325]: 5D ']'
+    0x00081204, 0xf0f0c0c0, 0xc0c0c0c0, 0xc0c0c0c0, 0xc0c0c0c0, 0xf0f00000,
+    // [331]: 5E '^'
+    0x00080406, 0x3030cccc,
+    // [333]: 5F '_'
+    0x000c0212, 0xffffff00,
+    // [335]: 60 '`'
+    0x00080406, 0x3030c0c0,
+    // [337]: 61 'a'
+    0x000a0a0a, 0x3c0f0c03, 0x00fc3f0c, 0x330cfc3f, 0x00000000,
+    // [342]: 62 'b'
+    0x000a0e06, 0x0300c030, 0x0c3f0fcc, 0x330cc330, 0xcc330c3f, 0x0fc00000,
+    // [348]: 63 'c'
+    0x000a0a0a, 0x3c0f0c33, 0x0c0300cc, 0x330c3c0f, 0x00000000,
+    // [353]: 64 'd'
+    0x000a0e06, 0xc0300c03, 0x00fc3f0c, 0x330cc330, 0xcc330cfc, 0x3f000000,
+    // [359]: 65 'e'
+    0x000a0a0a, 0x3c0f0c33, 0x0cff3fc0, 0x300c3c0f, 0x00000000,
+    // [364]: 66 'f'
+    0x000a0e06, 0xf03c00c0, 0x303f0fc0, 0xc0300c03, 0x00c0300c, 0x03000000,
+    // [370]: 67 'g'
+    0x000a0e0a, 0xfc3f0c33, 0x0cc330cc, 0x330cfc3f, 0x0c03003c, 0x0f000000,
+    // [376]: 68 'h'
+    0x000a0e06, 0x0300c030, 0x0c3f0fcc, 0x330cc330, 0xcc330cc3, 0x30c00000,
+    // [382]: 69 'i'
+    0x00060e06, 0xc30000c3, 0x0c30c30c, 0x30c30000,
+    // [386]: 6A 'j'
+    0x00081206, 0xc0c00000, 0xc0c0c0c0, 0xc0c0c0c0, 0xc0c0c0c0, 0x3c3c0000,
+    // [392]: 6B 'k'
+    0x000a0e06, 0x0300c030, 0x0cc330c3, 0x30cc0f03, 0xc330ccc3, 0x30c00000,
+    // [398]: 6C 'l'
+    0x00060e06, 0xc30c30c3, 0x0c30c30c, 0x30c30000,
+    // [402]: 6D 'm'
+    0x000a0a0a, 0x33ccfccf, 0x33ccf33c, 0xcf33ccf3, 0x30000000,
+    // [407]: 6E 'n'
+    0x000a0a0a, 0x3f0fcc33, 0x0cc330cc, 0x330cc330, 0xc0000000,
+    // [412]: 6F 'o'
+    0x000a0a0a, 0x3c0f0c33, 0x0cc330cc, 0x330c3c0f, 0x00000000,
+    // [417]: 70 'p'
+    0x000a0e0a, 0x3f0fcc33, 0x0cc330cc, 0x330c3f0f, 0xc0300c03, 0x00c00000,
+    // [423]: 71 'q'
+    0x000a0e0a, 0xfc3f0c33, 0x0cc330cc, 0x330cfc3f, 0x0c0300c0, 0x30000000,
+    // [429]: 72 'r'
+    0x000a0a0a, 0xf33cc0f0, 0x3c0300c0, 0x300c0300, 0xc0000000,
+    // [434]: 73 's'
+    0x000a0a0a, 0xfc3f0030, 0x0c3c0f0c, 0x03003f0f, 0xc0000000,
+    // [439]: 74 't'
+    0x00080e06, 0x30303030, 0xfcfc3030, 0x30303030, 0xc0c00000,
+    // [444]: 75 'u'
+    0x000a0a0a, 0xc330cc33, 0x0cc330cc, 0x330cfc3f, 0x00000000,
+    // [449]: 76 'v'
+    0x000a0a0a, 0xc0f03330, 0xcc330cc0, 0xc0300c03, 0x00000000,
+    // [454]: 77 'w'
+    0x000a0a0a, 0xc0f03c0f, 0x03ccf33c, 0xcf33330c, 0xc0000000,
+    // [459]: 78 'x'
+    0x000a0a0a, 0xc0f03330, 0xcc0c0303, 0x30ccc0f0, 0x30000000,
+    // [464]: 79 'y'
+    0x000a0e0a, 0xc0f03c0f, 0x03330cc3, 0x30cc0c03, 0x00c03003, 0xc0f00000,
+    // [470]: 7A 'z'
+    0x000a0a0a, 0xff3fc300, 0xc00c0300, 0x300cff3f, 0xc0000000,
+    // [475]: 7B '{'
+    0x00081204, 0xc0c03030, 0x30303030, 0x0c0c3030, 0x30303030, 0xc0c00000,
+    // [481]: 7C '|'
+    0x00061204, 0xc30c30c3, 0x0c30c30c, 0x30c30c30, 0xc3000000,
+    // [486]: 7D '}'
+    0x00081204, 0x0c0c3030, 0x30303030, 0xc0c03030, 0x30303030, 0x0c0c0000,
+    // [492]: 7E '~'
+    0x000a0406, 0xcf33c3cc, 0xf3000000,
+];
+
+// Packed glyph patterns for Unicode Latin 1 block
+const LATIN_1_DATA: [u32; 570] = [
+    // [0]: A0 ' '
+    0x000c0000, 0x00000000,
+    // [2]: A1 '¡'
+    0x00060e06, 0xc30000c3, 0x0c30c30c, 0x30c30000,
+    // [6]: A2 '¢'
+    0x000a0e06, 0x0c0303f0, 0xfcccf330, 0xcc33ccf3, 0x33f0fc0c, 0x03000000,
+    // [12]: A3 '£'
+    0x000a0e06, 0x3c0f0030, 0x0c0300c0, 0xfc3f0300, 0xcc330c3f, 0xcff00000,
+    // [18]: A4 '¤'
+    0x000a0e06, 0xc0f033f0, 0xfcc0f03c, 0x0f03c0f0, 0x33f0fcc0, 0xf0300000,
+    // [24]: A5 '¥'
+    0x000a0e06, 0xc0f03330, 0xccfffff0, 0xc030ffff, 0xf0c0300c, 0x03000000,
+    // [30]: A6 '¦'
+    0x00061204, 0xc30c30c3, 0x0c30000c, 0x30c30c30, 0xc3000000,
+    // [35]: A7 '§'
+    0x000a1204, 0x3f0fcc0f, 0x0300c033, 0xf0fcc0f0, 0x33f0fcc0, 0x300c0f03, 0x3f0fc000,
+    // [42]: A8 '¨'
+    0x00080206, 0xcccc0000,
+    // [44]: A9 '©'
+    0x000a0e06, 0x3f0fcc0f, 0x03fcff3c, 0xcf33fcff, 0x3c0f033f, 0x0fc00000,
+    // [50]: AA 'ª'
+    0x00080e04, 0x3030cccc, 0xf0f0cccc, 0xf0f00000, 0xfcfc0000,
+    // [55]: AB '«'
+    0x000a0a0a, 0xcc330330, 0xcc0cc333, 0x30cccc33, 0x00000000,
+    // [60]: AC '¬'
+    0x000a060c, 0xff3fcc03, 0x00c03000,
+    // [63]: AD '­'
+    0x000a020c, 0xff3fc000,
+    // [65]: AE '®'
+    0x000a0e06, 0x3f0fcc0f, 0x03cff3ff, 0x3fcfcff3, 0xff3fcf3f, 0x0fc00000,
+    // [71]: AF '¯'
+    0x00080206, 0xfcfc0000,
+    // [73]: B0 '°'
+    0x000a0804, 0x3c0f0c33, 0x0cc330c3, 0xc0f00000,
+    // [77]: B1 '±'
+    0x000a0c08, 0x0c0300c0, 0x30fffff0, 0xc0300c03, 0x0fffff00,
+    // [82]: B2 '²'
+    0x00080a02, 0xfcfcc0c0, 0xfcfc0c0c, 0xfcfc0000,
+    // [86]: B3 '³'
+    0x00080a02, 0xfcfcc0c0, 0xfcfcc0c0, 0xfcfc0000,
+    // [90]: B4 '´'
+    0x00080406, 0xc0c03030,
+    // [92]: B5 'µ'
+    0x000a0e0a, 0x30cc330c, 0xc330cc33, 0x0cc3cff3, 0xf00c0300, 0xc0300000,
+    // [98]: B6 '¶'
+    0x000a1206, 0xff3fccff, 0x3fcff3fc, 0xf33ccc33, 0x0cc330cc, 0x330cc330, 0xcc330000,
+    // [105]: B7 '·'
+    0x0006020e, 0xc3000000,
+    // [107]: B8 '¸'
+    0x00080414, 0xc0c03030,
+    // [109]: B9 '¹'
+    0x00080a02, 0x30303c3c, 0x30303030, 0xfcfc0000,
+    // [113]: BA 'º'
+    0x000a0c06, 0x3c0f0c33, 0x0cc330c3, 0xc0f00000, 0x0ff3fc00,
+    // [118]: BB '»'
+    0x000a0a0a, 0x0cc33330, 0xcccc3303, 0x30cc0cc3, 0x30000000,
+    // [123]: BC '¼'
+    0x000a1202, 0x00c03c0f, 0x0330cc30, 0xc0300300, 0xcccf33cc, 0x330fc3f0, 0xc0300000,
+    // [130]: BD '½'
+    0x000a1202, 0x00c03c0f, 0x0330cc30, 0xc0300300, 0xc3ccf3c0, 0x300300c0, 0xfc3f0000,
+    // [137]: BE '¾'
+    0x000a1202, 0x03c0fc33, 0x0c33ccf0, 0xf03c03c0, 0xfccf33cc, 0x330fc3f0, 0xc0300000,
+    // [144]: BF '¿'
+    0x000a0e06, 0x300c0000, 0x00300c00, 0xc0300300, 0xcc330c3c, 0x0f000000,
+    // [150]: C0 'À'
+    0x000a1400, 0x0300c0c0, 0x30000000, 0xc0300c03, 0x0330cc33, 0x0ccfffff, 0xc0f03c0f, 0x03000000,
+    // [158]: C1 'Á'
+    0x000a1400, 0x300c00c0, 0x30000000, 0xc0300c03, 0x0330cc33, 0x0ccfffff, 0xc0f03c0f, 0x03000000,
+    // [166]: C2 'Â'
+    0x000a1400, 0x0c030330, 0xcc000000, 0xc0300c03, 0x0330cc33, 0x0ccfffff, 0xc0f03c0f, 0x03000000,
+    // [174]: C3 'Ã'
+    0x000a1400, 0xcf33c3cc, 0xf3000000, 0xc0300c03, 0x0330cc33, 0x0ccfffff, 0xc0f03c0f, 0x03000000,
+    // [182]: C4 'Ä'
+    0x000a1202, 0x330cc000, 0x000c0300, 0xc030330c, 0xc330ccff, 0xfffc0f03, 0xc0f03000,
+    // [189]: C5 'Å'
+    0x000a1400, 0x3f0fcc0f, 0x033f0fc0, 0xc0300c03, 0x0330cc33, 0x0ccfffff, 0xc0f03c0f, 0x03000000,
+    // [197]: C6 'Æ'
+    0x000a0e06, 0xff3fc0cc, 0x330cc33f, 0xffff0cc3, 0x30cc33fc, 0xff300000,
+    // [203]: C7 'Ç'
+    0x000a1206, 0x3f0fcc0f, 0x0300c030, 0x0c0300c0, 0x3c0f033f, 0x0fc0c030, 0x0300c000,
+    // [210]: C8 'È'
+    0x000a1400, 0x0c030300, 0xc000000f, 0xf3fc0300, 0xc0300c3f, 0x0fc0300c, 0x0300cff3, 0xfc000000,
+    // [218]: C9 'É'
+    0x000a1400, 0x300c00c0, 0x3000000f, 0xf3fc0300, 0xc0300c3f, 0x0fc0300c, 0x0300cff3, 0xfc000000,
+    // [226]: CA 'Ê'
+    0x000a1400, 0x0c030330, 0xcc00000f, 0xf3fc0300, 0xc0300c3f, 0x0fc0300c, 0x0300cff3, 0xfc000000,
+    // [234]: CB 'Ë'
+    0x000a1202, 0x330cc000, 0x00ff3fc0, 0x300c0300, 0xc3f0fc03, 0x00c0300c, 0xff3fc000,
+    // [241]: CC 'Ì'
+    0x00081400, 0x3030c0c0, 0x0000c0c0, 0xc0c0c0c0, 0xc0c0c0c0, 0xc0c0c0c0,
+    // [247]: CD 'Í'
+    0x00081400, 0xc0c03030, 0x00003030, 0x30303030, 0x30303030, 0x30303030,
+    // [253]: CE 'Î'
+    0x00081400, 0x3030cccc, 0x00003030, 0x30303030, 0x30303030, 0x30303030,
+    // [259]: CF 'Ï'
+    0x00081202, 0xcccc0000, 0x30303030, 0x30303030, 0x30303030, 0x30300000,
+    // [265]: D0 'Ð'
+    0x000a0e06, 0x3f0fcc33, 0x0cc330cc, 0xff3fc330, 0xcc330c3f, 0x0fc00000,
+    // [271]: D1 'Ñ'
+    0x000a1400, 0xcf33c3cc, 0xf300000c, 0x3f0fc3f0, 0xfccf33cc, 0xf33f0fc3, 0xf0fc3c0f, 0x03000000,
+    // [279]: D2 'Ò'
+    0x000a1400, 0x0300c0c0, 0x30000003, 0xf0fcc0f0, 0x3c0f03c0, 0xf03c0f03, 0xc0f033f0, 0xfc000000,
+    // [287]: D3 'Ó'
+    0x000a1400, 0x300c00c0, 0x30000003, 0xf0fcc0f0, 0x3c0f03c0, 0xf03c0f03, 0xc0f033f0, 0xfc000000,
+    // [295]: D4 'Ô'
+    0x000a1400, 0x0c030330, 0xcc000003, 0xf0fcc0f0, 0x3c0f03c0, 0xf03c0f03, 0xc0f033f0, 0xfc000000,
+    // [303]: D5 'Õ'
+    0x000a1400, 0xcf33c3cc, 0xf3000003, 0xf0fcc0f0, 0x3c0f03c0, 0xf03c0f03, 0xc0f033f0, 0xfc000000,
+    // [311]: D6 'Ö'
+    0x000a1202, 0x330cc000, 0x003f0fcc, 0x0f03c0f0, 0x3c0f03c0, 0xf03c0f03, 0x3f0fc000,
+    // [318]: D7 '×'
+    0x000a0a0a, 0xc0f03330, 0xcc0c0303, 0x30ccc0f0, 0x30000000,
+    // [323]: D8 'Ø'
+    0x000a0e06, 0xff3fcf0f, 0xc3ccf33c, 0xcf33c3f0, 0xfc0f033f, 0xcff00000,
+    // [329]: D9 'Ù'
+    0x000a1400, 0x0300c0c0, 0x3000000c, 0x0f03c0f0, 0x3c0f03c0, 0xf03c0f03, 0xc0f033f0, 0xfc000000,
+    // [337]: DA 'Ú'
+    0x000a1400, 0x300c00c0, 0x3000000c, 0x0f03c0f0, 0x3c0f03c0, 0xf03c0f03, 0xc0f033f0, 0xfc000000,
+    // [345]: DB 'Û'
+    0x000a1400, 0x0c030330, 0xcc00000c, 0x0f03c0f0, 0x3c0f03c0, 0xf03c0f03, 0xc0f033f0, 0xfc000000,
+    // [353]: DC 'Ü'
+    0x000a1202, 0x330cc000, 0x00c0f03c, 0x0f03c0f0, 0x3c0f03c0, 0xf03c0f03, 0x3f0fc000,
+    // [360]: DD 'Ý'
+    0x000a1400, 0x300c00c0, 0x3000000c, 0x0f03c0f0, 0x3330cc0c, 0x0300c030, 0x0c0300c0, 0x30000000,
+    // [368]: DE 'Þ'
+    0x000a0e06, 0x0300c030, 0xfc3f30cc, 0x330cc30f, 0xc3f00c03, 0x00c00000,
+    // [374]: DF 'ß'
+    0x000a0e06, 0x0f03c30c, 0xc30cc330, 0xcc3330cc, 0x3c0f033c, 0xcf300000,
+    // [380]: E0 'à'
+    0x000a1004, 0x0c030300, 0xc0000003, 0xc0f0c330, 0xcfc3f0c3, 0x30cfc3f0,
+    // [386]: E1 'á'
+    0x000a1004, 0x300c00c0, 0x30000003, 0xc0f0c330, 0xcfc3f0c3, 0x30cfc3f0,
+    // [392]: E2 'â'
+    0x000a1004, 0x300c0cc3, 0x30000003, 0xc0f0c330, 0xcfc3f0c3, 0x30cfc3f0,
+    // [398]: E3 'ã'
+    0x000a1004, 0xcc330330, 0xcc000003, 0xc0f0c330, 0xcfc3f0c3, 0x30cfc3f0,
+    // [404]: E4 'ä'
+    0x000a0e06, 0xcc330000, 0x003c0f0c, 0x330cfc3f, 0x0c330cfc, 0x3f000000,
+    // [410]: E5 'å'
+    0x000a1202, 0x3c0f0c33, 0x0c3c0f00, 0x00003c0f, 0x0c330cfc, 0x3f0c330c, 0xfc3f0000,
+    // [417]: E6 'æ'
+    0x000a0a0a, 0x33ccfcc3, 0x30fffff0, 0xcc33f33c, 0xc0000000,
+    // [422]: E7 'ç'
+    0x000a0e0a, 0x3c0f0c33, 0x0c0300cc, 0x330c3c0f, 0x0300c00c, 0x03000000,
+    // [428]: E8 'è'
+    0x000a1004, 0x0c030300, 0xc0000003, 0xc0f0c330, 0xcff3fc03, 0x00c3c0f0,
+    // [434]: E9 'é'
+    0x000a1004, 0x300c00c0, 0x30000003, 0xc0f0c330, 0xcff3fc03, 0x00cfc3f0,
+    // [440]: EA 'ê'
+    0x000a1004, 0x0c030330, 0xcc000003, 0xc0f0c330, 0xcff3fc03, 0x00c3c0f0,
+    // [446]: EB 'ë'
+    0x000a0e06, 0xc330c000, 0x003c0f0c, 0x330cff3f, 0xc0300cfc, 0x3f000000,
+    // [452]: EC 'ì'
+    0x00081004, 0x3030c0c0, 0x0000c0c0, 0xc0c0c0c0, 0xc0c0c0c0,
+    // [457]: ED 'í'
+    0x00081004, 0xc0c03030, 0x00003030, 0x30303030, 0x30303030,
+    // [462]: EE 'î'
+    0x00081004, 0x3030cccc, 0x00003030, 0x30303030, 0x30303030,
+    // [467]: EF 'ï'
+    0x00080e06, 0xcccc0000, 0x30303030, 0x30303030, 0x30300000,
+    // [472]: F0 'ð'
+    0x000a1004, 0x030cc3c0, 0x3c330c0c, 0xc330c330, 0xcc330cc3, 0x30c3c0f0,
+    // [478]: F1 'ñ'
+    0x000a1004, 0xcc330330, 0xcc000003, 0xf0fcc330, 0xcc330cc3, 0x30cc330c,
+    // [484]: F2 'ò'
+    0x000a1004, 0x0c030300, 0xc0000003, 0xc0f0c330, 0xcc330cc3, 0x30c3c0f0,
+    // [490]: F3 'ó'
+    0x000a1004, 0x300c00c0, 0x30000003, 0xc0f0c330, 0xcc330cc3, 0x30c3c0f0,
+    // [496]: F4 'ô'
+    0x000a1004, 0x0c030330, 0xcc000003, 0xc0f0c330, 0xcc330cc3, 0x30c3c0f0,
+    // [502]: F5 'õ'
+    0x000a1004, 0xcc330330, 0xcc000003, 0xc0f0c330, 0xcc330cc3, 0x30c3c0f0,
+    // [508]: F6 'ö'
+    0x000a0e06, 0xc330c000, 0x003c0f0c, 0x330cc330, 0xcc330c3c, 0x0f000000,
+    // [514]: F7 '÷'
+    0x000a0a08, 0x0c030000, 0x00fffff0, 0x00000c03, 0x00000000,
+    // [519]: F8 'ø'
+    0x000a0c08, 0xff3fcf0f, 0xc3ccf33c, 0x3f0f3f0f, 0xc00c0300,
+    // [524]: F9 'ù'
+    0x000a1004, 0x0c030300, 0xc000000c, 0x330cc330, 0xcc330cc3, 0x30cfc3f0,
+    // [530]: FA 'ú'
+    0x000a1004, 0x300c00c0, 0x3000000c, 0x330cc330, 0xcc330cc3, 0x30cfc3f0,
+    // [536]: FB 'û'
+    0x000a1004, 0x0c030330, 0xcc00000c, 0x330cc330, 0xcc330cc3, 0x30cfc3f0,
+    // [542]: FC 'ü'
+    0x000a0e06, 0xc330c000, 0x00c330cc, 0x330cc330, 0xcc330cfc, 0x3f000000,
+    // [548]: FD 'ý'
+    0x000a1404, 0x300c00c0, 0x3000000c, 0x0f03c0f0, 0x3330cc33, 0x0cc0c030, 0x0c03003c, 0x0f000000,
+    // [556]: FE 'þ'
+    0x000a1206, 0x0300c030, 0x0c3f0fcc, 0x330cc330, 0xcc330c3f, 0x0fc0300c, 0x0300c000,
+    // [563]: FF 'ÿ'
+    0x000a1206, 0x330cc000, 0x00c0f03c, 0x0f03330c, 0xc330cc0c, 0x0300c030, 0x03c0f000,
+];
+
+// Packed glyph patterns for Unicode Box Drawing block
+const BOX_DRAWING_DATA: [u32; 850] = [
+    // [0]: 2500 '─'
+    0x000c020c, 0xffffff00,
+    // [2]: 2501 '━'
+    0x000c040b, 0xffffffff, 0xffff0000,
+    // [5]: 2502 '│'
+    0x00061800, 0xc30c30c3, 0x0c30c30c, 0x30c30c30, 0xc30c30c3, 0x0c300000,
+    // [11]: 2503 '┃'
+    0x00071800, 0xf1e3c78f, 0x1e3c78f1, 0xe3c78f1e, 0x3c78f1e3, 0xc78f1e3c, 0x78000000,
+    // [18]: 2504 '┄'
+    0x000b020c, 0xeefddc00,
+    // [20]: 2505 '┅'
+    0x000b040b, 0xeefddfbb, 0xf7700000,
+    // [23]: 2506 '┆'
+    0x00061600, 0xc30c30c3, 0x0000c30c, 0x30c30000, 0xc30c30c3, 0x00000000,
+    // [29]: 2507 '┇'
+    0x00071600, 0xf1e3c78f, 0x1e0000f1, 0xe3c78f1e, 0x0000f1e3, 0xc78f1e00,
+    // [35]: 2508 '┈'
+    0x000b020c, 0xdb7b6c00,
+    // [37]: 2509 '┉'
+    0x000b040b, 0xdb7b6f6d, 0xedb00000,
+    // [40]: 250A '┊'
+    0x00061600, 0xc30c3000, 0x0c30c300, 0x00c30c30, 0x000c30c3, 0x00000000,
+    // [46]: 250B '┋'
+    0x00071600, 0xf1e3c780, 0x003c78f1, 0xe0000f1e, 0x3c780003, 0xc78f1e00,
+    // [52]: 250C '┌'
+    0x000c0c0c, 0xff0ff003, 0x00300300, 0x30030030, 0x03003003, 0x00300000,
+    // [58]: 250D '┍'
+    0x000c0d0b, 0xff0ff0ff, 0x0ff00300, 0x30030030, 0x03003003, 0x00300300,
+    // [64]: 250E '┎'
+    0x000c0c0c, 0xff8ff807, 0x80780780, 0x78078078, 0x07807807, 0x80780000,
+    // [70]: 250F '┏'
+    0x000c0d0b, 0xff8ff8ff, 0x8ff80780, 0x78078078, 0x07807807, 0x80780780,
+    // [76]: 2510 '┐'
+    0x00060c0c, 0xfffc30c3, 0x0c30c30c, 0x30000000,
+    // [80]: 2511 '┑'
+    0x00060d0b, 0xffffffc3, 0x0c30c30c, 0x30c00000,
+    // [84]: 2512 '┒'
+    0x00070c0c, 0xffffc78f, 0x1e3c78f1, 0xe3c78000,
+    // [88]: 2513 '┓'
+    0x00070d0b, 0xffffffff, 0x1e3c78f1, 0xe3c78f00,
+    // [92]: 2514 '└'
+    0x000c0e00, 0x03003003, 0x00300300, 0x30030030, 0x03003003, 0x0030ff0f, 0xf0000000,
+    // [99]: 2515 '┕'
+    0x000c0f00, 0x03003003, 0x00300300, 0x30030030, 0x03003003, 0x0ff0ff0f, 0xf0ff0000,
+    // [106]: 2516 '┖'
+    0x000c0e00, 0x07807807, 0x80780780, 0x78078078, 0x07807807, 0x8078ff8f, 0xf8000000,
+    // [113]: 2517 '┗'
+    0x000c0f00, 0x07807807, 0x80780780, 0x78078078, 0x07807807, 0x8ff8ff8f, 0xf8ff8000,
+    // [120]: 2518 '┘'
+    0x00060e00, 0xc30c30c3, 0x0c30c30c, 0x30fff000,
+    // [124]: 2519 '┙'
+    0x00060f00, 0xc30c30c3, 0x0c30c30c, 0x3fffffc0,
+    // [128]: 251A '┚'
+    0x00070e00, 0xf1e3c78f, 0x1e3c78f1, 0xe3c78fff, 0xc0000000,
+    // [133]: 251B '┛'
+    0x00070f00, 0xf1e3c78f, 0x1e3c78f1, 0xe3c7ffff, 0xff800000,
+    // [138]: 251C '├'
+    0x000c1800, 0x03003003, 0x00300300, 0x30030030, 0x03003003, 0x0030ff0f, 0xf0030030, 0x03003003,
+    0x00300300, 0x30030030,
+    // [148]: 251D '┝'
+    0x000c1800, 0x03003003, 0x00300300, 0x30030030, 0x03003003, 0x0ff0ff0f, 0xf0ff0030, 0x03003003,
+    0x00300300, 0x30030030,
+    // [158]: 251E '┞'
+    0x000c1800, 0x07807807, 0x80780780, 0x78078078, 0x07807807, 0x8078ff8f, 0xf8030030, 0x03003003,
+    0x00300300, 0x30030030,
+    // [168]: 251F '┟'
+    0x000c1800, 0x03003003, 0x00300300, 0x30030030, 0x03003003, 0x0030ff8f, 0xf8078078, 0x07807807,
+    0x80780780, 0x78078078,
+    // [178]: 2520 '┠'
+    0x000c1800, 0x07807807, 0x80780780, 0x78078078, 0x07807807, 0x8078ff8f, 0xf8078078, 0x07807807,
+    0x80780780, 0x78078078,
+    // [188]: 2521 '┡'
+    0x000c1800, 0x07807807, 0x80780780, 0x78078078, 0x07807807, 0x8ff8ff8f, 0xf8ff8030, 0x03003003,
+    0x00300300, 0x30030030,
+    // [198]: 2522 '┢'
+    0x000c1800, 0x03003003, 0x00300300, 0x30030030, 0x03003003, 0x0ff8ff8f, 0xf8ff8078, 0x07807807,
+    0x80780780, 0x78078078,
+    // [208]: 2523 '┣'
+    0x000c1800, 0x07807807, 0x80780780, 0x78078078, 0x07807807, 0x8ff8ff8f, 0xf8ff8078, 0x07807807,
+    0x80780780, 0x78078078,
+    // [218]: 2524 '┤'
+    0x00061800, 0xc30c30c3, 0x0c30c30c, 0x30fffc30, 0xc30c30c3, 0x0c300000,
+    // [224]: 2525 '┥'
+    0x00061800, 0xc30c30c3, 0x0c30c30c, 0x3ffffff0, 0xc30c30c3, 0x0c300000,
+    // [230]: 2526 '┦'
+    0x00071800, 0xf1e3c78f, 0x1e3c78f1, 0xe3c78fff, 0xd83060c1, 0x83060c18, 0x30000000,
+    // [237]: 2527 '┧'
+    0x00071800, 0x60c18306, 0x0c183060, 0xc1830fff, 0xfc78f1e3, 0xc78f1e3c, 0x78000000,
+    // [244]: 2528 '┨'
+    0x00071800, 0xf1e3c78f, 0x1e3c78f1, 0xe3c78fff, 0xfc78f1e3, 0xc78f1e3c, 0x78000000,
+    // [251]: 2529 '┩'
+    0x00071800, 0xf1e3c78f, 0x1e3c78f1, 0xe3c7ffff, 0xffb060c1, 0x83060c18, 0x30000000,
+    // [258]: 252A '┪'
+    0x00071800, 0x60c18306, 0x0c183060, 0xc187ffff, 0xfff8f1e3, 0xc78f1e3c, 0x78000000,
+    // [265]: 252B '┫'
+    0x00071800, 0xf1e3c78f, 0x1e3c78f1, 0xe3c7ffff, 0xfff8f1e3, 0xc78f1e3c, 0x78000000,
+    // [272]: 252C '┬'
+    0x000c0c0c, 0xffffff03, 0x00300300, 0x30030030, 0x03003003, 0x00300000,
+    // [278]: 252D '┭'
+    0x000c0d0b, 0x03ffffff, 0xf03f0300, 0x30030030, 0x03003003, 0x00300300,
+    // [284]: 252E '┮'
+    0x000c0d0b, 0xff0fffff, 0xfff00300, 0x30030030, 0x03003003, 0x00300300,
+    // [290]: 252F '┯'
+    0x000c0d0b, 0xffffffff, 0xffff0300, 0x30030030, 0x03003003, 0x00300300,
+    // [296]: 2530 '┰'
+    0x000c0c0c, 0xffffff07, 0x80780780, 0x78078078, 0x07807807, 0x80780000,
+    // [302]: 2531 '┱'
+    0x000c0d0b, 0x07ffffff, 0xf07f0780, 0x78078078, 0x07807807, 0x80780780,
+    // [308]: 2532 '┲'
+    0x000c0d0b, 0xff8fffff, 0xfff80780, 0x78078078, 0x07807807, 0x80780780,
+    // [314]: 2533 '┳'
+    0x000c0d0b, 0xffffffff, 0xffff0780, 0x78078078, 0x07807807, 0x80780780,
+    // [320]: 2534 '┴'
+    0x000c0e00, 0x03003003, 0x00300300, 0x30030030, 0x03003003, 0x0030ffff, 0xff000000,
+    // [327]: 2535 '┵'
+    0x000c0f00, 0x03003003, 0x00300300, 0x30030030, 0x03003003, 0x003fffff, 0xff03f000,
+    // [334]: 2536 '┶'
+    0x000c0f00, 0x03003003, 0x00300300, 0x30030030, 0x03003003, 0x0ff0ffff, 0xffff0000,
+    // [341]: 2537 '┷'
+    0x000c0f00, 0x03003003, 0x00300300, 0x30030030, 0x03003003, 0x0fffffff, 0xfffff000,
+    // [348]: 2538 '┸'
+    0x000c0e00, 0x07807807, 0x80780780, 0x78078078, 0x07807807, 0x8078ffff, 0xff000000,
+    // [355]: 2539 '┹'
+    0x000c0f00, 0x07807807, 0x80780780, 0x78078078, 0x07807807, 0x807fffff, 0xff07f000,
+    // [362]: 253A '┺'
+    0x000c0f00, 0x07807807, 0x80780780, 0x78078078, 0x07807807, 0x8ff8ffff, 0xffff8000,
+    // [369]: 253B '┻'
+    0x000c0f00, 0x07807807, 0x80780780, 0x78078078, 0x07807807, 0x8fffffff, 0xfffff000,
+    // [376]: 253C '┼'
+    0x000c1800, 0x03003003, 0x00300300, 0x30030030, 0x03003003, 0x0030ffff, 0xff030030, 0x03003003,
+    0x00300300, 0x30030030,
+    // [386]: 253D '┽'
+    0x000c1800, 0x03003003, 0x00300300, 0x30030030, 0x03003003, 0x003fffff, 0xff03f030, 0x03003003,
+    0x00300300, 0x30030030,
+    // [396]: 253E '┾'
+    0x000c1800, 0x03003003, 0x00300300, 0x30030030, 0x03003003, 0x0ff0ffff, 0xffff0030, 0x03003003,
+    0x00300300, 0x30030030,
+    // [406]: 253F '┿'
+    0x000c1800, 0x03003003, 0x00300300, 0x30030030, 0x03003003, 0x0fffffff, 0xfffff030, 0x03003003,
+    0x00300300, 0x30030030,
+    // [416]: 2540 '╀'
+    0x000c1800, 0x07807807, 0x80780780, 0x78078078, 0x07807807, 0x8078ffff, 0xff030030, 0x03003003,
+    0x00300300, 0x30030030,
+    // [426]: 2541 '╁'
+    0x000c1800, 0x03003003, 0x00300300, 0x30030030, 0x03003003, 0x0030ffff, 0xff078078, 0x07807807,
+    0x80780780, 0x78078078,
+    // [436]: 2542 '╂'
+    0x000c1800, 0x07807807, 0x80780780, 0x78078078, 0x07807807, 0x8078ffff, 0xff078078, 0x07807807,
+    0x80780780, 0x78078078,
+    // [446]: 2543 '╃'
+    0x000c1800, 0x07807807, 0x80780780, 0x78078078, 0x07807807, 0x807fffff, 0xff07f030, 0x03003003,
+    0x00300300, 0x30030030,
+    // [456]: 2544 '╄'
+    0x000c1800, 0x07807807, 0x80780780, 0x78078078, 0x07807807, 0x8ff8ffff, 0xffff8030, 0x03003003,
+    0x00300300, 0x30030030,
+    // [466]: 2545 '╅'
+    0x000c1800, 0x03003003, 0x00300300, 0x30030030, 0x03003003, 0x007fffff, 0xff07f078, 0x07807807,
+    0x80780780, 0x78078078,
+    // [476]: 2546 '╆'
+    0x000c1800, 0x03003003, 0x00300300, 0x30030030, 0x03003003, 0x0ff8ffff, 0xffff8078, 0x07807807,
+    0x80780780, 0x78078078,
+    // [486]: 2547 '╇'
+    0x000c1800, 0x07807807, 0x80780780, 0x78078078, 0x07807807, 0x8fffffff, 0xfffff030, 0x03003003,
+    0x00300300, 0x30030030,
+    // [496]: 2548 '╈'
+    0x000c1800, 0x03003003, 0x00300300, 0x30030030, 0x03003003, 0x0fffffff, 0xfffff078, 0x07807807,
+    0x80780780, 0x78078078,
+    // [506]: 2549 '╉'
+    0x000c1800, 0x07807807, 0x80780780, 0x78078078, 0x07807807, 0x807fffff, 0xff07f078, 0x07807807,
+    0x80780780, 0x78078078,
+    // [516]: 254A '╊'
+    0x000c1800, 0x07807807, 0x80780780, 0x78078078, 0x07807807, 0x8ff8ffff, 0xffff8078, 0x07807807,
+    0x80780780, 0x78078078,
+    // [526]: 254B '╋'
+    0x000c1800, 0x07807807, 0x80780780, 0x78078078, 0x07807807, 0x8fffffff, 0xfffff078, 0x07807807,
+    0x80780780, 0x78078078,
+    // [536]: 254C '╌'
+    0x000b020c, 0xfbff7c00,
+    // [538]: 254D '╍'
+    0x000b040b, 0xfbff7fef, 0xfdf00000,
+    // [541]: 254E '╎'
+    0x00061600, 0xc30c30c3, 0x0c30c300, 0x00c30c30, 0xc30c30c3, 0x00000000,
+    // [547]: 254F '╏'
+    0x00071600, 0xf1e3c78f, 0x1e3c78f1, 0xe0000f1e, 0x3c78f1e3, 0xc78f1e00,
+    // [553]: 2550 '═'
+    0x000c060a, 0xffffff00, 0x0000ffff, 0xff000000,
+    // [557]: 2551 '║'
+    0x00081800, 0xcccccccc, 0xcccccccc, 0xcccccccc, 0xcccccccc, 0xcccccccc, 0xcccccccc,
+    // [564]: 2552 '╒'
+    0x000c0e0a, 0xff0ff003, 0x0030ff0f, 0xf0030030, 0x03003003, 0x00300300, 0x30000000,
+    // [571]: 2553 '╓'
+    0x000c0c0c, 0xffcffc0c, 0xc0cc0cc0, 0xcc0cc0cc, 0x0cc0cc0c, 0xc0cc0000,
+    // [577]: 2554 '╔'
+    0x000c0e0a, 0xffcffc0c, 0xc0ccffcf, 0xfc0cc0cc, 0x0cc0cc0c, 0xc0cc0cc0, 0xcc000000,
+    // [584]: 2555 '╕'
+    0x00060e0a, 0xfffc30ff, 0xfc30c30c, 0x30c30000,
+    // [588]: 2556 '╖'
+    0x00080c0c, 0xffffcccc, 0xcccccccc, 0xcccccccc,
+    // [592]: 2557 '╗'
+    0x00080e0a, 0xffffcccc, 0xffffcccc, 0xcccccccc, 0xcccc0000,
+    // [597]: 2558 '╘'
+    0x000c1000, 0x03003003, 0x00300300, 0x30030030, 0x030030ff, 0x0ff00300, 0x30ff0ff0,
+    // [604]: 2559 '╙'
+    0x000c0e00, 0x0cc0cc0c, 0xc0cc0cc0, 0xcc0cc0cc, 0x0cc0cc0c, 0xc0ccffcf, 0xfc000000,
+    // [611]: 255A '╚'
+    0x000c1000, 0x0cc0cc0c, 0xc0cc0cc0, 0xcc0cc0cc, 0x0cc0ccff, 0xcffc0cc0, 0xccffcffc,
+    // [618]: 255B '╛'
+    0x00061000, 0xc30c30c3, 0x0c30c30f, 0xffc30fff,
+    // [622]: 255C '╜'
+    0x00080e00, 0xcccccccc, 0xcccccccc, 0xcccccccc, 0xffff0000,
+    // [627]: 255D '╝'
+    0x00081000, 0xcccccccc, 0xcccccccc, 0xccccffff, 0xccccffff,
+    // [632]: 255E '╞'
+    0x000c1800, 0x03003003, 0x00300300, 0x30030030, 0x030030ff, 0x0ff00300, 0x30ff0ff0, 0x03003003,
+    0x00300300, 0x30030030,
+    // [642]: 255F '╟'
+    0x000c1800, 0x0cc0cc0c, 0xc0cc0cc0, 0xcc0cc0cc, 0x0cc0cc0c, 0xc0ccffcf, 0xfc0cc0cc, 0x0cc0cc0c,
+    0xc0cc0cc0, 0xcc0cc0cc,
+    // [652]: 2560 '╠'
+    0x000c1800, 0x0cc0cc0c, 0xc0cc0cc0, 0xcc0cc0cc, 0x0cc0ccff, 0xcffc0cc0, 0xccffcffc, 0x0cc0cc0c,
+    0xc0cc0cc0, 0xcc0cc0cc,
+    // [662]: 2561 '╡'
+    0x00061800, 0xc30c30c3, 0x0c30c30f, 0xffc30fff, 0xc30c30c3, 0x0c300000,
+    // [668]: 2562 '╢'
+    0x00081800, 0xcccccccc, 0xcccccccc, 0xcccccccc, 0xffffcccc, 0xcccccccc, 0xcccccccc,
+    // [675]: 2563 '╣'
+    0x00081800, 0xcccccccc, 0xcccccccc, 0xccccffff, 0xccccffff, 0xcccccccc, 0xcccccccc,
+    // [682]: 2564 '╤'
+    0x000c0e0a, 0xffffff03, 0x0030ffff, 0xff030030, 0x03003003, 0x00300300, 0x30000000,
+    // [689]: 2565 '╥'
+    0x000c0c0c, 0xffffff0c, 0xc0cc0cc0, 0xcc0cc0cc, 0x0cc0cc0c, 0xc0cc0000,
+    // [695]: 2566 '╦'
+    0x000c0e0a, 0xffffff0c, 0xc0ccffff, 0xff0cc0cc, 0x0cc0cc0c, 0xc0cc0cc0, 0xcc000000,
+    // [702]: 2567 '╧'
+    0x000c1000, 0x03003003, 0x00300300, 0x30030030, 0x030030ff, 0xffff0300, 0x30ffffff,
+    // [709]: 2568 '╨'
+    0x000c0e00, 0x0cc0cc0c, 0xc0cc0cc0, 0xcc0cc0cc, 0x0cc0cc0c, 0xc0ccffff, 0xff000000,
+    // [716]: 2569 '╩'
+    0x000c1000, 0x0cc0cc0c, 0xc0cc0cc0, 0xcc0cc0cc, 0x0cc0ccff, 0xffff0cc0, 0xccffffff,
+    // [723]: 256A '╪'
+    0x000c1800, 0x03003003, 0x00300300, 0x30030030, 0x030030ff, 0xffff0300, 0x30ffffff, 0x03003003,
+    0x00300300, 0x30030030,
+    // [733]: 256B '╫'
+    0x000c1800, 0x0cc0cc0c, 0xc0cc0cc0, 0xcc0cc0cc, 0x0cc0cc0c, 0xc0ccffff, 0xff0cc0cc, 0x0cc0cc0c,
+    0xc0cc0cc0, 0xcc0cc0cc,
+    // [743]: 256C '╬'
+    0x000c1800, 0x0cc0cc0c, 0xc0cc0cc0, 0xcc0cc0cc, 0x0cc0ccff, 0xffff0cc0, 0xccffffff, 0x0cc0cc0c,
+    0xc0cc0cc0, 0xcc0cc0cc,
+    // [753]: 256D '╭'
+    0x000c0c0c, 0xfe0ff003, 0x00300300, 0x30030030, 0x03003003, 0x00300000,
+    // [759]: 256E '╮'
+    0x00060c0c, 0x7ffc30c3, 0x0c30c30c, 0x30000000,
+    // [763]: 256F '╯'
+    0x00060e00, 0xc30c30c3, 0x0c30c30c, 0x30fdf000,
+    // [767]: 2570 '╰'
+    0x000c0e00, 0x03003003, 0x00300300, 0x30030030, 0x03003003, 0x0030ff0f, 0xe0000000,
+    // [774]: 2571 '╱'
+    0x000c1800, 0xc00c0060, 0x06003003, 0x00180180, 0x0c00c006, 0x00600300, 0x30018018, 0x00c00c00,
+    0x60060030, 0x03001001,
+    // [784]: 2572 '╲'
+    0x000c1800, 0x00300300, 0x600600c0, 0x0c018018, 0x03003006, 0x00600c00, 0xc0180180, 0x30030060,
+    0x0600c00c, 0x00800800,
+    // [794]: 2573 '╳'
+    0x000c1800, 0xc03c0360, 0x660630c3, 0x0c198198, 0x0f00f006, 0x00600f00, 0xf0198198, 0x30c30c60,
+    0x6606c03c, 0x03801801,
+    // [804]: 2574 '╴'
+    0x0006020c, 0xfff00000,
+    // [806]: 2575 '╵'
+    0x00060e00, 0xc30c30c3, 0x0c30c30c, 0x30c30000,
+    // [810]: 2576 '╶'
+    0x000c020c, 0xff0ff000,
+    // [812]: 2577 '╷'
+    0x00060c0c, 0xc30c30c3, 0x0c30c30c, 0x30000000,
+    // [816]: 2578 '╸'
+    0x0007040b, 0xfffffff0,
+    // [818]: 2579 '╹'
+    0x00070f00, 0xf1e3c78f, 0x1e3c78f1, 0xe3c78f1e, 0x3c000000,
+    // [823]: 257A '╺'
+    0x000c040b, 0xff8ff8ff, 0x8ff80000,
+    // [826]: 257B '╻'
+    0x00070d0b, 0xf1e3c78f, 0x1e3c78f1, 0xe3c78f00,
+    // [830]: 257C '╼'
+    0x000c040b, 0xff8fffff, 0xfff80000,
+    // [833]: 257D '╽'
+    0x00071800, 0x60c18306, 0x0c183060, 0xc1878f1e, 0x3c78f1e3, 0xc78f1e3c, 0x78000000,
+    // [840]: 257E '╾'
+    0x000c040b, 0x07ffffff, 0xf07f0000,
+    // [843]: 257F '╿'
+    0x00071800, 0xf1e3c78f, 0x1e3c78f1, 0xe3c78f1e, 0x3c3060c1, 0x83060c18, 0x30000000,
+];
+
+// Packed glyph patterns for Unicode Specials block
+const SPECIALS_DATA: [u32; 6] = [
+    // [0]: FFFD '�'
+    0x000a1002, 0x0c0303f0, 0xfcc0f03c, 0xff3ff3fc, 0xffffff33, 0x0cc0c030,
+];
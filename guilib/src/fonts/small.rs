@@ -1,18 +1,38 @@
 #![allow(dead_code)]
 //! Small Font
 
-/// Return offset into DATA[] for start of pattern depicting glyph for character c
+/// Return offset into DATA[] for start of pattern depicting glyph for character c.
+/// Characters from optional blocks that were left out of the build by cargo
+/// features get the replacement character glyph.
 pub fn get_glyph_pattern_offset(c: char) -> usize {
     match c as u32 {
         0x20..=0x7E => BASIC_LATIN[(c as usize) - 0x20] as usize,
-        0xA0..=0xFF => LATIN_1[(c as usize) - 0xA0] as usize,
-        0x152..=0x153 => LATIN_EXTENDED_A[(c as usize) - 0x152] as usize,
-        0x2018..=0x2022 => GENERAL_PUNCTUATION[(c as usize) - 0x2018] as usize,
-        0x20AC..=0x20AC => CURRENCY_SYMBOLS[(c as usize) - 0x20AC] as usize,
-        _ => SPECIALS[(0xFFFD as usize) - 0xFFFD] as usize,
+        #[cfg(feature = "small-latin-1")]
+        0xA0..=0xFF => LATIN_1_BASE + LATIN_1[(c as usize) - 0xA0] as usize,
+        #[cfg(feature = "small-latin-extended-a")]
+        0x152..=0x153 => LATIN_EXTENDED_A_BASE + LATIN_EXTENDED_A[(c as usize) - 0x152] as usize,
+        #[cfg(feature = "small-general-punctuation")]
+        0x2018..=0x2022 => GENERAL_PUNCTUATION_BASE + GENERAL_PUNCTUATION[(c as usize) - 0x2018] as usize,
+        #[cfg(feature = "small-currency-symbols")]
+        0x20AC..=0x20AC => CURRENCY_SYMBOLS_BASE + CURRENCY_SYMBOLS[(c as usize) - 0x20AC] as usize,
+        _ => SPECIALS_BASE + SPECIALS[0] as usize,
     }
 }
 
+// Optional blocks are compiled in when their cargo feature is enabled
+const LATIN_1_ENABLED: bool = cfg!(feature = "small-latin-1");
+const LATIN_EXTENDED_A_ENABLED: bool = cfg!(feature = "small-latin-extended-a");
+const GENERAL_PUNCTUATION_ENABLED: bool = cfg!(feature = "small-general-punctuation");
+const CURRENCY_SYMBOLS_ENABLED: bool = cfg!(feature = "small-currency-symbols");
+
+// Offsets of each block's glyph patterns within DATA[]
+const SPECIALS_BASE: usize = BASIC_LATIN_DATA.len();
+const LATIN_1_BASE: usize = SPECIALS_BASE + SPECIALS_DATA.len();
+const LATIN_EXTENDED_A_BASE: usize = LATIN_1_BASE + super::block_len(LATIN_1_ENABLED, &LATIN_1_DATA);
+const GENERAL_PUNCTUATION_BASE: usize = LATIN_EXTENDED_A_BASE + super::block_len(LATIN_EXTENDED_A_ENABLED, &LATIN_EXTENDED_A_DATA);
+const CURRENCY_SYMBOLS_BASE: usize = GENERAL_PUNCTUATION_BASE + super::block_len(GENERAL_PUNCTUATION_ENABLED, &GENERAL_PUNCTUATION_DATA);
+const DATA_LEN: usize = CURRENCY_SYMBOLS_BASE + super::block_len(CURRENCY_SYMBOLS_ENABLED, &CURRENCY_SYMBOLS_DATA);
+
 // Index to Unicode Basic Latin block glyph patterns
 const BASIC_LATIN: [u16; 95] = [
     0, // ' '
@@ -114,140 +134,140 @@ const BASIC_LATIN: [u16; 95] = [
 
 // Index to Unicode Latin 1 block glyph patterns
 const LATIN_1: [u16; 96] = [
-    455, // ' '
-    457, // '¡'
-    459, // '¢'
-    465, // '£'
-    471, // '¤'
-    477, // '¥'
-    483, // '¦'
-    486, // '§'
-    493, // '¨'
-    495, // '©'
-    504, // 'ª'
-    508, // '«'
-    513, // '¬'
-    516, // '­'
-    518, // '®'
-    527, // '¯'
-    529, // '°'
-    532, // '±'
-    537, // '²'
-    540, // '³'
-    543, // '´'
-    545, // 'µ'
-    552, // '¶'
-    560, // '·'
-    562, // '¸'
-    564, // '¹'
-    567, // 'º'
-    571, // '»'
-    576, // '¼'
-    589, // '½'
-    602, // '¾'
-    615, // '¿'
-    620, // 'À'
-    628, // 'Á'
-    636, // 'Â'
-    644, // 'Ã'
-    652, // 'Ä'
-    659, // 'Å'
-    667, // 'Æ'
-    675, // 'Ç'
-    682, // 'È'
-    688, // 'É'
-    694, // 'Ê'
-    700, // 'Ë'
-    706, // 'Ì'
-    710, // 'Í'
-    714, // 'Î'
-    719, // 'Ï'
-    724, // 'Ð'
-    731, // 'Ñ'
-    739, // 'Ò'
-    747, // 'Ó'
-    755, // 'Ô'
-    763, // 'Õ'
-    771, // 'Ö'
-    778, // '×'
-    783, // 'Ø'
-    791, // 'Ù'
-    799, // 'Ú'
-    807, // 'Û'
-    815, // 'Ü'
-    822, // 'Ý'
-    830, // 'Þ'
-    835, // 'ß'
-    841, // 'à'
-    846, // 'á'
-    851, // 'â'
-    856, // 'ã'
-    861, // 'ä'
-    866, // 'å'
-    872, // 'æ'
-    878, // 'ç'
-    883, // 'è'
-    888, // 'é'
-    893, // 'ê'
-    898, // 'ë'
-    903, // 'ì'
-    906, // 'í'
-    909, // 'î'
-    913, // 'ï'
-    917, // 'ð'
-    922, // 'ñ'
-    927, // 'ò'
-    932, // 'ó'
-    937, // 'ô'
-    942, // 'õ'
-    947, // 'ö'
-    952, // '÷'
-    957, // 'ø'
-    963, // 'ù'
-    968, // 'ú'
-    973, // 'û'
-    978, // 'ü'
-    983, // 'ý'
-    991, // 'þ'
-    997, // 'ÿ'
+    0, // ' '
+    2, // '¡'
+    4, // '¢'
+    10, // '£'
+    16, // '¤'
+    22, // '¥'
+    28, // '¦'
+    31, // '§'
+    38, // '¨'
+    40, // '©'
+    49, // 'ª'
+    53, // '«'
+    58, // '¬'
+    61, // '­'
+    63, // '®'
+    72, // '¯'
+    74, // '°'
+    77, // '±'
+    82, // '²'
+    85, // '³'
+    88, // '´'
+    90, // 'µ'
+    97, // '¶'
+    105, // '·'
+    107, // '¸'
+    109, // '¹'
+    112, // 'º'
+    116, // '»'
+    121, // '¼'
+    134, // '½'
+    147, // '¾'
+    160, // '¿'
+    165, // 'À'
+    173, // 'Á'
+    181, // 'Â'
+    189, // 'Ã'
+    197, // 'Ä'
+    204, // 'Å'
+    212, // 'Æ'
+    220, // 'Ç'
+    227, // 'È'
+    233, // 'É'
+    239, // 'Ê'
+    245, // 'Ë'
+    251, // 'Ì'
+    255, // 'Í'
+    259, // 'Î'
+    264, // 'Ï'
+    269, // 'Ð'
+    276, // 'Ñ'
+    284, // 'Ò'
+    292, // 'Ó'
+    300, // 'Ô'
+    308, // 'Õ'
+    316, // 'Ö'
+    323, // '×'
+    328, // 'Ø'
+    336, // 'Ù'
+    344, // 'Ú'
+    352, // 'Û'
+    360, // 'Ü'
+    367, // 'Ý'
+    375, // 'Þ'
+    380, // 'ß'
+    386, // 'à'
+    391, // 'á'
+    396, // 'â'
+    401, // 'ã'
+    406, // 'ä'
+    411, // 'å'
+    417, // 'æ'
+    423, // 'ç'
+    428, // 'è'
+    433, // 'é'
+    438, // 'ê'
+    443, // 'ë'
+    448, // 'ì'
+    451, // 'í'
+    454, // 'î'
+    458, // 'ï'
+    462, // 'ð'
+    467, // 'ñ'
+    472, // 'ò'
+    477, // 'ó'
+    482, // 'ô'
+    487, // 'õ'
+    492, // 'ö'
+    497, // '÷'
+    502, // 'ø'
+    508, // 'ù'
+    513, // 'ú'
+    518, // 'û'
+    523, // 'ü'
+    528, // 'ý'
+    536, // 'þ'
+    542, // 'ÿ'
 ];
 
 // Index to Unicode Latin Extended A block glyph patterns
 const LATIN_EXTENDED_A: [u16; 2] = [
-    1004, // 'Œ'
-    1012, // 'œ'
+    0, // 'Œ'
+    8, // 'œ'
 ];
 
 // Index to General Punctuation block glyph patterns
 const GENERAL_PUNCTUATION: [u16; 11] = [
-    1018, // '‘'
-    1020, // '’'
-    1022, // '‚'
-    1024, // '‛'
-    1026, // '“'
-    1029, // '”'
-    1032, // '„'
-    1035, // '‟'
-    1038, // '†'
-    1041, // '‡'
-    1046, // '•'
+    0, // '‘'
+    2, // '’'
+    4, // '‚'
+    6, // '‛'
+    8, // '“'
+    11, // '”'
+    14, // '„'
+    17, // '‟'
+    20, // '†'
+    23, // '‡'
+    28, // '•'
 ];
 
 // Index to Unicode Currency Symbols block glyph patterns
 const CURRENCY_SYMBOLS: [u16; 1] = [
-    1051, // '€'
+    0, // '€'
 ];
 
 // Index to Unicode Specials block glyph patterns
 const SPECIALS: [u16; 1] = [
-    1058, // '�'
+    0, // '�'
 ];
 
 /// Maximum height of glyph patterns in this bitmap typeface.
 /// This will be true: h + yOffset <= MAX_HEIGHT
 pub const MAX_HEIGHT: u8 = 24;
 
-/// Packed glyph pattern data.
+/// Packed glyph pattern data for all compiled in blocks.
 /// Record format:
 ///  [offset+0]: ((w as u8) << 16) | ((h as u8) << 8) | (yOffset as u8)
 ///  [offset+1..=ceil(w*h/32)]: packed 1-bit pixels; 0=clear, 1=set
@@ -257,7 +277,27 @@ pub const MAX_HEIGHT: u8 = 24;
 ///  h: Height of pattern in pixels
 ///  yOffset: Vertical offset (pixels downward from top of line) to position
 ///     glyph pattern properly relative to text baseline
-pub const DATA: [u32; 1071] = [
+pub const DATA: [u32; DATA_LEN] = super::concat_blocks(&[
+    &BASIC_LATIN_DATA,
+    &SPECIALS_DATA,
+    super::block_if(LATIN_1_ENABLED, &LATIN_1_DATA),
+    super::block_if(LATIN_EXTENDED_A_ENABLED, &LATIN_EXTENDED_A_DATA),
+    super::block_if(GENERAL_PUNCTUATION_ENABLED, &GENERAL_PUNCTUATION_DATA),
+    super::block_if(CURRENCY_SYMBOLS_ENABLED, &CURRENCY_SYMBOLS_DATA),
+]);
+
+/// Flash footprint of each block (glyph patterns plus index)
+pub const BLOCK_COSTS: [super::BlockCost; 6] = [
+    super::BlockCost::new("Basic Latin", true, &BASIC_LATIN_DATA, &BASIC_LATIN),
+    super::BlockCost::new("Latin 1", LATIN_1_ENABLED, &LATIN_1_DATA, &LATIN_1),
+    super::BlockCost::new("Latin Extended A", LATIN_EXTENDED_A_ENABLED, &LATIN_EXTENDED_A_DATA, &LATIN_EXTENDED_A),
+    super::BlockCost::new("General Punctuation", GENERAL_PUNCTUATION_ENABLED, &GENERAL_PUNCTUATION_DATA, &GENERAL_PUNCTUATION),
+    super::BlockCost::new("Currency Symbols", CURRENCY_SYMBOLS_ENABLED, &CURRENCY_SYMBOLS_DATA, &CURRENCY_SYMBOLS),
+    super::BlockCost::new("Specials", true, &SPECIALS_DATA, &SPECIALS),
+];
+
+// Packed glyph patterns for Unicode Basic Latin block
+const BASIC_LATIN_DATA: [u32; 455] = [
     // [0]: 20 ' '
     0x0004020b, 0x00000000,
     // [2]: 21 '!'
@@ -448,232 +488,252 @@ pub const DATA: [u32; 1071] = [
     0x00061204, 0x0c330c30, 0xc30cc303, 0x0c30c30c, 0x0c300000,
     // [452]: 7E '~'
     0x000a0406, 0xcf33c3cc, 0xf3000000,
-    // [455]: A0 ' '
+];
+
+// Packed glyph patterns for Unicode Latin 1 block
+const LATIN_1_DATA: [u32; 549] = [
+    // [0]: A0 ' '
     0x0004020b, 0x00000000,
-    // [457]: A1 '¡'
+    // [2]: A1 '¡'
     0x00020e06, 0xf0fffff0,
-    // [459]: A2 '¢'
+    // [4]: A2 '¢'
     0x000a0e06, 0x0c0303f0, 0xfcccf330, 0xcc33ccf3, 0x33f0fc0c, 0x03000000,
-    // [465]: A3 '£'
+    // [10]: A3 '£'
     0x000a0e06, 0x3c0f0030, 0x0c0300c0, 0xfc3f0300, 0xcc330c3f, 0xcff00000,
-    // [471]: A4 '¤'
+    // [16]: A4 '¤'
     0x000a0e06, 0xc0f033f0, 0xfcc0f03c, 0x0f03c0f0, 0x33f0fcc0, 0xf0300000,
-    // [477]: A5 '¥'
+    // [22]: A5 '¥'
     0x000a0e06, 0xc0f03330, 0xccfffff0, 0xc030ffff, 0xf0c0300c, 0x03000000,
-    // [483]: A6 '¦'
+    // [28]: A6 '¦'
     0x00021204, 0xffff0fff, 0xf0000000,
-    // [486]: A7 '§'
+    // [31]: A7 '§'
     0x000a1204, 0x3f0fcc0f, 0x0300c033, 0xf0fcc0f0, 0x33f0fcc0, 0x300c0f03, 0x3f0fc000,
-    // [493]: A8 '¨'
+    // [38]: A8 '¨'
     0x00060206, 0xcf300000,
-    // [495]: A9 '©'
+    // [40]: A9 '©'
     0x00101004, 0x0ff00ff0, 0x300c300c, 0xc3c3c3c3, 0xc033c033, 0xc033c033, 0xc3c3c3c3, 0x300c300c,
     0x0ff00ff0,
-    // [504]: AA 'ª'
+    // [49]: AA 'ª'
     0x00060e04, 0x30ccf3f3, 0xccf3f3c0, 0x00fff000,
-    // [508]: AB '«'
+    // [53]: AB '«'
     0x000c0a0a, 0xc30c3030, 0xc30c0c30, 0xc330c30c, 0xc30c3000,
-    // [513]: AC '¬'
+    // [58]: AC '¬'
     0x0008060c, 0xffffc0c0, 0xc0c00000,
-    // [516]: AD '­'
+    // [61]: AD '­'
     0x0008020c, 0xffff0000,
-    // [518]: AE '®'
+    // [63]: AE '®'
     0x00101004, 0x0ff00ff0, 0x300c300c, 0xc3f3c3f3, 0xcc33cc33, 0xc3f3c3f3, 0xcc33cc33, 0x300c300c,
     0x0ff00ff0,
-    // [527]: AF '¯'
+    // [72]: AF '¯'
     0x00060206, 0xfff00000,
-    // [529]: B0 '°'
+    // [74]: B0 '°'
     0x00080804, 0x3c3cc3c3, 0xc3c33c3c,
-    // [532]: B1 '±'
+    // [77]: B1 '±'
     0x000a0c08, 0x0c0300c0, 0x30fffff0, 0xc0300c03, 0x0fffff00,
-    // [537]: B2 '²'
+    // [82]: B2 '²'
     0x00060a02, 0xfffc30ff, 0xf0c3fff0,
-    // [540]: B3 '³'
+    // [85]: B3 '³'
     0x00060a02, 0xfffc30ff, 0xfc30fff0,
-    // [543]: B4 '´'
+    // [88]: B4 '´'
     0x00040406, 0xcc330000,
-    // [545]: B5 'µ'
+    // [90]: B5 'µ'
     0x000c0e0a, 0xc30c30c3, 0x0c3030c3, 0x0c30c30c, 0xcfccfc00, 0x30030030, 0x03000000,
-    // [552]: B6 '¶'
+    // [97]: B6 '¶'
     0x000c1206, 0xffcffc33, 0xf33f33f3, 0x3f33c33c, 0x33033033, 0x03303303, 0x30330330, 0x33033000,
-    // [560]: B7 '·'
+    // [105]: B7 '·'
     0x0002020e, 0xf0000000,
-    // [562]: B8 '¸'
+    // [107]: B8 '¸'
     0x00040414, 0xcc330000,
-    // [564]: B9 '¹'
+    // [109]: B9 '¹'
     0x00060a02, 0x30c3cf30, 0xc30cfff0,
-    // [567]: BA 'º'
+    // [112]: BA 'º'
     0x00080c06, 0x3c3cc3c3, 0xc3c33c3c, 0x0000ffff,
-    // [571]: BB '»'
+    // [116]: BB '»'
     0x000c0a0a, 0x0c30c330, 0xc30cc30c, 0x3030c30c, 0x0c30c300,
-    // [576]: BC '¼'
+    // [121]: BC '¼'
     0x00121400, 0x0c030300, 0xc0c03c30, 0x0f030300, 0xc0c03030, 0x0c0c00cf, 0xc033fccc, 0x033300cc,
     0x30330c0f, 0xc303f0c0, 0xc00c3003, 0x0c00c300, 0x30000000,
-    // [589]: BD '½'
+    // [134]: BD '½'
     0x00121400, 0x0c030300, 0xc0c03c30, 0x0f030300, 0xc0c03030, 0x0c0c00cf, 0xc033ffcc, 0x03f300c0,
     0x30300c0f, 0xc303f0c0, 0x0c0c0303, 0x0fc0c3f0, 0x30000000,
-    // [602]: BE '¾'
+    // [147]: BE '¾'
     0x00121400, 0x0c0fc303, 0xf0c0c030, 0x30030fc0, 0xc3f030c0, 0x0c3000cf, 0xc033fccc, 0x033300cc,
     0x30330c0f, 0xc303f0c0, 0xc00c3003, 0x0c00c300, 0x30000000,
-    // [615]: BF '¿'
+    // [160]: BF '¿'
     0x00080e06, 0x30300000, 0x30300c0c, 0x0303c3c3, 0x3c3c0000,
-    // [620]: C0 'À'
+    // [165]: C0 'À'
     0x000a1400, 0x0300c0c0, 0x30000000, 0xc0300c03, 0x0330cc33, 0x0ccfffff, 0xc0f03c0f, 0x03000000,
-    // [628]: C1 'Á'
+    // [173]: C1 'Á'
     0x000a1400, 0x300c00c0, 0x30000000, 0xc0300c03, 0x0330cc33, 0x0ccfffff, 0xc0f03c0f, 0x03000000,
-    // [636]: C2 'Â'
+    // [181]: C2 'Â'
     0x000a1400, 0x0c030330, 0xcc000000, 0xc0300c03, 0x0330cc33, 0x0ccfffff, 0xc0f03c0f, 0x03000000,
-    // [644]: C3 'Ã'
+    // [189]: C3 'Ã'
     0x000a1400, 0xcf33c3cc, 0xf3000000, 0xc0300c03, 0x0330cc33, 0x0ccfffff, 0xc0f03c0f, 0x03000000,
-    // [652]: C4 'Ä'
+    // [197]: C4 'Ä'
     0x000a1202, 0x330cc000, 0x000c0300, 0xc030330c, 0xc330ccff, 0xfffc0f03, 0xc0f03000,
-    // [659]: C5 'Å'
+    // [204]: C5 'Å'
     0x000a1400, 0x3f0fcc0f, 0x033f0fc0, 0xc0300c03, 0x0330cc33, 0x0ccfffff, 0xc0f03c0f, 0x03000000,
-    // [667]: C6 'Æ'
+    // [212]: C6 'Æ'
     0x00100e06, 0xffc0ffc0, 0x03300330, 0x03300330, 0x3ffc3ffc, 0x030c030c, 0x03030303, 0xff03ff03,
-    // [675]: C7 'Ç'
+    // [220]: C7 'Ç'
     0x000a1206, 0x3f0fcc0f, 0x0300c030, 0x0c0300c0, 0x3c0f033f, 0x0fc0c030, 0x0300c000,
-    // [682]: C8 'È'
+    // [227]: C8 'È'
     0x00081400, 0x0c0c3030, 0x0000ffff, 0x03030303, 0x3f3f0303, 0x0303ffff,
-    // [688]: C9 'É'
+    // [233]: C9 'É'
     0x00081400, 0x30300c0c, 0x0000ffff, 0x03030303, 0x3f3f0303, 0x0303ffff,
-    // [694]: CA 'Ê'
+    // [239]: CA 'Ê'
     0x00081400, 0x0c0c3333, 0x0000ffff, 0x03030303, 0x3f3f0303, 0x0303ffff,
-    // [700]: CB 'Ë'
+    // [245]: CB 'Ë'
     0x00081202, 0x33330000, 0xffff0303, 0x03033f3f, 0x03030303, 0xffff0000,
-    // [706]: CC 'Ì'
+    // [251]: CC 'Ì'
     0x00041400, 0x33cc00cc, 0xcccccccc, 0xcccc0000,
-    // [710]: CD 'Í'
+    // [255]: CD 'Í'
     0x00041400, 0xcc330033, 0x33333333, 0x33330000,
-    // [714]: CE 'Î'
+    // [259]: CE 'Î'
     0x00061400, 0x30ccf300, 0x030c30c3, 0x0c30c30c, 0x30c30c00,
-    // [719]: CF 'Ï'
+    // [264]: CF 'Ï'
     0x00061202, 0xcf300030, 0xc30c30c3, 0x0c30c30c, 0x30c00000,
-    // [724]: D0 'Ð'
+    // [269]: D0 'Ð'
     0x000c0e06, 0x0fc0fc30, 0xc30cc0cc, 0x0cc3fc3f, 0xc0cc0c30, 0xc30c0fc0, 0xfc000000,
-    // [731]: D1 'Ñ'
+    // [276]: D1 'Ñ'
     0x000a1400, 0xcf33c3cc, 0xf300000c, 0x3f0fc3f0, 0xfccf33cc, 0xf33f0fc3, 0xf0fc3c0f, 0x03000000,
-    // [739]: D2 'Ò'
+    // [284]: D2 'Ò'
     0x000a1400, 0x0300c0c0, 0x30000003, 0xf0fcc0f0, 0x3c0f03c0, 0xf03c0f03, 0xc0f033f0, 0xfc000000,
-    // [747]: D3 'Ó'
+    // [292]: D3 'Ó'
     0x000a1400, 0x300c00c0, 0x30000003, 0xf0fcc0f0, 0x3c0f03c0, 0xf03c0f03, 0xc0f033f0, 0xfc000000,
-    // [755]: D4 'Ô'
+    // [300]: D4 'Ô'
     0x000a1400, 0x0c030330, 0xcc000003, 0xf0fcc0f0, 0x3c0f03c0, 0xf03c0f03, 0xc0f033f0, 0xfc000000,
-    // [763]: D5 'Õ'
+    // [308]: D5 'Õ'
     0x000a1400, 0xcf33c3cc, 0xf3000003, 0xf0fcc0f0, 0x3c0f03c0, 0xf03c0f03, 0xc0f033f0, 0xfc000000,
-    // [771]: D6 'Ö'
+    // [316]: D6 'Ö'
     0x000a1202, 0x330cc000, 0x003f0fcc, 0x0f03c0f0, 0x3c0f03c0, 0xf03c0f03, 0x3f0fc000,
-    // [778]: D7 '×'
+    // [323]: D7 '×'
     0x000a0a0a, 0xc0f03330, 0xcc0c0303, 0x30ccc0f0, 0x30000000,
-    // [783]: D8 'Ø'
+    // [328]: D8 'Ø'
     0x000e0e06, 0xcfc33f03, 0x030c0c3c, 0x30f0c333, 0x0ccc30f0, 0xc3c3030c, 0x0c0fcc3f, 0x30000000,
-    // [791]: D9 'Ù'
+    // [336]: D9 'Ù'
     0x000a1400, 0x0300c0c0, 0x3000000c, 0x0f03c0f0, 0x3c0f03c0, 0xf03c0f03, 0xc0f033f0, 0xfc000000,
-    // [799]: DA 'Ú'
+    // [344]: DA 'Ú'
     0x000a1400, 0x300c00c0, 0x3000000c, 0x0f03c0f0, 0x3c0f03c0, 0xf03c0f03, 0xc0f033f0, 0xfc000000,
-    // [807]: DB 'Û'
+    // [352]: DB 'Û'
     0x000a1400, 0x0c030330, 0xcc00000c, 0x0f03c0f0, 0x3c0f03c0, 0xf03c0f03, 0xc0f033f0, 0xfc000000,
-    // [815]: DC 'Ü'
+    // [360]: DC 'Ü'
     0x000a1202, 0x330cc000, 0x00c0f03c, 0x0f03c0f0, 0x3c0f03c0, 0xf03c0f03, 0x3f0fc000,
-    // [822]: DD 'Ý'
+    // [367]: DD 'Ý'
     0x000a1400, 0x300c00c0, 0x3000000c, 0x0f03c0f0, 0x3330cc0c, 0x0300c030, 0x0c0300c0, 0x30000000,
-    // [830]: DE 'Þ'
+    // [375]: DE 'Þ'
     0x00080e06, 0x0303033f, 0x3fc3c3c3, 0xc33f3f03, 0x03030000,
-    // [835]: DF 'ß'
+    // [380]: DF 'ß'
     0x000a0e06, 0x0f03c30c, 0xc30cc330, 0xcc3330cc, 0x3c0f033c, 0xcf300000,
-    // [841]: E0 'à'
+    // [386]: E0 'à'
     0x00081004, 0x0c0c3030, 0x00003c3c, 0xc3c3fcfc, 0xc3c3fcfc,
-    // [846]: E1 'á'
+    // [391]: E1 'á'
     0x00081004, 0x30300c0c, 0x00003c3c, 0xc3c3fcfc, 0xc3c3fcfc,
-    // [851]: E2 'â'
+    // [396]: E2 'â'
     0x00081004, 0x3030cccc, 0x00003c3c, 0xc3c3fcfc, 0xc3c3fcfc,
-    // [856]: E3 'ã'
+    // [401]: E3 'ã'
     0x00081004, 0xcccc3333, 0x00003c3c, 0xc3c3fcfc, 0xc3c3fcfc,
-    // [861]: E4 'ä'
+    // [406]: E4 'ä'
     0x00080e06, 0xcccc0000, 0x3c3cc3c3, 0xfcfcc3c3, 0xfcfc0000,
-    // [866]: E5 'å'
+    // [411]: E5 'å'
     0x00081202, 0x3c3cc3c3, 0x3c3c0000, 0x3c3cc3c3, 0xfcfcc3c3, 0xfcfc0000,
-    // [872]: E6 'æ'
+    // [417]: E6 'æ'
     0x000e0a0a, 0x3cf0f3cc, 0x3030c0ff, 0xf3ffc030, 0xc0c33cf0, 0xf3c00000,
-    // [878]: E7 'ç'
+    // [423]: E7 'ç'
     0x00080e0a, 0x3c3cc3c3, 0x0303c3c3, 0x3c3c3030, 0x0c0c0000,
-    // [883]: E8 'è'
+    // [428]: E8 'è'
     0x00081004, 0x0c0c3030, 0x00003c3c, 0xc3c3ffff, 0x03033c3c,
-    // [888]: E9 'é'
+    // [433]: E9 'é'
     0x00081004, 0x30300c0c, 0x00003c3c, 0xc3c3ffff, 0x0303fcfc,
-    // [893]: EA 'ê'
+    // [438]: EA 'ê'
     0x00081004, 0x0c0c3333, 0x00003c3c, 0xc3c3ffff, 0x03033c3c,
-    // [898]: EB 'ë'
+    // [443]: EB 'ë'
     0x00080e06, 0xc3c30000, 0x3c3cc3c3, 0xffff0303, 0xfcfc0000,
-    // [903]: EC 'ì'
+    // [448]: EC 'ì'
     0x00041004, 0x33cc00cc, 0xcccccccc,
-    // [906]: ED 'í'
+    // [451]: ED 'í'
     0x00041004, 0xcc330033, 0x33333333,
-    // [909]: EE 'î'
+    // [454]: EE 'î'
     0x00061004, 0x30ccf300, 0x030c30c3, 0x0c30c30c,
-    // [913]: EF 'ï'
+    // [458]: EF 'ï'
     0x00060e06, 0xcf300030, 0xc30c30c3, 0x0c30c000,
-    // [917]: F0 'ð'
+    // [462]: F0 'ð'
     0x00081004, 0x03333c0f, 0x3330cccc, 0xc3c3c3c3, 0xc3c33c3c,
-    // [922]: F1 'ñ'
+    // [467]: F1 'ñ'
     0x00081004, 0xcccc3333, 0x00003f3f, 0xc3c3c3c3, 0xc3c3c3c3,
-    // [927]: F2 'ò'
+    // [472]: F2 'ò'
     0x00081004, 0x0c0c3030, 0x00003c3c, 0xc3c3c3c3, 0xc3c33c3c,
-    // [932]: F3 'ó'
+    // [477]: F3 'ó'
     0x00081004, 0x30300c0c, 0x00003c3c, 0xc3c3c3c3, 0xc3c33c3c,
-    // [937]: F4 'ô'
+    // [482]: F4 'ô'
     0x00081004, 0x0c0c3333, 0x00003c3c, 0xc3c3c3c3, 0xc3c33c3c,
-    // [942]: F5 'õ'
+    // [487]: F5 'õ'
     0x00081004, 0xcccc3333, 0x00003c3c, 0xc3c3c3c3, 0xc3c33c3c,
-    // [947]: F6 'ö'
+    // [492]: F6 'ö'
     0x00080e06, 0xc3c30000, 0x3c3cc3c3, 0xc3c3c3c3, 0x3c3c0000,
-    // [952]: F7 '÷'
+    // [497]: F7 '÷'
     0x000a0a08, 0x0c030000, 0x00fffff0, 0x00000c03, 0x00000000,
-    // [957]: F8 'ø'
+    // [502]: F8 'ø'
     0x000c0c08, 0xcf0cf030, 0xc30c3cc3, 0xcc33c33c, 0x30c30c0f, 0x30f30000,
-    // [963]: F9 'ù'
+    // [508]: F9 'ù'
     0x00081004, 0x0c0c3030, 0x0000c3c3, 0xc3c3c3c3, 0xc3c3fcfc,
-    // [968]: FA 'ú'
+    // [513]: FA 'ú'
     0x00081004, 0x30300c0c, 0x0000c3c3, 0xc3c3c3c3, 0xc3c3fcfc,
-    // [973]: FB 'û'
+    // [518]: FB 'û'
     0x00081004, 0x0c0c3333, 0x0000c3c3, 0xc3c3c3c3, 0xc3c3fcfc,
-    // [978]: FC 'ü'
+    // [523]: FC 'ü'
     0x00080e06, 0xc3c30000, 0xc3c3c3c3, 0xc3c3c3c3, 0xfcfc0000,
-    // [983]: FD 'ý'
+    // [528]: FD 'ý'
     0x000a1404, 0x300c00c0, 0x3000000c, 0x0f03c0f0, 0x3330cc33, 0x0cc0c030, 0x0c03003c, 0x0f000000,
-    // [991]: FE 'þ'
+    // [536]: FE 'þ'
     0x00081206, 0x03030303, 0x3f3fc3c3, 0xc3c3c3c3, 0x3f3f0303, 0x03030000,
-    // [997]: FF 'ÿ'
+    // [542]: FF 'ÿ'
     0x000a1206, 0x330cc000, 0x00c0f03c, 0x0f03330c, 0xc330cc0c, 0x0300c030, 0x03c0f000,
-    // [1004]: 152 'Œ'
+];
+
+// Packed glyph patterns for Unicode Latin Extended A block
+const LATIN_EXTENDED_A_DATA: [u32; 14] = [
+    // [0]: 152 'Œ'
     0x00100e06, 0xfffcfffc, 0x03030303, 0x03030303, 0x3f033f03, 0x03030303, 0x03030303, 0xfffcfffc,
-    // [1012]: 153 'œ'
+    // [8]: 153 'œ'
     0x000e0a0a, 0x3cf0f3cc, 0x30f0c3ff, 0x0ffc3030, 0xc0c33ff0, 0xffc00000,
-    // [1018]: 2018 '‘'
+];
+
+// Packed glyph patterns for General Punctuation block
+const GENERAL_PUNCTUATION_DATA: [u32; 33] = [
+    // [0]: 2018 '‘'
     0x00040604, 0xcc333300,
-    // [1020]: 2019 '’'
+    // [2]: 2019 '’'
     0x00040604, 0xcccc3300,
-    // [1022]: 201A '‚'
+    // [4]: 201A '‚'
     0x00040612, 0xcccc3300,
-    // [1024]: 201B '‛'
+    // [6]: 201B '‛'
     0x00040604, 0x3333cc00,
-    // [1026]: 201C '“'
+    // [8]: 201C '“'
     0x00080604, 0xcccc3333, 0x33330000,
-    // [1029]: 201D '”'
+    // [11]: 201D '”'
     0x00080604, 0xcccccccc, 0x33330000,
-    // [1032]: 201E '„'
+    // [14]: 201E '„'
     0x00080612, 0xcccccccc, 0x33330000,
-    // [1035]: 201F '‟'
+    // [17]: 201F '‟'
     0x00080604, 0x33333333, 0xcccc0000,
-    // [1038]: 2020 '†'
+    // [20]: 2020 '†'
     0x00060a04, 0x30cfff30, 0xc30c30c0,
-    // [1041]: 2021 '‡'
+    // [23]: 2021 '‡'
     0x00061206, 0x30c30cff, 0xf30c30c3, 0x0cfff30c, 0x30c00000,
-    // [1046]: 2022 '•'
+    // [28]: 2022 '•'
     0x000a0a08, 0x3f0fcfff, 0xffffffff, 0xffff3f0f, 0xc0000000,
-    // [1051]: 20AC '€'
+];
+
+// Packed glyph patterns for Unicode Currency Symbols block
+const CURRENCY_SYMBOLS_DATA: [u32; 7] = [
+    // [0]: 20AC '€'
     0x000c0e04, 0x3f03f0c0, 0xcc0c0ff0, 0xff00c00c, 0x0ff0ffc0, 0xcc0c3f03, 0xf0000000,
-    // [1058]: FFFD '�'
+];
+
+// Packed glyph patterns for Unicode Specials block
+const SPECIALS_DATA: [u32; 13] = [
+    // [0]: FFFD '�'
     0x00121402, 0x00c00030, 0x003f000f, 0xc00f3c03, 0xcf03ccf0, 0xf33cfcff, 0xff3ffff3, 0xfffcff3f,
     0xff0fffc0, 0xf3c03cf0, 0x03f000fc, 0x000c0003, 0x00000000,
 ];
@@ -0,0 +1,186 @@
+//! USB HID keyboards: turn boot protocol keyboard reports into scancodes
+//!
+//! A boot protocol report is 8 bytes: a byte of modifier bits, a reserved
+//! byte, and the usage IDs of up to 6 other keys that are down. Each report
+//! gives the whole keyboard state, so HidKeyboard compares it with the last
+//! one to find the keys that went down or up. Usage IDs name key positions
+//! on a US keyboard, and they map to the key in the same place here, so the
+//! active layout's maps decide what each key types (like the browser
+//! simulator's keyboard table).
+
+use super::scancode::{Key, Scancode};
+
+/// Bytes in a boot protocol keyboard report
+pub const REPORT_SIZE: usize = 8;
+
+/// Usage ID that fills every key slot when too many keys are down to tell
+/// which (phantom state)
+const ERROR_ROLL_OVER: u8 = 0x01;
+
+/// Keys for the modifier bits, from bit 0: left Ctrl, Shift, Alt, GUI, then
+/// right Ctrl, Shift, Alt, GUI (Ctrl and GUI have no key here)
+const MODIFIER_KEYS: [Option<Key>; 8] = [
+    None,
+    Some(Key::P43),
+    Some(Key::P53),
+    None,
+    None,
+    Some(Key::P43),
+    Some(Key::P57),
+    None,
+];
+
+/// Usage IDs of keys that have a key here
+const USAGES: [(u8, Key); 51] = [
+    (0x04, Key::P33), // A
+    (0x05, Key::P48), // B
+    (0x06, Key::P46), // C
+    (0x07, Key::P35), // D
+    (0x08, Key::P25), // E
+    (0x09, Key::P36), // F
+    (0x0a, Key::P37), // G
+    (0x0b, Key::P38), // H
+    (0x0c, Key::P30), // I
+    (0x0d, Key::P39), // J
+    (0x0e, Key::P40), // K
+    (0x0f, Key::P41), // L
+    (0x10, Key::P50), // M
+    (0x11, Key::P49), // N
+    (0x12, Key::P31), // O
+    (0x13, Key::P32), // P
+    (0x14, Key::P23), // Q
+    (0x15, Key::P26), // R
+    (0x16, Key::P34), // S
+    (0x17, Key::P27), // T
+    (0x18, Key::P29), // U
+    (0x19, Key::P47), // V
+    (0x1a, Key::P24), // W
+    (0x1b, Key::P45), // X
+    (0x1c, Key::P28), // Y
+    (0x1d, Key::P44), // Z
+    (0x1e, Key::P13), // 1
+    (0x1f, Key::P14), // 2
+    (0x20, Key::P15), // 3
+    (0x21, Key::P16), // 4
+    (0x22, Key::P17), // 5
+    (0x23, Key::P18), // 6
+    (0x24, Key::P19), // 7
+    (0x25, Key::P20), // 8
+    (0x26, Key::P21), // 9
+    (0x27, Key::P22), // 0
+    (0x28, Key::P52), // Enter
+    (0x29, Key::PC),  // Escape
+    (0x2a, Key::P42), // Backspace
+    (0x2c, Key::P55), // Space
+    (0x36, Key::P54), // Comma
+    (0x37, Key::P56), // Period
+    (0x38, Key::P51), // Slash
+    (0x3a, Key::P3),  // F1
+    (0x3b, Key::P4),  // F2
+    (0x3c, Key::P7),  // F3
+    (0x3d, Key::P8),  // F4
+    (0x4f, Key::P6),  // Right arrow
+    (0x50, Key::P5),  // Left arrow
+    (0x51, Key::P9),  // Down arrow
+    (0x52, Key::P2),  // Up arrow
+];
+
+/// Most keys that can be held at once: one per modifier bit and key slot
+const HELD_SIZE: usize = 8 + REPORT_SIZE - 2;
+
+/// Most scancodes from one report: every held key up, and as many down
+pub const MAX_EVENTS: usize = 2 * HELD_SIZE;
+
+/// Look up the key for a usage ID
+pub fn key_for_usage(usage: u8) -> Option<Key> {
+    let found = USAGES.iter().find(|(u, _)| *u == usage);
+    found.map(|(_, key)| *key)
+}
+
+/// Keys held according to the last report, modifier bits first, then key
+/// slots in report order
+pub struct HidKeyboard {
+    held: [Option<Key>; HELD_SIZE],
+}
+impl HidKeyboard {
+    /// Initialize with no keys held
+    pub const fn new() -> HidKeyboard {
+        HidKeyboard {
+            held: [None; HELD_SIZE],
+        }
+    }
+
+    /// Take a report, returning scancodes for the keys that went up, then
+    /// for the keys that went down. Modifiers go down before other keys and
+    /// up after them. Keys that both Shift bits map to only go up when
+    /// neither bit is set. A phantom state report changes nothing, so keys
+    /// stay down until a report says which keys are down.
+    pub fn report(&mut self, report: &[u8; REPORT_SIZE]) -> [Option<Scancode>; MAX_EVENTS] {
+        let mut events = [None; MAX_EVENTS];
+        if report[2..].contains(&ERROR_ROLL_OVER) {
+            return events;
+        }
+        let mut held = [None; HELD_SIZE];
+        let mods = (0..8).filter(|bit| report[0] & (1 << bit) != 0);
+        let mods = mods.map(|bit| MODIFIER_KEYS[bit]);
+        let keys = report[2..].iter().map(|u| key_for_usage(*u));
+        for (slot, key) in held.iter_mut().zip(mods.chain(keys)) {
+            *slot = key;
+        }
+        let ups = self.held.iter().enumerate().rev();
+        let ups = ups.filter_map(|(i, k)| changed(&self.held, i, *k, &held));
+        let downs = held.iter().enumerate();
+        let downs = downs.filter_map(|(i, k)| changed(&held, i, *k, &self.held));
+        let scancodes = ups.map(Scancode::Release).chain(downs.map(Scancode::Press));
+        for (ev, sc) in events.iter_mut().zip(scancodes) {
+            *ev = Some(sc);
+        }
+        self.held = held;
+        events
+    }
+}
+impl Default for HidKeyboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Check key k at index i of held keys a: get k if this is its first place
+/// in a, and it is not held in b
+fn changed(a: &[Option<Key>], i: usize, k: Option<Key>, b: &[Option<Key>]) -> Option<Key> {
+    let key = k?;
+    let first = a.iter().position(|h| *h == k) == Some(i);
+    (first && !b.contains(&k)).then_some(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn events(hid: &mut HidKeyboard, report: [u8; REPORT_SIZE]) -> [Option<Scancode>; 3] {
+        let evs = hid.report(&report);
+        assert!(evs[3..].iter().all(|ev| ev.is_none()));
+        [evs[0], evs[1], evs[2]]
+    }
+
+    #[test]
+    fn reports_to_scancodes() {
+        let mut hid = HidKeyboard::new();
+        let (p, r) = (Scancode::Press, Scancode::Release);
+        // Shift goes down before H, even when they are in the same report
+        let evs = events(&mut hid, [0x02, 0, 0x0b, 0, 0, 0, 0, 0]);
+        assert_eq!(evs, [Some(p(Key::P43)), Some(p(Key::P38)), None]);
+        // Both Shift keys are one key here, so it stays down for the other
+        let evs = events(&mut hid, [0x22, 0, 0x0b, 0, 0, 0, 0, 0]);
+        assert_eq!(evs, [None, None, None]);
+        let evs = events(&mut hid, [0x20, 0, 0x0b, 0x0c, 0, 0, 0, 0]);
+        assert_eq!(evs, [Some(p(Key::P30)), None, None]);
+        // Phantom state leaves keys down, and a key slot can move
+        let evs = events(&mut hid, [0x20, 0, 1, 1, 1, 1, 1, 1]);
+        assert_eq!(evs, [None, None, None]);
+        let evs = events(&mut hid, [0x00, 0, 0x0c, 0, 0, 0, 0, 0]);
+        assert_eq!(evs, [Some(r(Key::P38)), Some(r(Key::P43)), None]);
+        let evs = events(&mut hid, [0; REPORT_SIZE]);
+        assert_eq!(evs, [Some(r(Key::P30)), None, None]);
+    }
+}
@@ -1,18 +1,113 @@
 #![no_std]
 
+mod alternates;
 mod blit;
+mod compose;
+mod dialog;
+mod editor;
+mod focus;
+mod fontcosts;
 mod fonts;
+pub mod hid;
 mod kbd;
+mod modifiers;
+mod nav;
+mod repeat;
+pub mod scancode;
+mod settings;
+mod softkeys;
+mod strbuf;
 pub mod state;
+mod symbols;
+mod textarea;
+mod toast;
+mod touch;
+mod undo;
 mod views;
+mod widgets;
 
 /// Public API for keyboard and screen events
 pub mod api {
-    use super::{demo, kbd, state, views};
+    pub use super::fonts::{BlockCost, FontCost, FONT_COSTS};
 
-    /// Repaint the active view
+    /// Milliseconds between calls to tick
+    pub const TICK_MS: u32 = 100;
+    use super::{alternates, compose, demo, focus, kbd, modifiers, nav, repeat, softkeys, state};
+    use super::{hid, scancode, symbols, toast, touch, views};
+
+    /// Repaint the active view, collecting its focusable widgets as it
+    /// paints, then mark the focused widget
     pub fn repaint(fb: &mut state::FrameBuf, ctx: &mut state::Context) {
-        views::home_screen(fb, ctx);
+        ctx.focus.clear();
+        (nav::active(ctx).paint)(fb, ctx);
+        focus::paint_indicator(&mut fb.buf, ctx);
+        views::apply_theme(fb, ctx);
+        toast::paint(fb, ctx);
+    }
+
+    /// Set the home screen title, repainting only the status bar
+    pub fn set_title(fb: &mut state::FrameBuf, ctx: &mut state::Context, title: &str) {
+        ctx.status_title.set(title);
+        views::status_bar_repaint(fb, ctx);
+    }
+
+    /// Set the status bar clock text (24 hour "HH:MM"), repainting only the
+    /// status bar
+    pub fn set_time(fb: &mut state::FrameBuf, ctx: &mut state::Context, time: &str) {
+        ctx.status_time.set(time);
+        views::status_bar_repaint(fb, ctx);
+    }
+
+    /// Set the battery level and charging state, repainting only the status
+    /// bar
+    pub fn set_battery(
+        fb: &mut state::FrameBuf,
+        ctx: &mut state::Context,
+        level: state::Battery,
+        charging: bool,
+    ) {
+        ctx.status_battery = level;
+        ctx.status_charging = charging;
+        views::status_bar_repaint(fb, ctx);
+    }
+
+    /// Set the radio signal strength, repainting only the status bar
+    pub fn set_radio(fb: &mut state::FrameBuf, ctx: &mut state::Context, level: state::Radio) {
+        ctx.status_radio = level;
+        views::status_bar_repaint(fb, ctx);
+    }
+
+    /// Set the placeholder text shown while the home screen note is empty
+    pub fn set_note(fb: &mut state::FrameBuf, ctx: &mut state::Context, note: &str) {
+        ctx.note.set(note);
+        repaint(fb, ctx);
+    }
+
+    /// Show a toast notification over the active view
+    pub fn toast(fb: &mut state::FrameBuf, ctx: &mut state::Context, text: &str) {
+        toast::show(ctx, text);
+        repaint(fb, ctx);
+    }
+
+    /// Advance timers by 1 tick. Hosts should call this every TICK_MS.
+    pub fn tick(fb: &mut state::FrameBuf, ctx: &mut state::Context) {
+        toast::tick(fb, ctx);
+        modifiers::tick(ctx);
+        if alternates::tick(ctx) {
+            repaint(fb, ctx);
+        }
+        // A held key repeats its keystroke, and stays drawn pressed
+        if let Some((i, result)) = repeat::tick(ctx, TICK_MS) {
+            if handle_result(fb, ctx, i, result) {
+                views::keyboard_invert_key(fb, i);
+            }
+        }
+    }
+
+    /// Set how long a key must be held before it repeats, and the time
+    /// between repeats, in milliseconds (a delay of 0 turns repeat off)
+    pub fn set_key_repeat(ctx: &mut state::Context, delay_ms: u32, interval_ms: u32) {
+        repeat::set_timing(ctx, delay_ms, interval_ms);
     }
 
     /// Handle a key down event
@@ -20,49 +115,147 @@ pub mod api {
         if key_index >= kbd::MAP_SIZE as u32 {
             return;
         }
-        let result = &kbd::cur_map_lut(ctx)[key_index as usize];
-        match result {
-            kbd::R::C(c) => {
-                ctx.buffer_keystroke(*c);
-                repaint(fb, ctx);
-            }
-            kbd::R::AltL => {
-                kbd::modkey_down(ctx, result);
+        let i = key_index as usize;
+        let lut = kbd::cur_map_lut(ctx);
+        repeat::key_down(ctx, i, lut);
+        handle_result(fb, ctx, i, &lut[i]);
+        views::keyboard_invert_key(fb, i);
+    }
+
+    /// Handle the keystroke result of a key press or repeat. A held key
+    /// stops repeating once it changes the view, so its repeats do not land
+    /// in the view that it opened or went back to.
+    /// Return: true if the screen was repainted (key no longer shows pressed)
+    fn handle_result(
+        fb: &mut state::FrameBuf,
+        ctx: &mut state::Context,
+        key_index: usize,
+        result: &kbd::R,
+    ) -> bool {
+        let view = ctx.views.active();
+        let repainted = handle_keystroke(fb, ctx, key_index, result);
+        if ctx.views.active() != view {
+            repeat::stop(ctx);
+        }
+        repainted
+    }
+
+    /// Handle a keystroke result for handle_result
+    fn handle_keystroke(
+        fb: &mut state::FrameBuf,
+        ctx: &mut state::Context,
+        key_index: usize,
+        result: &kbd::R,
+    ) -> bool {
+        // Modifier keys latch, lock, or hold their layer. Other keys release
+        // one-shot modifiers, now that their result has been looked up.
+        if modifiers::key_down(ctx, key_index, result) {
+            repaint(fb, ctx);
+            return true;
+        }
+        // Dead keys and Compose sequences turn keystrokes into accented
+        // characters before anything else sees them
+        let composed;
+        let result = match compose::feed(ctx, result) {
+            compose::Feed::Pass => result,
+            compose::Feed::Used => {
                 repaint(fb, ctx);
+                return true;
             }
-            kbd::R::AltR => {
-                kbd::modkey_down(ctx, result);
-                repaint(fb, ctx);
+            compose::Feed::Char(c) => {
+                composed = kbd::R::C(c);
+                &composed
             }
-            kbd::R::Shift => {
-                kbd::modkey_down(ctx, result);
+            compose::Feed::Both(c) => {
+                (nav::active(ctx).handle_key)(ctx, &kbd::R::C(c));
                 repaint(fb, ctx);
+                result
             }
-            _ => (),
+        };
+        // Characters typed on the home screen start the long-press timer
+        alternates::key_down(ctx, key_index, result);
+        // The active view gets first chance at every keystroke, including
+        // the F-keys and Click. Arrows and Click that it passes on move focus
+        // between its widgets or activate the focused one.
+        if (nav::active(ctx).handle_key)(ctx, result) || focus::handle_key(ctx, result) {
+            repaint(fb, ctx);
+            return true;
         }
-        views::keyboard_invert_key(fb, key_index as usize);
+        match result {
+            kbd::R::Symbol => symbols::open(ctx),
+            // F-keys carry out the active view's soft key actions
+            _ if softkeys::press(ctx, result) => (),
+            _ => return false,
+        }
+        repaint(fb, ctx);
+        true
     }
 
     /// Handle a key up event
-    pub fn keyup(fb: &mut state::FrameBuf, _ctx: &mut state::Context, key_index: u32) {
+    pub fn keyup(fb: &mut state::FrameBuf, ctx: &mut state::Context, key_index: u32) {
         if key_index >= kbd::MAP_SIZE as u32 {
             return;
         }
+        alternates::key_up(ctx, key_index as usize);
+        repeat::key_up(ctx, key_index as usize);
+        // A modifier held down for other keys is released as it comes up
+        if modifiers::key_up(ctx, key_index as usize) {
+            repaint(fb, ctx);
+            return;
+        }
         views::keyboard_invert_key(fb, key_index as usize);
     }
 
-    /// Change keyboard layout to azerty
-    pub fn kbd_set_layout_azerty(fb: &mut state::FrameBuf, ctx: &mut state::Context) {
-        kbd::set_layout(ctx, kbd::Layout::Azerty);
-        kbd::set_modkey(ctx, kbd::ModKey::Base);
-        repaint(fb, ctx);
+    /// Handle a USB HID boot protocol keyboard report, pressing and releasing
+    /// keys to match the keys that the report says are down
+    pub fn hid_report(
+        fb: &mut state::FrameBuf,
+        ctx: &mut state::Context,
+        report: &[u8; hid::REPORT_SIZE],
+    ) {
+        let events = ctx.hid.report(report);
+        for sc in events.iter().flatten() {
+            match sc {
+                scancode::Scancode::Press(key) => keydown(fb, ctx, key.index() as u32),
+                scancode::Scancode::Release(key) => keyup(fb, ctx, key.index() as u32),
+            }
+        }
     }
 
-    /// Change keyboard layout to qwerty
-    pub fn kbd_set_layout_qwerty(fb: &mut state::FrameBuf, ctx: &mut state::Context) {
-        kbd::set_layout(ctx, kbd::Layout::Qwerty);
-        kbd::set_modkey(ctx, kbd::ModKey::Base);
-        repaint(fb, ctx);
+    /// Handle a touch or pointer press at a screen position, pressing the
+    /// on-screen key or focusing the widget under it
+    pub fn touch_down(fb: &mut state::FrameBuf, ctx: &mut state::Context, x: u32, y: u32) {
+        touch::down(fb, ctx, x as usize, y as usize);
+    }
+
+    /// Handle a touch or pointer moving to a screen position while pressed
+    pub fn touch_move(fb: &mut state::FrameBuf, ctx: &mut state::Context, x: u32, y: u32) {
+        touch::move_to(fb, ctx, x as usize, y as usize);
+    }
+
+    /// Handle a touch or pointer release at a screen position, releasing the
+    /// key or activating the widget that the touch started on
+    pub fn touch_up(fb: &mut state::FrameBuf, ctx: &mut state::Context, x: u32, y: u32) {
+        touch::up(fb, ctx, x as usize, y as usize);
+    }
+
+    /// Handle a touch or pointer that the host cancelled, releasing its key
+    /// without activating anything
+    pub fn touch_cancel(fb: &mut state::FrameBuf, ctx: &mut state::Context) {
+        touch::cancel(fb, ctx);
+    }
+
+    /// Get the index of the current keyboard map (layout and modifier keys)
+    /// so a host can show a matching keyboard overlay. Layouts are changed
+    /// from the settings view.
+    pub fn kbd_map_index(ctx: &state::Context) -> i32 {
+        kbd::cur_map_index(ctx)
+    }
+
+    /// Get the name of the current keyboard layout (as defined in
+    /// layouts/*.kbd), so a host can show matching key caps
+    pub fn kbd_layout_name(ctx: &state::Context) -> &'static str {
+        kbd::LAYOUT_NAMES[ctx.kbd_layout as usize]
     }
 
     /// Step the UI demonstration animation by 1 tick
@@ -73,12 +266,13 @@ pub mod api {
 
 /// UI Demonstration functions to substitute for unimplemented UI events
 mod demo {
-    use super::{api, state};
+    use super::scancode::Key;
+    use super::{api, kbd, settings, state};
 
     /// Step the UI demonstration animation by 1 tick
     pub fn tick(fb: &mut state::FrameBuf, ctx: &mut state::Context) {
         let fr = ctx.demo_frame;
-        ctx.demo_frame = (fr + 1) % 171;
+        ctx.demo_frame = (fr + 1) % 167;
         match fr {
             0..=4 => {
                 ctx.cycle_radio();
@@ -88,228 +282,585 @@ mod demo {
                 ctx.cycle_battery();
                 api::repaint(fb, ctx);
             }
-            10 => api::kbd_set_layout_qwerty(fb, ctx),
-            11 => api::keydown(fb, ctx, Key::P4 as u32), // shift (F2)
-            12 => api::keyup(fb, ctx, Key::P4 as u32),   // shift (F2)
+            10 => {
+                settings::set_layout(ctx, kbd::Layout::Qwerty);
+                api::repaint(fb, ctx);
+            }
+            11 => api::keydown(fb, ctx, Key::P43 as u32), // shift, one-shot
+            12 => api::keyup(fb, ctx, Key::P43 as u32),   // shift
             13 => api::keydown(fb, ctx, Key::P24 as u32), // W
-            14 => api::keyup(fb, ctx, Key::P24 as u32),  // W
-            15 => api::keydown(fb, ctx, Key::P4 as u32), // shift (F2)
-            16 => api::keyup(fb, ctx, Key::P4 as u32),   // shift (F2)
-            17 => api::keydown(fb, ctx, Key::P38 as u32), // h
-            18 => api::keyup(fb, ctx, Key::P38 as u32),  // h
-            19 => api::keydown(fb, ctx, Key::P33 as u32), // a
-            20 => api::keyup(fb, ctx, Key::P33 as u32),  // a
-            21 => api::keydown(fb, ctx, Key::P27 as u32), // t
-            22 => api::keyup(fb, ctx, Key::P27 as u32),  // t
-            23 => api::keydown(fb, ctx, Key::P55 as u32), //
-            24 => api::keyup(fb, ctx, Key::P55 as u32),  //
-            25 => api::keydown(fb, ctx, Key::P30 as u32), // i
-            26 => api::keyup(fb, ctx, Key::P30 as u32),  // i
-            27 => api::keydown(fb, ctx, Key::P34 as u32), // s
-            28 => api::keyup(fb, ctx, Key::P34 as u32),  // s
-            29 => api::keydown(fb, ctx, Key::P55 as u32), //
-            30 => api::keyup(fb, ctx, Key::P55 as u32),  //
-            31 => api::keydown(fb, ctx, Key::P30 as u32), // i
-            32 => api::keyup(fb, ctx, Key::P30 as u32),  // i
-            33 => api::keydown(fb, ctx, Key::P27 as u32), // t
-            34 => api::keyup(fb, ctx, Key::P27 as u32),  // t
-            35 => api::keydown(fb, ctx, Key::P55 as u32), //
-            36 => api::keyup(fb, ctx, Key::P55 as u32),  //
-            37 => api::keydown(fb, ctx, Key::P28 as u32), // y
-            38 => api::keyup(fb, ctx, Key::P28 as u32),  // y
-            39 => api::keydown(fb, ctx, Key::P31 as u32), // o
-            40 => api::keyup(fb, ctx, Key::P31 as u32),  // o
-            41 => api::keydown(fb, ctx, Key::P29 as u32), // u
-            42 => api::keyup(fb, ctx, Key::P29 as u32),  // u
-            43 => api::keydown(fb, ctx, Key::P55 as u32), //
-            44 => api::keyup(fb, ctx, Key::P55 as u32),  //
-            45 => api::keydown(fb, ctx, Key::P24 as u32), // w
-            46 => api::keyup(fb, ctx, Key::P24 as u32),  // w
-            47 => api::keydown(fb, ctx, Key::P31 as u32), // o
-            48 => api::keyup(fb, ctx, Key::P31 as u32),  // o
-            49 => api::keydown(fb, ctx, Key::P29 as u32), // u
-            50 => api::keyup(fb, ctx, Key::P29 as u32),  // u
-            51 => api::keydown(fb, ctx, Key::P41 as u32), // l
-            52 => api::keyup(fb, ctx, Key::P41 as u32),  // l
-            53 => api::keydown(fb, ctx, Key::P35 as u32), // d
-            54 => api::keyup(fb, ctx, Key::P35 as u32),  // d
-            55 => api::keydown(fb, ctx, Key::P55 as u32), //
-            56 => api::keyup(fb, ctx, Key::P55 as u32),  //
-            57 => api::keydown(fb, ctx, Key::P34 as u32), // s
-            58 => api::keyup(fb, ctx, Key::P34 as u32),  // s
+            14 => api::keyup(fb, ctx, Key::P24 as u32),   // W
+            15 => api::keydown(fb, ctx, Key::P38 as u32), // h
+            16 => api::keyup(fb, ctx, Key::P38 as u32),   // h
+            17 => api::keydown(fb, ctx, Key::P33 as u32), // a
+            18 => api::keyup(fb, ctx, Key::P33 as u32),   // a
+            19 => api::keydown(fb, ctx, Key::P27 as u32), // t
+            20 => api::keyup(fb, ctx, Key::P27 as u32),   // t
+            21 => api::keydown(fb, ctx, Key::P55 as u32), //
+            22 => api::keyup(fb, ctx, Key::P55 as u32),   //
+            23 => api::keydown(fb, ctx, Key::P30 as u32), // i
+            24 => api::keyup(fb, ctx, Key::P30 as u32),   // i
+            25 => api::keydown(fb, ctx, Key::P34 as u32), // s
+            26 => api::keyup(fb, ctx, Key::P34 as u32),   // s
+            27 => api::keydown(fb, ctx, Key::P55 as u32), //
+            28 => api::keyup(fb, ctx, Key::P55 as u32),   //
+            29 => api::keydown(fb, ctx, Key::P30 as u32), // i
+            30 => api::keyup(fb, ctx, Key::P30 as u32),   // i
+            31 => api::keydown(fb, ctx, Key::P27 as u32), // t
+            32 => api::keyup(fb, ctx, Key::P27 as u32),   // t
+            33 => api::keydown(fb, ctx, Key::P55 as u32), //
+            34 => api::keyup(fb, ctx, Key::P55 as u32),   //
+            35 => api::keydown(fb, ctx, Key::P28 as u32), // y
+            36 => api::keyup(fb, ctx, Key::P28 as u32),   // y
+            37 => api::keydown(fb, ctx, Key::P31 as u32), // o
+            38 => api::keyup(fb, ctx, Key::P31 as u32),   // o
+            39 => api::keydown(fb, ctx, Key::P29 as u32), // u
+            40 => api::keyup(fb, ctx, Key::P29 as u32),   // u
+            41 => api::keydown(fb, ctx, Key::P55 as u32), //
+            42 => api::keyup(fb, ctx, Key::P55 as u32),   //
+            43 => api::keydown(fb, ctx, Key::P24 as u32), // w
+            44 => api::keyup(fb, ctx, Key::P24 as u32),   // w
+            45 => api::keydown(fb, ctx, Key::P31 as u32), // o
+            46 => api::keyup(fb, ctx, Key::P31 as u32),   // o
+            47 => api::keydown(fb, ctx, Key::P29 as u32), // u
+            48 => api::keyup(fb, ctx, Key::P29 as u32),   // u
+            49 => api::keydown(fb, ctx, Key::P41 as u32), // l
+            50 => api::keyup(fb, ctx, Key::P41 as u32),   // l
+            51 => api::keydown(fb, ctx, Key::P35 as u32), // d
+            52 => api::keyup(fb, ctx, Key::P35 as u32),   // d
+            53 => api::keydown(fb, ctx, Key::P55 as u32), //
+            54 => api::keyup(fb, ctx, Key::P55 as u32),   //
+            55 => api::keydown(fb, ctx, Key::P34 as u32), // s
+            56 => api::keyup(fb, ctx, Key::P34 as u32),   // s
+            57 => api::keydown(fb, ctx, Key::P25 as u32), // e
+            58 => api::keyup(fb, ctx, Key::P25 as u32),   // e
             59 => api::keydown(fb, ctx, Key::P25 as u32), // e
-            60 => api::keyup(fb, ctx, Key::P25 as u32),  // e
-            61 => api::keydown(fb, ctx, Key::P25 as u32), // e
-            62 => api::keyup(fb, ctx, Key::P25 as u32),  // e
-            63 => api::keydown(fb, ctx, Key::P51 as u32), // ?
-            64 => api::keyup(fb, ctx, Key::P51 as u32),  // ?
-            65 => api::keydown(fb, ctx, Key::P55 as u32), //
-            66 => api::keyup(fb, ctx, Key::P55 as u32),  //
-            67 => api::keydown(fb, ctx, Key::P4 as u32), // shift (F2)
-            68 => api::keyup(fb, ctx, Key::P4 as u32),   // shift (F2)
-            69 => api::keydown(fb, ctx, Key::P30 as u32), // I
-            70 => api::keyup(fb, ctx, Key::P30 as u32),  // I
-            71 => api::keydown(fb, ctx, Key::P4 as u32), // shift (F2)
-            72 => api::keyup(fb, ctx, Key::P4 as u32),   // shift (F2)
-            73 => api::keydown(fb, ctx, Key::P36 as u32), // f
-            74 => api::keyup(fb, ctx, Key::P36 as u32),  // f
-            75 => api::keydown(fb, ctx, Key::P55 as u32), //
-            76 => api::keyup(fb, ctx, Key::P55 as u32),  //
-            77 => api::keydown(fb, ctx, Key::P33 as u32), // a
-            78 => api::keyup(fb, ctx, Key::P33 as u32),  // a
-            79 => api::keydown(fb, ctx, Key::P29 as u32), // u
-            80 => api::keyup(fb, ctx, Key::P29 as u32),  // u
-            81 => api::keydown(fb, ctx, Key::P37 as u32), // g
-            82 => api::keyup(fb, ctx, Key::P37 as u32),  // g
-            83 => api::keydown(fb, ctx, Key::P38 as u32), // h
-            84 => api::keyup(fb, ctx, Key::P38 as u32),  // h
-            85 => api::keydown(fb, ctx, Key::P27 as u32), // t
-            86 => api::keyup(fb, ctx, Key::P27 as u32),  // t
-            87 => api::keydown(fb, ctx, Key::P55 as u32), //
-            88 => api::keyup(fb, ctx, Key::P55 as u32),  //
-            89 => api::keydown(fb, ctx, Key::P31 as u32), // o
-            90 => api::keyup(fb, ctx, Key::P31 as u32),  // o
-            91 => api::keydown(fb, ctx, Key::P36 as u32), // f
-            92 => api::keyup(fb, ctx, Key::P36 as u32),  // f
-            93 => api::keydown(fb, ctx, Key::P55 as u32), //
-            94 => api::keyup(fb, ctx, Key::P55 as u32),  //
-            95 => api::keydown(fb, ctx, Key::P24 as u32), // w
-            96 => api::keyup(fb, ctx, Key::P24 as u32),  // w
-            97 => api::keydown(fb, ctx, Key::P31 as u32), // o
-            98 => api::keyup(fb, ctx, Key::P31 as u32),  // o
-            99 => api::keydown(fb, ctx, Key::P25 as u32), // e
-            100 => api::keyup(fb, ctx, Key::P25 as u32), // e
-            101 => api::keydown(fb, ctx, Key::P55 as u32), //
-            102 => api::keyup(fb, ctx, Key::P55 as u32), //
-            103 => api::keydown(fb, ctx, Key::P31 as u32), // o
-            104 => api::keyup(fb, ctx, Key::P31 as u32), // o
-            105 => api::keydown(fb, ctx, Key::P26 as u32), // r
-            106 => api::keyup(fb, ctx, Key::P26 as u32), // r
-            107 => api::keydown(fb, ctx, Key::P55 as u32), //
-            108 => api::keyup(fb, ctx, Key::P55 as u32), //
-            109 => api::keydown(fb, ctx, Key::P24 as u32), // w
-            110 => api::keyup(fb, ctx, Key::P24 as u32), // w
-            111 => api::keydown(fb, ctx, Key::P31 as u32), // o
-            112 => api::keyup(fb, ctx, Key::P31 as u32), // o
-            113 => api::keydown(fb, ctx, Key::P49 as u32), // n
-            114 => api::keyup(fb, ctx, Key::P49 as u32), // n
-            115 => api::keydown(fb, ctx, Key::P35 as u32), // d
-            116 => api::keyup(fb, ctx, Key::P35 as u32), // d
-            117 => api::keydown(fb, ctx, Key::P25 as u32), // e
-            118 => api::keyup(fb, ctx, Key::P25 as u32), // e
-            119 => api::keydown(fb, ctx, Key::P26 as u32), // r
-            120 => api::keyup(fb, ctx, Key::P26 as u32), // r
-            121 => api::keydown(fb, ctx, Key::P54 as u32), // ,
-            122 => api::keyup(fb, ctx, Key::P54 as u32), // ,
-            123 => api::keydown(fb, ctx, Key::P55 as u32), //
-            124 => api::keyup(fb, ctx, Key::P55 as u32), //
-            125 => api::keydown(fb, ctx, Key::P46 as u32), // c
-            126 => api::keyup(fb, ctx, Key::P46 as u32), // c
-            127 => api::keydown(fb, ctx, Key::P25 as u32), // e
-            128 => api::keyup(fb, ctx, Key::P25 as u32), // e
-            129 => api::keydown(fb, ctx, Key::P33 as u32), // a
-            130 => api::keyup(fb, ctx, Key::P33 as u32), // a
-            131 => api::keydown(fb, ctx, Key::P34 as u32), // s
-            132 => api::keyup(fb, ctx, Key::P34 as u32), // s
-            133 => api::keydown(fb, ctx, Key::P25 as u32), // e
-            134 => api::keyup(fb, ctx, Key::P25 as u32), // e
-            135 => api::keydown(fb, ctx, Key::P55 as u32), //
-            136 => api::keyup(fb, ctx, Key::P55 as u32), //
-            137 => api::keydown(fb, ctx, Key::P28 as u32), // y
-            138 => api::keyup(fb, ctx, Key::P28 as u32), // y
-            139 => api::keydown(fb, ctx, Key::P31 as u32), // o
-            140 => api::keyup(fb, ctx, Key::P31 as u32), // o
-            141 => api::keydown(fb, ctx, Key::P29 as u32), // u
-            142 => api::keyup(fb, ctx, Key::P29 as u32), // u
-            143 => api::keydown(fb, ctx, Key::P26 as u32), // r
-            144 => api::keyup(fb, ctx, Key::P26 as u32), // r
-            145 => api::keydown(fb, ctx, Key::P55 as u32), //
-            146 => api::keyup(fb, ctx, Key::P55 as u32), //
-            147 => api::keydown(fb, ctx, Key::P34 as u32), // s
-            148 => api::keyup(fb, ctx, Key::P34 as u32), // s
-            149 => api::keydown(fb, ctx, Key::P25 as u32), // e
-            150 => api::keyup(fb, ctx, Key::P25 as u32), // e
-            151 => api::keydown(fb, ctx, Key::P33 as u32), // a
-            152 => api::keyup(fb, ctx, Key::P33 as u32), // a
-            153 => api::keydown(fb, ctx, Key::P26 as u32), // r
-            154 => api::keyup(fb, ctx, Key::P26 as u32), // r
-            155 => api::keydown(fb, ctx, Key::P46 as u32), // c
-            156 => api::keyup(fb, ctx, Key::P46 as u32), // c
-            157 => api::keydown(fb, ctx, Key::P38 as u32), // h
-            158 => api::keyup(fb, ctx, Key::P38 as u32), // h
-            159 => api::keydown(fb, ctx, Key::P56 as u32), // .
-            160 => api::keyup(fb, ctx, Key::P56 as u32), // .
+            60 => api::keyup(fb, ctx, Key::P25 as u32),   // e
+            61 => api::keydown(fb, ctx, Key::P51 as u32), // ?
+            62 => api::keyup(fb, ctx, Key::P51 as u32),   // ?
+            63 => api::keydown(fb, ctx, Key::P55 as u32), //
+            64 => api::keyup(fb, ctx, Key::P55 as u32),   //
+            65 => api::keydown(fb, ctx, Key::P43 as u32), // shift, one-shot
+            66 => api::keyup(fb, ctx, Key::P43 as u32),   // shift
+            67 => api::keydown(fb, ctx, Key::P30 as u32), // I
+            68 => api::keyup(fb, ctx, Key::P30 as u32),   // I
+            69 => api::keydown(fb, ctx, Key::P36 as u32), // f
+            70 => api::keyup(fb, ctx, Key::P36 as u32),   // f
+            71 => api::keydown(fb, ctx, Key::P55 as u32), //
+            72 => api::keyup(fb, ctx, Key::P55 as u32),   //
+            73 => api::keydown(fb, ctx, Key::P33 as u32), // a
+            74 => api::keyup(fb, ctx, Key::P33 as u32),   // a
+            75 => api::keydown(fb, ctx, Key::P29 as u32), // u
+            76 => api::keyup(fb, ctx, Key::P29 as u32),   // u
+            77 => api::keydown(fb, ctx, Key::P37 as u32), // g
+            78 => api::keyup(fb, ctx, Key::P37 as u32),   // g
+            79 => api::keydown(fb, ctx, Key::P38 as u32), // h
+            80 => api::keyup(fb, ctx, Key::P38 as u32),   // h
+            81 => api::keydown(fb, ctx, Key::P27 as u32), // t
+            82 => api::keyup(fb, ctx, Key::P27 as u32),   // t
+            83 => api::keydown(fb, ctx, Key::P55 as u32), //
+            84 => api::keyup(fb, ctx, Key::P55 as u32),   //
+            85 => api::keydown(fb, ctx, Key::P31 as u32), // o
+            86 => api::keyup(fb, ctx, Key::P31 as u32),   // o
+            87 => api::keydown(fb, ctx, Key::P36 as u32), // f
+            88 => api::keyup(fb, ctx, Key::P36 as u32),   // f
+            89 => api::keydown(fb, ctx, Key::P55 as u32), //
+            90 => api::keyup(fb, ctx, Key::P55 as u32),   //
+            91 => api::keydown(fb, ctx, Key::P24 as u32), // w
+            92 => api::keyup(fb, ctx, Key::P24 as u32),   // w
+            93 => api::keydown(fb, ctx, Key::P31 as u32), // o
+            94 => api::keyup(fb, ctx, Key::P31 as u32),   // o
+            95 => api::keydown(fb, ctx, Key::P25 as u32), // e
+            96 => api::keyup(fb, ctx, Key::P25 as u32),   // e
+            97 => api::keydown(fb, ctx, Key::P55 as u32), //
+            98 => api::keyup(fb, ctx, Key::P55 as u32),   //
+            99 => api::keydown(fb, ctx, Key::P31 as u32), // o
+            100 => api::keyup(fb, ctx, Key::P31 as u32),  // o
+            101 => api::keydown(fb, ctx, Key::P26 as u32), // r
+            102 => api::keyup(fb, ctx, Key::P26 as u32),  // r
+            103 => api::keydown(fb, ctx, Key::P55 as u32), //
+            104 => api::keyup(fb, ctx, Key::P55 as u32),  //
+            105 => api::keydown(fb, ctx, Key::P24 as u32), // w
+            106 => api::keyup(fb, ctx, Key::P24 as u32),  // w
+            107 => api::keydown(fb, ctx, Key::P31 as u32), // o
+            108 => api::keyup(fb, ctx, Key::P31 as u32),  // o
+            109 => api::keydown(fb, ctx, Key::P49 as u32), // n
+            110 => api::keyup(fb, ctx, Key::P49 as u32),  // n
+            111 => api::keydown(fb, ctx, Key::P35 as u32), // d
+            112 => api::keyup(fb, ctx, Key::P35 as u32),  // d
+            113 => api::keydown(fb, ctx, Key::P25 as u32), // e
+            114 => api::keyup(fb, ctx, Key::P25 as u32),  // e
+            115 => api::keydown(fb, ctx, Key::P26 as u32), // r
+            116 => api::keyup(fb, ctx, Key::P26 as u32),  // r
+            117 => api::keydown(fb, ctx, Key::P54 as u32), // ,
+            118 => api::keyup(fb, ctx, Key::P54 as u32),  // ,
+            119 => api::keydown(fb, ctx, Key::P55 as u32), //
+            120 => api::keyup(fb, ctx, Key::P55 as u32),  //
+            121 => api::keydown(fb, ctx, Key::P46 as u32), // c
+            122 => api::keyup(fb, ctx, Key::P46 as u32),  // c
+            123 => api::keydown(fb, ctx, Key::P25 as u32), // e
+            124 => api::keyup(fb, ctx, Key::P25 as u32),  // e
+            125 => api::keydown(fb, ctx, Key::P33 as u32), // a
+            126 => api::keyup(fb, ctx, Key::P33 as u32),  // a
+            127 => api::keydown(fb, ctx, Key::P34 as u32), // s
+            128 => api::keyup(fb, ctx, Key::P34 as u32),  // s
+            129 => api::keydown(fb, ctx, Key::P25 as u32), // e
+            130 => api::keyup(fb, ctx, Key::P25 as u32),  // e
+            131 => api::keydown(fb, ctx, Key::P55 as u32), //
+            132 => api::keyup(fb, ctx, Key::P55 as u32),  //
+            133 => api::keydown(fb, ctx, Key::P28 as u32), // y
+            134 => api::keyup(fb, ctx, Key::P28 as u32),  // y
+            135 => api::keydown(fb, ctx, Key::P31 as u32), // o
+            136 => api::keyup(fb, ctx, Key::P31 as u32),  // o
+            137 => api::keydown(fb, ctx, Key::P29 as u32), // u
+            138 => api::keyup(fb, ctx, Key::P29 as u32),  // u
+            139 => api::keydown(fb, ctx, Key::P26 as u32), // r
+            140 => api::keyup(fb, ctx, Key::P26 as u32),  // r
+            141 => api::keydown(fb, ctx, Key::P55 as u32), //
+            142 => api::keyup(fb, ctx, Key::P55 as u32),  //
+            143 => api::keydown(fb, ctx, Key::P34 as u32), // s
+            144 => api::keyup(fb, ctx, Key::P34 as u32),  // s
+            145 => api::keydown(fb, ctx, Key::P25 as u32), // e
+            146 => api::keyup(fb, ctx, Key::P25 as u32),  // e
+            147 => api::keydown(fb, ctx, Key::P33 as u32), // a
+            148 => api::keyup(fb, ctx, Key::P33 as u32),  // a
+            149 => api::keydown(fb, ctx, Key::P26 as u32), // r
+            150 => api::keyup(fb, ctx, Key::P26 as u32),  // r
+            151 => api::keydown(fb, ctx, Key::P46 as u32), // c
+            152 => api::keyup(fb, ctx, Key::P46 as u32),  // c
+            153 => api::keydown(fb, ctx, Key::P38 as u32), // h
+            154 => api::keyup(fb, ctx, Key::P38 as u32),  // h
+            155 => api::keydown(fb, ctx, Key::P56 as u32), // .
+            156 => api::keyup(fb, ctx, Key::P56 as u32),  // .
+            157 => api::keydown(fb, ctx, Key::P55 as u32), //
+            158 => api::keyup(fb, ctx, Key::P55 as u32),  //
+            159 => api::keydown(fb, ctx, Key::P55 as u32), //
+            160 => api::keyup(fb, ctx, Key::P55 as u32),  //
             161 => api::keydown(fb, ctx, Key::P55 as u32), //
-            162 => api::keyup(fb, ctx, Key::P55 as u32), //
+            162 => api::keyup(fb, ctx, Key::P55 as u32),  //
             163 => api::keydown(fb, ctx, Key::P55 as u32), //
-            164 => api::keyup(fb, ctx, Key::P55 as u32), //
+            164 => api::keyup(fb, ctx, Key::P55 as u32),  //
             165 => api::keydown(fb, ctx, Key::P55 as u32), //
-            166 => api::keyup(fb, ctx, Key::P55 as u32), //
-            167 => api::keydown(fb, ctx, Key::P55 as u32), //
-            168 => api::keyup(fb, ctx, Key::P55 as u32), //
-            169 => api::keydown(fb, ctx, Key::P55 as u32), //
-            170 => api::keyup(fb, ctx, Key::P55 as u32), //
+            166 => api::keyup(fb, ctx, Key::P55 as u32),  //
             _ => (),
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::scancode::Key;
+    use super::{alternates, api, blit, editor, kbd, settings, state, views};
 
-    /// Lookup table to translate from keycode to key index
-    /// Comments refer to key caps of base qwerty layout
-    #[allow(dead_code)]
-    enum Key {
-        P2 = 0,   // Up
-        P5 = 1,   // Left
-        PC = 2,   // Click
-        P6 = 3,   // Right
-        P3 = 4,   // F1
-        P4 = 5,   // F2 (shift)
-        P9 = 6,   // Down
-        P7 = 7,   // F3
-        P8 = 8,   // F4
-        P13 = 9,  // 1
-        P14 = 10, // 2
-        P15 = 11, // 3
-        P16 = 12, // 4
-        P17 = 13, // 5
-        P18 = 14, // 6
-        P19 = 15, // 7
-        P20 = 16, // 8
-        P21 = 17, // 9
-        P22 = 18, // 0
-        P23 = 19, // q
-        P24 = 20, // w
-        P25 = 21, // e
-        P26 = 22, // r
-        P27 = 23, // t
-        P28 = 24, // y
-        P29 = 25, // u
-        P30 = 26, // i
-        P31 = 27, // o
-        P32 = 28, // p
-        P33 = 29, // a
-        P34 = 30, // s
-        P35 = 31, // d
-        P36 = 32, // f
-        P37 = 33, // g
-        P38 = 34, // h
-        P39 = 35, // j
-        P40 = 36, // k
-        P41 = 37, // l
-        P42 = 38, // backspace
-        P43 = 39, // !
-        P44 = 40, // z
-        P45 = 41, // x
-        P46 = 42, // c
-        P47 = 43, // v
-        P48 = 44, // b
-        P49 = 45, // n
-        P50 = 46, // m
-        P51 = 47, // ?
-        P52 = 48, // enter
-        P53 = 49, // leftShift
-        P54 = 50, // ,
-        P55 = 51, // space
-        P56 = 52, // .
-        P57 = 53, // rightShift
+    /// Press and release a key
+    fn press(fb: &mut state::FrameBuf, ctx: &mut state::Context, key: Key) {
+        let i = key as u32;
+        api::keydown(fb, ctx, i);
+        api::keyup(fb, ctx, i);
+    }
+
+    /// Type a string of chars from the base qwerty layout
+    fn type_str(fb: &mut state::FrameBuf, ctx: &mut state::Context, s: &str) {
+        for c in s.chars() {
+            let i = kbd::MAP_QWERTY_BASE
+                .iter()
+                .position(|r| matches!(r, kbd::R::C(k) if *k == c))
+                .unwrap() as u32;
+            api::keydown(fb, ctx, i);
+            api::keyup(fb, ctx, i);
+        }
+    }
+
+    /// Undo (AltL+F1) or redo (AltL+F3), leaving AltL unlatched
+    fn alt_press(fb: &mut state::FrameBuf, ctx: &mut state::Context, key: Key) {
+        press(fb, ctx, Key::P53);
+        press(fb, ctx, key);
+        press(fb, ctx, Key::P53);
+    }
+
+    fn setup() -> (state::FrameBuf, state::Context) {
+        let mut fb = state::FrameBuf::new();
+        let mut ctx = state::Context::new();
+        settings::set_layout(&mut ctx, kbd::Layout::Qwerty);
+        api::repaint(&mut fb, &mut ctx);
+        (fb, ctx)
+    }
+
+    #[test]
+    fn undo_redo_by_word() {
+        let (mut fb, mut ctx) = setup();
+        type_str(&mut fb, &mut ctx, "hello world");
+        alt_press(&mut fb, &mut ctx, Key::P3);
+        assert_eq!(ctx.text.as_str(), "hello ");
+        alt_press(&mut fb, &mut ctx, Key::P3);
+        assert_eq!(ctx.text.as_str(), "");
+        alt_press(&mut fb, &mut ctx, Key::P7);
+        assert_eq!(ctx.text.as_str(), "hello ");
+        alt_press(&mut fb, &mut ctx, Key::P7);
+        assert_eq!(ctx.text.as_str(), "hello world");
+        assert_eq!(ctx.text.cursor(), 11);
+    }
+
+    #[test]
+    fn undo_backspace_and_cursor_edits() {
+        let (mut fb, mut ctx) = setup();
+        type_str(&mut fb, &mut ctx, "cat dog");
+        press(&mut fb, &mut ctx, Key::P42); // backspace
+        press(&mut fb, &mut ctx, Key::P42);
+        assert_eq!(ctx.text.as_str(), "cat d");
+        // Moving the cursor starts a new edit group
+        press(&mut fb, &mut ctx, Key::P5); // left
+        type_str(&mut fb, &mut ctx, "x");
+        assert_eq!(ctx.text.as_str(), "cat xd");
+        alt_press(&mut fb, &mut ctx, Key::P3);
+        assert_eq!(ctx.text.as_str(), "cat d");
+        alt_press(&mut fb, &mut ctx, Key::P3);
+        assert_eq!(ctx.text.as_str(), "cat dog");
+        assert_eq!(ctx.text.cursor(), 7);
+    }
+
+    #[test]
+    fn new_edit_discards_redo() {
+        let (mut fb, mut ctx) = setup();
+        type_str(&mut fb, &mut ctx, "one two");
+        alt_press(&mut fb, &mut ctx, Key::P3);
+        type_str(&mut fb, &mut ctx, "six");
+        alt_press(&mut fb, &mut ctx, Key::P7);
+        assert_eq!(ctx.text.as_str(), "one six");
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let (mut fb, mut ctx) = setup();
+        for _ in 0..100 {
+            type_str(&mut fb, &mut ctx, "a ");
+        }
+        for _ in 0..100 {
+            alt_press(&mut fb, &mut ctx, Key::P3);
+        }
+        let words_left = 100 - super::undo::UNDO_DEPTH;
+        assert_eq!(ctx.text.as_str().len(), words_left * 2);
+    }
+
+    #[test]
+    fn symbol_picker_view_routing() {
+        let (mut fb, mut ctx) = setup();
+        // AltL+Symbol pushes the picker, which takes over Click and arrows
+        press(&mut fb, &mut ctx, Key::P53);
+        press(&mut fb, &mut ctx, Key::P54);
+        assert!(ctx.views.active() == state::View::SymbolPicker);
+        press(&mut fb, &mut ctx, Key::P53);
+        press(&mut fb, &mut ctx, Key::P6); // right
+        press(&mut fb, &mut ctx, Key::PC); // click
+        assert!(ctx.views.active() == state::View::Home);
+        assert_eq!(ctx.text.as_str(), "¿");
+    }
+
+    #[test]
+    fn soft_keys_open_menu_and_picker() {
+        let (mut fb, mut ctx) = setup();
+        type_str(&mut fb, &mut ctx, "hi");
+        // F1 opens the edit menu, and Down, Click picks "Redo" (no change)
+        press(&mut fb, &mut ctx, Key::P3);
+        assert!(ctx.views.active() == state::View::Menu);
+        press(&mut fb, &mut ctx, Key::P9); // down
+        press(&mut fb, &mut ctx, Key::PC); // click
+        assert!(ctx.views.active() == state::View::Home);
+        assert_eq!(ctx.text.as_str(), "hi");
+        // F3 opens the symbol picker, and F4 inserts from it
+        press(&mut fb, &mut ctx, Key::P7);
+        assert!(ctx.views.active() == state::View::SymbolPicker);
+        press(&mut fb, &mut ctx, Key::P8);
+        assert_eq!(ctx.text.as_str(), "hi¡");
+    }
+
+    #[test]
+    fn settings_change_layout_and_text_size() {
+        let (mut fb, mut ctx) = setup();
+        press(&mut fb, &mut ctx, Key::P8); // F4
+        assert!(ctx.views.active() == state::View::Settings);
+        assert_eq!(ctx.focus.len(), settings::FIELDS.len());
+        // Layout radio group has focus, with Qwerty selected
+        press(&mut fb, &mut ctx, Key::P2); // up
+        assert_eq!(api::kbd_map_index(&ctx), kbd::Map::Dvorak as i32);
+        press(&mut fb, &mut ctx, Key::P9); // down
+        press(&mut fb, &mut ctx, Key::P9);
+        assert_eq!(api::kbd_map_index(&ctx), kbd::Map::Qwertz as i32);
+        // Past the last option, Down moves focus to the text size group
+        press(&mut fb, &mut ctx, Key::P9);
+        press(&mut fb, &mut ctx, Key::PC); // click selects the next size
+        assert!(ctx.settings.text_size == settings::TextSize::Large);
+        press(&mut fb, &mut ctx, Key::P3); // F1
+        assert!(ctx.views.active() == state::View::Home);
+        type_str(&mut fb, &mut ctx, "big");
+        assert_eq!(ctx.text.as_str(), "big");
+    }
+
+    #[test]
+    fn font_costs_scroll_through_fonts() {
+        let (mut fb, mut ctx) = setup();
+        press(&mut fb, &mut ctx, Key::P8); // F4 opens settings
+        press(&mut fb, &mut ctx, Key::P8); // F4 opens fonts
+        assert!(ctx.views.active() == state::View::FontCosts);
+        // Down scrolls the list to keep the selected font visible
+        press(&mut fb, &mut ctx, Key::P9); // down
+        press(&mut fb, &mut ctx, Key::P9);
+        assert_eq!((ctx.font_list.selected, ctx.font_list.top), (2, 1));
+        press(&mut fb, &mut ctx, Key::P2); // up
+        assert_eq!((ctx.font_list.selected, ctx.font_list.top), (1, 1));
+        press(&mut fb, &mut ctx, Key::P3); // F1
+        assert!(ctx.views.active() == state::View::Settings);
+    }
+
+    #[test]
+    fn clear_note_after_confirmation() {
+        let (mut fb, mut ctx) = setup();
+        type_str(&mut fb, &mut ctx, "draft");
+        // Edit menu, Up wraps to the last item ("Clear note")
+        press(&mut fb, &mut ctx, Key::P3);
+        press(&mut fb, &mut ctx, Key::P2);
+        press(&mut fb, &mut ctx, Key::PC);
+        assert!(ctx.views.active() == state::View::Dialog);
+        // Typing does not reach the note while the dialog is open
+        type_str(&mut fb, &mut ctx, "x");
+        press(&mut fb, &mut ctx, Key::P6); // right, to the "clear" button
+        press(&mut fb, &mut ctx, Key::PC);
+        assert!(ctx.views.active() == state::View::Home);
+        assert_eq!(ctx.text.as_str(), "");
+        assert!(ctx.toast.is_active());
+        // Holding Enter on a dialog button closes the dialog, and the key
+        // does not go on to repeat into the note
+        type_str(&mut fb, &mut ctx, "draft");
+        press(&mut fb, &mut ctx, Key::P3);
+        press(&mut fb, &mut ctx, Key::P2);
+        press(&mut fb, &mut ctx, Key::PC);
+        api::keydown(&mut fb, &mut ctx, Key::P52 as u32); // enter
+        for _ in 0..10 {
+            api::tick(&mut fb, &mut ctx);
+        }
+        api::keyup(&mut fb, &mut ctx, Key::P52 as u32);
+        assert!(ctx.views.active() == state::View::Home);
+        assert_eq!(ctx.text.as_str(), "draft");
+    }
+
+    #[test]
+    fn status_setters_repaint_only_status_bar() {
+        let (mut fb, mut ctx) = setup();
+        ctx.settings.theme = settings::Theme::Dark;
+        api::repaint(&mut fb, &mut ctx);
+        let before = fb.buf;
+        // The title is copied, so its buffer can be reused afterwards
+        let mut name = *b"journal";
+        api::set_title(&mut fb, &mut ctx, core::str::from_utf8(&name).unwrap());
+        name[0] = b'J';
+        api::set_time(&mut fb, &mut ctx, "09:41");
+        api::set_battery(&mut fb, &mut ctx, state::Battery::from_percent(40), true);
+        api::set_radio(&mut fb, &mut ctx, state::Radio::from_bars(-1));
+        let status_words = views::STATUS_Y1 * blit::LCD_WORDS_PER_LINE;
+        assert!(fb.buf[..status_words] != before[..status_words]);
+        assert!(fb.buf[status_words..] == before[status_words..]);
+        // A partial repaint matches a full repaint
+        let partial = fb.buf;
+        api::repaint(&mut fb, &mut ctx);
+        assert!(fb.buf[..] == partial[..]);
+        assert_eq!(ctx.status_title.as_str(), "journal");
+        assert_eq!(name[0], b'J');
+    }
+
+    #[test]
+    fn azerty_dead_key_composes_accent() {
+        let (mut fb, mut ctx) = setup();
+        settings::set_layout(&mut ctx, kbd::Layout::Azerty);
+        press(&mut fb, &mut ctx, Key::P57); // AltR
+        press(&mut fb, &mut ctx, Key::P47); // dead ^
+        press(&mut fb, &mut ctx, Key::P57);
+        let before = fb.buf;
+        press(&mut fb, &mut ctx, Key::P25); // e
+        // The pending accent was showing in the status bar
+        let status_words = views::STATUS_Y1 * blit::LCD_WORDS_PER_LINE;
+        assert!(fb.buf[..status_words] != before[..status_words]);
+        press(&mut fb, &mut ctx, Key::P57);
+        press(&mut fb, &mut ctx, Key::P47);
+        press(&mut fb, &mut ctx, Key::P57);
+        press(&mut fb, &mut ctx, Key::P55); // space
+        assert_eq!(ctx.text.as_str(), "ê^");
+    }
+
+    #[test]
+    fn long_press_picks_alternate() {
+        let (mut fb, mut ctx) = setup();
+        type_str(&mut fb, &mut ctx, "caf");
+        // A quick press types the letter without opening the strip
+        api::keydown(&mut fb, &mut ctx, Key::P25 as u32); // e
+        for _ in 0..alternates::LONG_PRESS_TICKS - 1 {
+            api::tick(&mut fb, &mut ctx);
+        }
+        api::keyup(&mut fb, &mut ctx, Key::P25 as u32);
+        api::tick(&mut fb, &mut ctx);
+        assert!(ctx.views.active() == state::View::Home);
+        // Holding it opens the strip, and Right then Click picks è
+        api::keydown(&mut fb, &mut ctx, Key::P25 as u32);
+        for _ in 0..alternates::LONG_PRESS_TICKS {
+            api::tick(&mut fb, &mut ctx);
+        }
+        api::keyup(&mut fb, &mut ctx, Key::P25 as u32);
+        assert!(ctx.views.active() == state::View::Alternates);
+        press(&mut fb, &mut ctx, Key::P6); // right
+        press(&mut fb, &mut ctx, Key::PC); // click
+        assert!(ctx.views.active() == state::View::Home);
+        assert_eq!(ctx.text.as_str(), "cafeè");
+        // Typing another letter picks the selected alternate first
+        api::keydown(&mut fb, &mut ctx, Key::P33 as u32); // a
+        for _ in 0..alternates::LONG_PRESS_TICKS {
+            api::tick(&mut fb, &mut ctx);
+        }
+        api::keyup(&mut fb, &mut ctx, Key::P33 as u32);
+        type_str(&mut fb, &mut ctx, "s");
+        assert_eq!(ctx.text.as_str(), "cafeèàs");
+    }
+
+    #[test]
+    fn long_press_keeps_text_it_did_not_type() {
+        let (mut fb, mut ctx) = setup();
+        while ctx.text.insert('x') {}
+        // The buffer is full, so e types nothing, and picking é must not
+        // replace the x before the cursor
+        api::keydown(&mut fb, &mut ctx, Key::P25 as u32); // e
+        for _ in 0..alternates::LONG_PRESS_TICKS {
+            api::tick(&mut fb, &mut ctx);
+        }
+        api::keyup(&mut fb, &mut ctx, Key::P25 as u32);
+        assert!(ctx.views.active() == state::View::Alternates);
+        press(&mut fb, &mut ctx, Key::PC); // click
+        assert!(ctx.text.as_str().ends_with("xx"));
+        assert_eq!(ctx.text.as_str().len(), editor::TEXT_BUF_SIZE);
+    }
+
+    #[test]
+    fn shift_one_shot_held_and_caps_lock() {
+        let (mut fb, mut ctx) = setup();
+        press(&mut fb, &mut ctx, Key::P43); // shift
+        type_str(&mut fb, &mut ctx, "hi ");
+        // Held while other keys are pressed, shift lasts until it comes up
+        api::keydown(&mut fb, &mut ctx, Key::P43 as u32);
+        press(&mut fb, &mut ctx, Key::P38); // h
+        press(&mut fb, &mut ctx, Key::P30); // i
+        api::keyup(&mut fb, &mut ctx, Key::P43 as u32);
+        type_str(&mut fb, &mut ctx, " ");
+        // Double tap for caps lock, and tap again to turn it off
+        press(&mut fb, &mut ctx, Key::P43);
+        press(&mut fb, &mut ctx, Key::P43);
+        press(&mut fb, &mut ctx, Key::P31); // o
+        press(&mut fb, &mut ctx, Key::P40); // k
+        press(&mut fb, &mut ctx, Key::P43);
+        type_str(&mut fb, &mut ctx, "ay");
+        assert_eq!(ctx.text.as_str(), "Hi HI OKay");
+    }
+
+    #[test]
+    fn held_backspace_repeats_until_released() {
+        let (mut fb, mut ctx) = setup();
+        api::set_key_repeat(&mut ctx, 500, 100);
+        type_str(&mut fb, &mut ctx, "hello world");
+        api::keydown(&mut fb, &mut ctx, Key::P42 as u32); // backspace
+        for _ in 0..10 {
+            api::tick(&mut fb, &mut ctx);
+        }
+        api::keyup(&mut fb, &mut ctx, Key::P42 as u32);
+        api::tick(&mut fb, &mut ctx);
+        // 1 press, then a repeat at 500ms and every 100ms after that
+        assert_eq!(ctx.text.as_str(), "hell");
+        // The key was left drawn as released
+        let shown = fb.buf;
+        api::repaint(&mut fb, &mut ctx);
+        assert!(fb.buf[..] == shown[..]);
+    }
+
+    #[test]
+    fn hid_reports_type_with_the_active_layout() {
+        let (mut fb, mut ctx) = setup();
+        // Shift+H, I, then Q and W positions, which are A and Z on Azerty
+        let reports: [[u8; 8]; 4] = [
+            [0x02, 0, 0x0b, 0, 0, 0, 0, 0],
+            [0x00, 0, 0x0c, 0, 0, 0, 0, 0],
+            [0x00, 0, 0x14, 0x1a, 0, 0, 0, 0],
+            [0; 8],
+        ];
+        for report in reports.iter().take(2) {
+            api::hid_report(&mut fb, &mut ctx, report);
+        }
+        settings::set_layout(&mut ctx, kbd::Layout::Azerty);
+        for report in reports.iter().skip(2) {
+            api::hid_report(&mut fb, &mut ctx, report);
+        }
+        assert_eq!(ctx.text.as_str(), "Hiaz");
+    }
+
+    #[test]
+    fn hid_shift_and_f2_stay_apart_in_other_views() {
+        let (mut fb, mut ctx) = setup();
+        press(&mut fb, &mut ctx, Key::P8); // F4
+        assert!(ctx.views.active() == state::View::Settings);
+        // F2 is a soft key, which settings leaves unused, and not Shift
+        api::hid_report(&mut fb, &mut ctx, &[0x00, 0, 0x3b, 0, 0, 0, 0, 0]);
+        api::hid_report(&mut fb, &mut ctx, &[0; 8]);
+        assert_eq!(api::kbd_map_index(&ctx), kbd::Map::Qwerty as i32);
+        // Right Shift turns on the Shift layer and leaves settings open
+        api::hid_report(&mut fb, &mut ctx, &[0x20, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(api::kbd_map_index(&ctx), kbd::Map::QwertyShift as i32);
+        api::hid_report(&mut fb, &mut ctx, &[0; 8]);
+        assert!(ctx.views.active() == state::View::Settings);
+    }
+
+    /// Screen position in the middle of an on-screen key
+    fn key_center(col: u32, row: u32) -> (u32, u32) {
+        let y0 = views::KBD_Y0 as u32 + 2;
+        (1 + col * 33 + 16, y0 + row * 33 + 16)
+    }
+
+    #[test]
+    fn touch_presses_keys_and_activates_widgets() {
+        let (mut fb, mut ctx) = setup();
+        // h (column 5, row 3) and i (column 7, row 2)
+        for (col, row) in [(5, 3), (7, 2)].iter() {
+            let (x, y) = key_center(*col, *row);
+            api::touch_down(&mut fb, &mut ctx, x, y);
+            api::touch_up(&mut fb, &mut ctx, x, y);
+        }
+        assert_eq!(ctx.text.as_str(), "hi");
+        // Sliding off a key releases it, and the key is drawn as released
+        let (x, y) = key_center(8, 2);
+        api::touch_down(&mut fb, &mut ctx, x, y);
+        api::touch_move(&mut fb, &mut ctx, x, y - 33);
+        api::touch_up(&mut fb, &mut ctx, x, y - 33);
+        assert_eq!(ctx.text.as_str(), "hio");
+        // A cancelled touch on Backspace (column 9, row 3) stops repeating
+        let (x, y) = key_center(9, 3);
+        api::touch_down(&mut fb, &mut ctx, x, y);
+        api::touch_cancel(&mut fb, &mut ctx);
+        for _ in 0..20 {
+            api::tick(&mut fb, &mut ctx);
+        }
+        assert_eq!(ctx.text.as_str(), "hi");
+        let shown = fb.buf;
+        api::repaint(&mut fb, &mut ctx);
+        assert!(fb.buf[..] == shown[..]);
+        // F4 opens settings, where tapping the text size group selects the
+        // next size, and sliding off the theme group cancels
+        let (x, y) = key_center(8, 0);
+        api::touch_down(&mut fb, &mut ctx, x, y);
+        api::touch_up(&mut fb, &mut ctx, x, y);
+        assert!(ctx.views.active() == state::View::Settings);
+        let size = ctx.focus.region(1).unwrap();
+        let (x, y) = (size.x0 as u32 + 4, size.y0 as u32 + 4);
+        api::touch_down(&mut fb, &mut ctx, x, y);
+        api::touch_up(&mut fb, &mut ctx, x, y);
+        assert!(ctx.settings.text_size == settings::TextSize::Large);
+        let theme = ctx.focus.region(2).unwrap();
+        let (x, y) = (theme.x0 as u32 + 4, theme.y0 as u32 + 4);
+        api::touch_down(&mut fb, &mut ctx, x, y);
+        api::touch_move(&mut fb, &mut ctx, x, views::KBD_Y0 as u32 - 1);
+        api::touch_up(&mut fb, &mut ctx, x, views::KBD_Y0 as u32 - 1);
+        assert!(ctx.settings.theme == settings::Theme::Light);
     }
 }
@@ -0,0 +1,225 @@
+//! Modifier key engine: one-shot, toggled, held, and locked modifiers
+//!
+//! The layout's `press` rules pick the layer after each modifier key press
+//! (see kbd::modkey_down). This module decides when to press a modifier key
+//! again to release it:
+//!
+//! - A one-shot modifier (`latch Shift OneShot`) releases after the next
+//!   key. Tapping it twice within DOUBLE_TAP_TICKS locks it (caps lock for
+//!   Shift) until it is tapped again.
+//! - A toggled modifier (`latch AltL Toggle`) stays on until it is tapped
+//!   again.
+//! - A modifier that is held down while other keys are pressed releases
+//!   when it comes up, whatever its latch policy.
+
+use super::kbd::{self, ModKey, R};
+use super::state;
+
+/// Ticks within which a second tap of a one-shot modifier locks it (see
+/// api::tick for the tick rate)
+pub const DOUBLE_TAP_TICKS: usize = 4;
+
+/// Modifier keys, indexed like Modifiers::latched
+const KEYS: [R; 3] = [R::AltL, R::AltR, R::Shift];
+
+/// State of a modifier key
+#[derive(Copy, Clone, PartialEq)]
+pub enum Latched {
+    Off,
+    /// On until tapped again
+    Toggled,
+    /// On for the next key only
+    OneShot,
+    /// On until tapped again, from a double tap of a one-shot modifier
+    Locked,
+}
+
+/// Modifier key states, the modifier key that is down, and the last tap
+pub struct Modifiers {
+    pub latched: [Latched; 3],
+    /// Key index and modifier number of the modifier key that is down, and
+    /// whether other keys were pressed while it was down
+    pub down: Option<(usize, usize)>,
+    pub chorded: bool,
+    /// Modifier number of the last key tapped if it was a modifier, and
+    /// ticks since that tap
+    pub last_tap: Option<usize>,
+    pub tap_ticks: usize,
+}
+impl Modifiers {
+    /// Initialize with all modifiers off
+    pub const fn new() -> Modifiers {
+        Modifiers {
+            latched: [Latched::Off; 3],
+            down: None,
+            chorded: false,
+            last_tap: None,
+            tap_ticks: 0,
+        }
+    }
+}
+
+/// Get the modifier number of a modifier key result
+fn modifier(r: &R) -> Option<usize> {
+    KEYS.iter()
+        .position(|k| core::mem::discriminant(k) == core::mem::discriminant(r))
+}
+
+/// Check if a modifier is on in a modifier key state
+fn is_on(mk: ModKey, m: usize) -> bool {
+    match mk {
+        ModKey::Base => false,
+        ModKey::AltL => m == 0,
+        ModKey::AltR => m == 1,
+        ModKey::Shift => m == 2,
+        ModKey::AltRS => m == 1 || m == 2,
+    }
+}
+
+/// Turn off the latched state of modifiers that the layout rules turned off
+fn sync(ctx: &mut state::Context) {
+    for m in 0..KEYS.len() {
+        if !is_on(ctx.kbd_modkey, m) {
+            ctx.modifiers.latched[m] = Latched::Off;
+        }
+    }
+}
+
+/// Release a modifier by pressing it again
+fn release(ctx: &mut state::Context, m: usize) {
+    if is_on(ctx.kbd_modkey, m) {
+        kbd::modkey_down(ctx, &KEYS[m]);
+    }
+    ctx.modifiers.latched[m] = Latched::Off;
+    sync(ctx);
+}
+
+/// Press a modifier key, following the layout's press rules and latch
+/// policy for it
+fn press(ctx: &mut state::Context, key_index: usize, m: usize) {
+    ctx.modifiers.down = Some((key_index, m));
+    ctx.modifiers.chorded = false;
+    let double_tap = ctx.modifiers.last_tap == Some(m)
+        && ctx.modifiers.tap_ticks < DOUBLE_TAP_TICKS
+        && ctx.modifiers.latched[m] == Latched::OneShot;
+    ctx.modifiers.last_tap = Some(m);
+    ctx.modifiers.tap_ticks = 0;
+    if double_tap {
+        ctx.modifiers.latched[m] = Latched::Locked;
+        return;
+    }
+    let was_on = is_on(ctx.kbd_modkey, m);
+    kbd::modkey_down(ctx, &KEYS[m]);
+    if !was_on && is_on(ctx.kbd_modkey, m) {
+        ctx.modifiers.latched[m] = match kbd::latch(ctx, &KEYS[m]) {
+            kbd::Latch::Toggle => Latched::Toggled,
+            kbd::Latch::OneShot => Latched::OneShot,
+        };
+    }
+    sync(ctx);
+}
+
+/// Handle a key down event. Modifier keys change the modifier state. Other
+/// keys release one-shot modifiers (their layer has already been used to
+/// look up the key's result) and chord with a modifier key that is down.
+/// Return: true if the key was a modifier key (needs repaint)
+pub fn key_down(ctx: &mut state::Context, key_index: usize, r: &R) -> bool {
+    if let Some(m) = modifier(r) {
+        press(ctx, key_index, m);
+        return true;
+    }
+    if let R::Nop = r {
+        return false;
+    }
+    ctx.modifiers.last_tap = None;
+    let held = ctx.modifiers.down.map(|(_, m)| m);
+    if held.is_some() {
+        ctx.modifiers.chorded = true;
+    }
+    for m in 0..KEYS.len() {
+        if ctx.modifiers.latched[m] == Latched::OneShot && held != Some(m) {
+            release(ctx, m);
+        }
+    }
+    false
+}
+
+/// Handle a key up event, releasing a modifier key that was held down while
+/// other keys were pressed.
+/// Return: true if a modifier was released (needs repaint)
+pub fn key_up(ctx: &mut state::Context, key_index: usize) -> bool {
+    let m = match ctx.modifiers.down {
+        Some((k, m)) if k == key_index => m,
+        _ => return false,
+    };
+    ctx.modifiers.down = None;
+    if !ctx.modifiers.chorded || ctx.modifiers.latched[m] == Latched::Off {
+        return false;
+    }
+    ctx.modifiers.last_tap = None;
+    release(ctx, m);
+    true
+}
+
+/// Count a tick toward the double tap timeout
+pub fn tick(ctx: &mut state::Context) {
+    ctx.modifiers.tap_ticks = ctx.modifiers.tap_ticks.saturating_add(1);
+}
+
+/// Clear all modifiers (for a change of layout)
+pub fn reset(ctx: &mut state::Context) {
+    ctx.modifiers = Modifiers::new();
+    kbd::set_modkey(ctx, ModKey::Base);
+}
+
+/// Label for the Shift keycap, showing whether Shift is on or locked (short,
+/// since Shift is one key wide)
+pub fn shift_label(ctx: &state::Context) -> &'static str {
+    match ctx.modifiers.latched[2] {
+        Latched::Off => "sh",
+        Latched::Toggled | Latched::OneShot => "Sh",
+        Latched::Locked => "SH",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_shot_held_and_locked_shift() {
+        let mut ctx = state::Context::new();
+        kbd::set_layout(&mut ctx, kbd::Layout::Qwerty);
+        // One-shot: Shift is released by the next key
+        key_down(&mut ctx, 39, &R::Shift);
+        key_up(&mut ctx, 39);
+        assert_eq!(kbd::cur_map_index(&ctx), kbd::Map::QwertyShift as i32);
+        key_down(&mut ctx, 20, &R::C('Q'));
+        assert_eq!(kbd::cur_map_index(&ctx), kbd::Map::Qwerty as i32);
+        // Held: Shift stays on for every key until it comes up
+        key_down(&mut ctx, 39, &R::Shift);
+        key_down(&mut ctx, 20, &R::C('Q'));
+        key_down(&mut ctx, 21, &R::C('W'));
+        assert_eq!(kbd::cur_map_index(&ctx), kbd::Map::QwertyShift as i32);
+        assert!(key_up(&mut ctx, 39));
+        assert_eq!(kbd::cur_map_index(&ctx), kbd::Map::Qwerty as i32);
+        // Double tap locks until the next tap, and a slow second tap does not
+        for _ in 0..2 {
+            key_down(&mut ctx, 39, &R::Shift);
+            key_up(&mut ctx, 39);
+        }
+        assert_eq!(shift_label(&ctx), "SH");
+        key_down(&mut ctx, 20, &R::C('Q'));
+        assert_eq!(kbd::cur_map_index(&ctx), kbd::Map::QwertyShift as i32);
+        key_down(&mut ctx, 39, &R::Shift);
+        assert_eq!(kbd::cur_map_index(&ctx), kbd::Map::Qwerty as i32);
+        key_down(&mut ctx, 39, &R::Shift);
+        (0..DOUBLE_TAP_TICKS).for_each(|_| tick(&mut ctx));
+        key_down(&mut ctx, 39, &R::Shift);
+        assert_eq!(shift_label(&ctx), "sh");
+        // Toggled Alt stays on across keys
+        key_down(&mut ctx, 49, &R::AltL);
+        key_down(&mut ctx, 20, &R::C('1'));
+        assert_eq!(kbd::cur_map_index(&ctx), kbd::Map::QwertyAlt as i32);
+    }
+}
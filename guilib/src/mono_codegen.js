@@ -0,0 +1,279 @@
+"use strict";
+// This generates src/fonts/mono.rs, a monospace bitmap font derived from the
+// glyphs of the small font plus generated box drawing characters.
+//
+// Usage (from the guilib directory):
+//   node src/mono_codegen.js > src/fonts/mono.rs
+// Output uses the per-block format of src/font_blocks.js, with optional
+// blocks selected by the mono-* cargo features.
+//
+// Glyphs from src/fonts/small.rs are already pixel doubled, so they get
+// centered (on the 2px grid) into a fixed 12x24 cell. Glyphs too wide for the
+// cell are replaced by the hand drawn 1x overrides below, which get pixel
+// doubled on output. Box drawing glyphs span the full cell so that lines join
+// up between adjacent cells.
+const fs = require('fs');
+const path = require('path');
+const fontBlocks = require('./font_blocks.js');
+
+const CELL_W = 12;
+const CELL_H = 24;
+
+// Load glyph matrices from the glyph pattern records of a generated font file
+function loadFont(file) {
+    let glyphs = {};
+    for (let r of fontBlocks.parse(fs.readFileSync(file, 'utf8')).records) {
+        let header = r.words[0];
+        let w = (header >>> 16) & 0xff;
+        let h = (header >>> 8) & 0xff;
+        let yOffset = header & 0xff;
+        let rows = [];
+        for (let y = 0; y < h; y++) {
+            let row = [];
+            for (let x = 0; x < w; x++) {
+                // Pixels of each row are packed right to left (see www_codegen)
+                let i = y * w + (w - 1 - x);
+                row.push((r.words[1 + (i >>> 5)] >>> (31 - (i & 31))) & 1);
+            }
+            rows.push(row);
+        }
+        glyphs[r.code] = {w, h, yOffset, rows};
+    }
+    return glyphs;
+}
+
+// Hand drawn replacements (1x pixels) for glyphs wider than the cell.
+// Format: [yOffset at 1x, rows...]
+const OVERRIDES = {
+    0x25: [3, '##..#', '##..#', '...#.', '..#..', '.#...', '#..##', '#..##'],  // '%'
+    0x26: [2, '.##..', '#..#.', '#.#..', '.#...', '#.#.#', '#..#.', '#..#.', '.##.#'], // '&'
+    0x40: [3, '.###.', '#...#', '#.###', '#.#.#', '#.##.', '#....', '.###.'], // '@'
+    0x4D: [3, '#...#', '##.##', '#.#.#', '#.#.#', '#...#', '#...#', '#...#'], // 'M'
+    0x57: [3, '#...#', '#...#', '#...#', '#.#.#', '#.#.#', '##.##', '#...#'], // 'W'
+    0x6D: [5, '##.#.', '#.#.#', '#.#.#', '#.#.#', '#.#.#'], // 'm'
+    0x77: [5, '#...#', '#...#', '#.#.#', '#.#.#', '.#.#.'], // 'w'
+    0xA9: [3, '.###.', '#...#', '#.###', '#.#.#', '#.###', '#...#', '.###.'], // '©'
+    0xAB: [5, '..#.#', '.#.#.', '#.#..', '.#.#.', '..#.#'], // '«'
+    0xAE: [3, '.###.', '#...#', '###.#', '##.##', '###.#', '##.##', '.###.'], // '®'
+    0xB5: [5, '#..#.', '#..#.', '#..#.', '#..#.', '###.#', '#....', '#....'], // 'µ'
+    0xB6: [3, '.####', '###.#', '###.#', '.##.#', '..#.#', '..#.#', '..#.#', '..#.#', '..#.#'], // '¶'
+    0xBB: [5, '#.#..', '.#.#.', '..#.#', '.#.#.', '#.#..'], // '»'
+    0xBC: [1, '#....', '#...#', '#..#.', '..#..', '.#...', '#.#.#', '..#.#', '..###', '....#'], // '¼'
+    0xBD: [1, '#....', '#...#', '#..#.', '..#..', '.#...', '#.##.', '....#', '...#.', '..###'], // '½'
+    0xBE: [1, '##...', '.#..#', '##.#.', '.##..', '##...', '#.#.#', '..#.#', '..###', '....#'], // '¾'
+    0xC6: [3, '.####', '#.#..', '#.#..', '#####', '#.#..', '#.#..', '#.###'], // 'Æ'
+    0xD0: [3, '.###.', '.#..#', '.#..#', '###.#', '.#..#', '.#..#', '.###.'], // 'Ð'
+    0xD8: [3, '.####', '#..##', '#.#.#', '#.#.#', '##..#', '#...#', '####.'], // 'Ø'
+    0xE6: [5, '##.#.', '..#.#', '#####', '#.#..', '.#.##'], // 'æ'
+    0xF8: [4, '.####', '#..##', '#.#.#', '##..#', '.###.', '#....'], // 'ø'
+    0xFFFD: [1, '..#..', '.###.', '#...#', '###.#', '##.##', '#####', '.#.#.', '..#..'], // '�'
+};
+
+// Box drawing segments for U+2500..U+257F.
+// Line glyphs: up, right, down, left weights (0=none, 1=light, 2=heavy, 3=double)
+// Dashed lines: '-' (horizontal) or '|' (vertical), dash count, weight
+// Arcs: 'a' then segments like a line glyph; diagonals: '/', '\', 'x'
+const BOX = [
+    '0101', '0202', '1010', '2020', '-31', '-32', '|31', '|32',
+    '-41', '-42', '|41', '|42', '0110', '0210', '0120', '0220',
+    '0011', '0012', '0021', '0022', '1100', '1200', '2100', '2200',
+    '1001', '1002', '2001', '2002', '1110', '1210', '2110', '1120',
+    '2120', '2210', '1220', '2220', '1011', '1012', '2011', '1021',
+    '2021', '2012', '1022', '2022', '0111', '0112', '0211', '0212',
+    '0121', '0122', '0221', '0222', '1101', '1102', '1201', '1202',
+    '2101', '2102', '2201', '2202', '1111', '1112', '1211', '1212',
+    '2111', '1121', '2121', '2112', '2211', '1122', '1221', '2212',
+    '1222', '2122', '2221', '2222', '-21', '-22', '|21', '|22',
+    '0303', '3030', '0310', '0130', '0330', '0013', '0031', '0033',
+    '1300', '3100', '3300', '1003', '3001', '3003', '1310', '3130',
+    '3330', '1013', '3031', '3033', '0313', '0131', '0333', '1303',
+    '3101', '3303', '1313', '3131', '3333', 'a0110', 'a0011', 'a1001',
+    'a1100', '/', '\\', 'x', '0001', '1000', '0100', '0010',
+    '0002', '2000', '0200', '0020', '0201', '1020', '0102', '2010',
+];
+
+// Pixel bands (start, end) for each line weight: columns of vertical lines
+// and rows of horizontal lines
+const V_BANDS = [[], [[4, 6]], [[3, 7]], [[2, 4], [6, 8]]];
+const H_BANDS = [[], [[12, 14]], [[11, 15]], [[10, 12], [14, 16]]];
+
+function blankCell() {
+    let rows = [];
+    for (let y = 0; y < CELL_H; y++) {
+        rows.push(new Array(CELL_W).fill(0));
+    }
+    return rows;
+}
+
+function fill(m, x0, x1, y0, y1) {
+    for (let y = y0; y < y1; y++) {
+        for (let x = x0; x < x1; x++) {
+            m[y][x] = 1;
+        }
+    }
+}
+
+// Draw a box drawing glyph into a full cell matrix
+function boxGlyph(spec) {
+    let m = blankCell();
+    if (spec === '/' || spec === '\\' || spec === 'x') {
+        for (let y = 0; y < CELL_H; y++) {
+            let x = Math.floor(y * CELL_W / CELL_H);
+            if (spec !== '\\') {
+                fill(m, Math.max(0, CELL_W - 2 - x), CELL_W - x, y, y + 1);
+            }
+            if (spec !== '/') {
+                fill(m, x, Math.min(CELL_W, x + 2), y, y + 1);
+            }
+        }
+        return m;
+    }
+    if (spec[0] === '-' || spec[0] === '|') {
+        let n = parseInt(spec[1]);
+        let weight = parseInt(spec[2]);
+        let len = spec[0] === '-' ? CELL_W : CELL_H;
+        let step = len / n;
+        for (let i = 0; i < n; i++) {
+            let a = Math.round(i * step);
+            let b = Math.round((i + 1) * step) - (spec[0] === '-' ? 1 : 2);
+            if (spec[0] === '-') {
+                for (let [y0, y1] of H_BANDS[weight]) { fill(m, a, b, y0, y1); }
+            } else {
+                for (let [x0, x1] of V_BANDS[weight]) { fill(m, x0, x1, a, b); }
+            }
+        }
+        return m;
+    }
+    let arc = spec[0] === 'a';
+    let [u, r, d, l] = (arc ? spec.slice(1) : spec).split('').map(n => parseInt(n));
+    // Vertical segments extend across the bands of any horizontal segments
+    // (and vice versa) so that corners and junctions join up
+    let hb = H_BANDS[l].concat(H_BANDS[r]);
+    let vb = V_BANDS[u].concat(V_BANDS[d]);
+    let hTop = Math.min(...hb.map(b => b[0]));
+    let hBottom = Math.max(...hb.map(b => b[1]));
+    let vLeft = Math.min(...vb.map(b => b[0]));
+    let vRight = Math.max(...vb.map(b => b[1]));
+    const ownEnd = (bands, i) => bands.length > 0 ? bands[bands.length - 1][i] : 0;
+    for (let [x0, x1] of V_BANDS[u]) {
+        fill(m, x0, x1, 0, hb.length > 0 ? hBottom : ownEnd(H_BANDS[u], 1));
+    }
+    for (let [x0, x1] of V_BANDS[d]) {
+        fill(m, x0, x1, hb.length > 0 ? hTop : H_BANDS[d][0][0], CELL_H);
+    }
+    for (let [y0, y1] of H_BANDS[l]) {
+        fill(m, 0, vb.length > 0 ? vRight : ownEnd(V_BANDS[l], 1), y0, y1);
+    }
+    for (let [y0, y1] of H_BANDS[r]) {
+        fill(m, vb.length > 0 ? vLeft : V_BANDS[r][0][0], CELL_W, y0, y1);
+    }
+    if (arc) {
+        // Knock out the outside pixel of the corner to round it off
+        let x = r > 0 ? V_BANDS[1][0][0] : V_BANDS[1][0][1] - 1;
+        let y = d > 0 ? H_BANDS[1][0][0] : H_BANDS[1][0][1] - 1;
+        m[y][x] = 0;
+    }
+    return m;
+}
+
+// Place a pixel doubled glyph matrix into a full cell matrix, centered
+// horizontally on the 2px grid
+function centerGlyph(rows, w, yOffset) {
+    let m = blankCell();
+    let x0 = 2 * Math.floor((CELL_W / 2 - w / 2) / 2);
+    if (w >= CELL_W) {
+        x0 = 0;
+    }
+    for (let y = 0; y < rows.length && y + yOffset < CELL_H; y++) {
+        for (let x = 0; x < w && x0 + x < CELL_W; x++) {
+            m[y + yOffset][x0 + x] = rows[y][x];
+        }
+    }
+    return m;
+}
+
+// Pixel double a 1x override glyph
+function overrideGlyph(spec) {
+    let [yOffset, ...art] = spec;
+    let rows = [];
+    for (let line of art) {
+        let row = [];
+        for (let ch of line) {
+            row.push(ch === '#' ? 1 : 0, ch === '#' ? 1 : 0);
+        }
+        rows.push(row, row.slice());
+    }
+    return centerGlyph(rows, art[0].length * 2, yOffset * 2);
+}
+
+// Trim blank rows (top and bottom) and blank columns (right only, because
+// glyph headers have no x offset) then pack in the same format as main.js
+function convertCellToPattern(m) {
+    let top = 0;
+    while (top < CELL_H && m[top].every(px => px === 0)) { top++; }
+    if (top === CELL_H) {
+        // Blank glyph: keep a minimal pattern that advances a full cell
+        return [(CELL_W << 16) | (0 << 8) | 0, 0];
+    }
+    let bottom = CELL_H;
+    while (m[bottom - 1].every(px => px === 0)) { bottom--; }
+    let right = CELL_W;
+    while (m.every(row => row[right - 1] === 0)) { right--; }
+    let rows = m.slice(top, bottom).map(row => row.slice(0, right));
+    let patW = right;
+    let patH = rows.length;
+    let pattern = [(patW << 16) | (patH << 8) | top];
+    let bufWord = 0;
+    let flushed = false;
+    for (let y = 0; y < patH; y++) {
+        for (let x = 0; x < patW; x++) {
+            bufWord = (bufWord << 1) | rows[y][patW - 1 - x];
+            flushed = false;
+            if ((y * patW + x) % 32 == 31) {
+                pattern.push(bufWord);
+                bufWord = 0;
+                flushed = true;
+            }
+        }
+    }
+    if (!flushed) {
+        pattern.push(bufWord << (32 - ((patW * patH) % 32)));
+    }
+    return pattern;
+}
+
+function describe(code) {
+    let chr = String.fromCodePoint(code);
+    if (chr === "\\") { return "'\\\\'"; }
+    if (chr === "'") { return "'\\''"; }
+    return `'${chr}'`;
+}
+
+function main() {
+    let small = loadFont(path.join(__dirname, 'fonts', 'small.rs'));
+    let ranges = [[0x20, 0x7E], [0xA0, 0xFF], [0x2500, 0x257F], [0xFFFD, 0xFFFD]];
+    let records = [];
+    for (let [first, last] of ranges) {
+        for (let code = first; code <= last; code++) {
+            let m;
+            if (0x2500 <= code && code <= 0x257F) {
+                m = boxGlyph(BOX[code - 0x2500]);
+            } else if (OVERRIDES[code]) {
+                m = overrideGlyph(OVERRIDES[code]);
+            } else {
+                let g = small[code];
+                m = centerGlyph(g.rows, g.w, g.yOffset);
+            }
+            records.push({code, desc: describe(code), words: convertCellToPattern(m)});
+        }
+    }
+    let extra = `/// Width of the fixed character cell. Every glyph advances by exactly this
+/// many pixels, and glyph patterns include their own left side bearing.
+pub const CELL_W: u8 = ${CELL_W};
+
+/// Height of the fixed character cell (line pitch for grids of text)
+pub const CELL_H: u8 = ${CELL_H};`;
+    process.stdout.write(fontBlocks.emit({name: 'Mono', maxHeight: CELL_H, extra, records}));
+}
+
+main();
@@ -0,0 +1,200 @@
+//! View stack, and routing of repaints and keystrokes to the active view
+
+use super::kbd;
+use super::softkeys::{self, SoftKeys};
+use super::state::{self, FrameBuf, View};
+use super::{alternates, dialog, fontcosts, settings, symbols, textarea, views};
+
+/// Maximum number of views on the stack
+pub const VIEW_STACK_DEPTH: usize = 8;
+
+/// Stack of views. The bottom view is never popped, and the top view is
+/// the active one that gets painted and receives keystrokes. Each view
+/// remembers which of its widgets has focus.
+pub struct ViewStack {
+    views: [View; VIEW_STACK_DEPTH],
+    focus: [usize; VIEW_STACK_DEPTH],
+    depth: usize,
+}
+impl ViewStack {
+    /// Initialize a view stack holding only the home screen
+    pub const fn new() -> ViewStack {
+        ViewStack {
+            views: [View::Home; VIEW_STACK_DEPTH],
+            focus: [0; VIEW_STACK_DEPTH],
+            depth: 1,
+        }
+    }
+
+    /// Get the active view
+    pub fn active(&self) -> View {
+        self.views[self.depth - 1]
+    }
+
+    /// Make a view active on top of the current one
+    /// Return: false if the stack is full
+    pub fn push(&mut self, v: View) -> bool {
+        if self.depth == VIEW_STACK_DEPTH {
+            return false;
+        }
+        self.views[self.depth] = v;
+        self.focus[self.depth] = 0;
+        self.depth += 1;
+        true
+    }
+
+    /// Close the active view and return to the one below it
+    /// Return: false if the active view is the bottom of the stack
+    pub fn pop(&mut self) -> bool {
+        if self.depth == 1 {
+            return false;
+        }
+        self.depth -= 1;
+        true
+    }
+
+    /// Swap the active view for a different one
+    pub fn replace(&mut self, v: View) {
+        self.views[self.depth - 1] = v;
+        self.focus[self.depth - 1] = 0;
+    }
+
+    /// Get the view below the active view (the active view if there is none)
+    pub fn below(&self) -> View {
+        self.views[self.depth.saturating_sub(2)]
+    }
+
+    /// Return true if a view is anywhere on the stack
+    pub fn contains(&self, v: View) -> bool {
+        self.views[0..self.depth].contains(&v)
+    }
+
+    /// Get the index of the focused widget in the active view
+    pub fn focus(&self) -> usize {
+        self.focus[self.depth - 1]
+    }
+
+    /// Move focus to a different widget in the active view
+    pub fn set_focus(&mut self, i: usize) {
+        self.focus[self.depth - 1] = i;
+    }
+}
+
+/// Functions that implement the behavior of a view
+pub struct ViewHandler {
+    /// Title for the status bar
+    pub title: TitleFnPtr,
+    /// Paint the whole screen
+    pub paint: PaintFnPtr,
+    /// Handle a keystroke, returning true if it was used (needs repaint)
+    pub handle_key: HandleKeyFnPtr,
+    /// Activate the focused widget on Click, returning true if it was used
+    pub activate: ActivateFnPtr,
+    /// Labels and actions for the F1-F4 soft keys
+    pub soft_keys: SoftKeysFnPtr,
+}
+pub type TitleFnPtr = fn(&state::Context) -> &str;
+pub type PaintFnPtr = fn(&mut FrameBuf, &mut state::Context);
+pub type HandleKeyFnPtr = fn(&mut state::Context, &kbd::R) -> bool;
+pub type ActivateFnPtr = fn(&mut state::Context, usize) -> bool;
+pub type SoftKeysFnPtr = fn(&state::Context) -> &'static SoftKeys;
+
+/// Look up the handler functions for a view
+pub fn handler(v: View) -> ViewHandler {
+    match v {
+        View::Home => ViewHandler {
+            title: home_title,
+            paint: views::home_screen,
+            handle_key: textarea::handle_key,
+            activate: activate_none,
+            soft_keys: softkeys::home_soft_keys,
+        },
+        View::SymbolPicker => ViewHandler {
+            title: symbol_picker_title,
+            paint: views::symbol_picker,
+            handle_key: symbols::handle_key,
+            activate: activate_none,
+            soft_keys: softkeys::symbol_picker_soft_keys,
+        },
+        View::Menu => ViewHandler {
+            title: softkeys::menu_title,
+            paint: views::menu,
+            handle_key: softkeys::menu_handle_key,
+            activate: activate_none,
+            soft_keys: softkeys::menu_soft_keys,
+        },
+        View::Settings => ViewHandler {
+            title: settings_title,
+            paint: views::settings,
+            handle_key: settings::handle_key,
+            activate: settings::activate,
+            soft_keys: softkeys::settings_soft_keys,
+        },
+        View::Dialog => ViewHandler {
+            title: dialog::title,
+            paint: views::dialog,
+            handle_key: dialog::handle_key,
+            activate: dialog::activate,
+            soft_keys: dialog::soft_keys,
+        },
+        View::Alternates => ViewHandler {
+            title: alternates::title,
+            paint: views::alternates,
+            handle_key: alternates::handle_key,
+            activate: activate_none,
+            soft_keys: alternates::soft_keys,
+        },
+        View::FontCosts => ViewHandler {
+            title: font_costs_title,
+            paint: views::font_costs,
+            handle_key: fontcosts::handle_key,
+            activate: activate_none,
+            soft_keys: softkeys::font_costs_soft_keys,
+        },
+    }
+}
+
+fn home_title(ctx: &state::Context) -> &str {
+    ctx.status_title.as_str()
+}
+
+fn symbol_picker_title(_ctx: &state::Context) -> &str {
+    "symbols"
+}
+
+fn settings_title(_ctx: &state::Context) -> &str {
+    "settings"
+}
+
+fn font_costs_title(_ctx: &state::Context) -> &str {
+    "fonts"
+}
+
+/// Activate handler for views without focusable widgets
+fn activate_none(_ctx: &mut state::Context, _i: usize) -> bool {
+    false
+}
+
+/// Get the handler functions for the active view
+pub fn active(ctx: &state::Context) -> ViewHandler {
+    handler(ctx.views.active())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_pop_replace() {
+        let mut vs = ViewStack::new();
+        assert!(!vs.pop());
+        assert!(vs.push(View::SymbolPicker));
+        assert!(vs.active() == View::SymbolPicker);
+        vs.replace(View::Home);
+        assert!(vs.active() == View::Home);
+        assert!(vs.pop());
+        while vs.push(View::SymbolPicker) {}
+        assert!(vs.active() == View::SymbolPicker);
+        assert!(vs.contains(View::Home));
+    }
+}
@@ -0,0 +1,121 @@
+//! Key auto-repeat: a held key repeats after an initial delay, at a steady
+//! interval, for as long as it stays down
+
+use super::alternates;
+use super::kbd::{MapResultLUT, R};
+use super::state::{self, View};
+
+/// Default milliseconds before a held key starts repeating, and between
+/// repeats
+pub const DEFAULT_DELAY_MS: u32 = 500;
+pub const DEFAULT_INTERVAL_MS: u32 = 100;
+
+/// Repeat timing, and the key that is held
+pub struct KeyRepeat {
+    /// Milliseconds before the first repeat (0 turns repeat off), and
+    /// between repeats
+    pub delay_ms: u32,
+    pub interval_ms: u32,
+    /// Key index of the held key, and the map it was pressed in (so repeats
+    /// type the same thing after a one-shot modifier is released)
+    pub held: Option<(usize, &'static MapResultLUT)>,
+    /// Milliseconds until the next repeat
+    pub wait_ms: u32,
+}
+impl KeyRepeat {
+    /// Initialize with the default timing and no key held
+    pub const fn new() -> KeyRepeat {
+        KeyRepeat {
+            delay_ms: DEFAULT_DELAY_MS,
+            interval_ms: DEFAULT_INTERVAL_MS,
+            held: None,
+            wait_ms: 0,
+        }
+    }
+}
+
+/// Change the repeat timing (a delay of 0 turns repeat off). The interval
+/// is rounded up to at least 1ms.
+pub fn set_timing(ctx: &mut state::Context, delay_ms: u32, interval_ms: u32) {
+    ctx.repeat.delay_ms = delay_ms;
+    ctx.repeat.interval_ms = interval_ms.max(1);
+    ctx.repeat.held = None;
+}
+
+/// Check if a keystroke result repeats while its key is held. Modifiers,
+/// soft keys, and keys that open something do not, and neither do
+/// characters that have long-press alternates on the home screen (the only
+/// view where long-press opens them).
+fn repeats(ctx: &state::Context, r: &R) -> bool {
+    match r {
+        R::Up | R::Left | R::Right | R::Down => true,
+        R::Bksp | R::Delete | R::Enter | R::Undo | R::Redo => true,
+        R::C(c) => ctx.views.active() != View::Home || alternates::lookup(*c).is_none(),
+        _ => false,
+    }
+}
+
+/// Start timing a key that was pressed, if its result repeats
+pub fn key_down(ctx: &mut state::Context, key_index: usize, lut: &'static MapResultLUT) {
+    ctx.repeat.held = match ctx.repeat.delay_ms {
+        0 => None,
+        _ if repeats(ctx, &lut[key_index]) => Some((key_index, lut)),
+        _ => None,
+    };
+    ctx.repeat.wait_ms = ctx.repeat.delay_ms;
+}
+
+/// Stop repeating a key that was released
+pub fn key_up(ctx: &mut state::Context, key_index: usize) {
+    if let Some((k, _)) = ctx.repeat.held {
+        if k == key_index {
+            ctx.repeat.held = None;
+        }
+    }
+}
+
+/// Stop repeating the held key, while it stays down
+pub fn stop(ctx: &mut state::Context) {
+    ctx.repeat.held = None;
+}
+
+/// Count elapsed time for the held key.
+/// Return: key index and keystroke result to repeat, if one is due
+pub fn tick(ctx: &mut state::Context, elapsed_ms: u32) -> Option<(usize, &'static R)> {
+    let (k, lut) = ctx.repeat.held?;
+    if elapsed_ms < ctx.repeat.wait_ms {
+        ctx.repeat.wait_ms -= elapsed_ms;
+        return None;
+    }
+    // Only one repeat per tick, even if the interval is shorter
+    ctx.repeat.wait_ms = ctx.repeat.interval_ms;
+    Some((k, &lut[k]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::kbd;
+    use super::*;
+
+    #[test]
+    fn held_key_repeats_after_delay() {
+        let mut ctx = state::Context::new();
+        kbd::set_layout(&mut ctx, kbd::Layout::Qwerty);
+        set_timing(&mut ctx, 300, 200);
+        // Backspace (P42) repeats, and Shift (P43) does not
+        let lut = kbd::cur_map_lut(&ctx);
+        key_down(&mut ctx, 38, lut);
+        let due: usize = (0..10).filter(|_| tick(&mut ctx, 100).is_some()).count();
+        assert_eq!(due, 4);
+        key_up(&mut ctx, 38);
+        assert!(tick(&mut ctx, 1000).is_none());
+        key_down(&mut ctx, 39, lut);
+        assert!(tick(&mut ctx, 1000).is_none());
+        // e (P25) has long-press alternates on the home screen only
+        key_down(&mut ctx, 21, lut);
+        assert!(tick(&mut ctx, 1000).is_none());
+        ctx.views.push(View::Settings);
+        key_down(&mut ctx, 21, lut);
+        assert!(tick(&mut ctx, 1000).is_some());
+    }
+}
@@ -0,0 +1,97 @@
+//! Physical keys and scancodes, shared with hosts
+//!
+//! A scancode names a key and says whether it went down or up, like "P13p"
+//! (press) or "P13r" (release). Hosts should use these types to turn
+//! scancodes into the key indexes that api::keydown and api::keyup take,
+//! rather than keep their own tables.
+
+use core::fmt;
+
+use super::kbd;
+
+// Key enum, KEYS, and KEY_NAMES are generated from the key list in build.rs
+include!(concat!(env!("OUT_DIR"), "/keys.rs"));
+
+impl Key {
+    /// Key index, as taken by api::keydown and api::keyup
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Key name, like "P13"
+    pub fn name(self) -> &'static str {
+        KEY_NAMES[self.index()]
+    }
+
+    /// Look up a key by key index
+    pub fn from_index(index: usize) -> Option<Key> {
+        KEYS.get(index).copied()
+    }
+
+    /// Look up a key by name, like "P13"
+    pub fn from_name(name: &str) -> Option<Key> {
+        let index = KEY_NAMES.iter().position(|n| *n == name)?;
+        Some(KEYS[index])
+    }
+}
+
+/// Key press or release
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Scancode {
+    Press(Key),
+    Release(Key),
+}
+impl Scancode {
+    /// Parse a scancode like "P13p" (press) or "P13r" (release)
+    pub fn parse(s: &str) -> Option<Scancode> {
+        let name = s.get(..s.len().checked_sub(1)?)?;
+        let key = Key::from_name(name)?;
+        match &s[name.len()..] {
+            "p" => Some(Scancode::Press(key)),
+            "r" => Some(Scancode::Release(key)),
+            _ => None,
+        }
+    }
+
+    /// Key that the scancode is for
+    pub fn key(self) -> Key {
+        match self {
+            Scancode::Press(key) | Scancode::Release(key) => key,
+        }
+    }
+
+    /// Check if the scancode is a press
+    pub fn is_press(self) -> bool {
+        matches!(self, Scancode::Press(_))
+    }
+}
+impl fmt::Display for Scancode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Scancode::Press(key) => write!(f, "{}p", key.name()),
+            Scancode::Release(key) => write!(f, "{}r", key.name()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use super::*;
+    use std::format;
+
+    #[test]
+    fn parse_scancodes() {
+        assert_eq!(Scancode::parse("P13p"), Some(Scancode::Press(Key::P13)));
+        assert_eq!(Scancode::parse("PCr"), Some(Scancode::Release(Key::PC)));
+        assert_eq!(Key::from_index(53), Some(Key::P57));
+        for bad in ["", "p", "P13", "P13x", "P1p", "P58p", "P2_p", "P13pp"] {
+            assert_eq!(Scancode::parse(bad), None, "{:?}", bad);
+        }
+        for (i, key) in KEYS.iter().enumerate() {
+            assert_eq!(key.index(), i);
+            let sc = Scancode::Release(*key);
+            assert_eq!(Scancode::parse(&format!("{}", sc)), Some(sc));
+        }
+    }
+}
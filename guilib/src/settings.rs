@@ -0,0 +1,237 @@
+//! Settings view for keyboard layout, theme, text size, and clock format
+
+use super::focus;
+use super::fonts::{Font, GlyphSet};
+use super::kbd::{self, Layout};
+use super::modifiers;
+use super::state::{self, View};
+
+/// Screen themes
+#[derive(Copy, Clone, PartialEq)]
+pub enum Theme {
+    /// Black text on a clear background
+    Light,
+    /// Clear text on a black background
+    Dark,
+}
+
+/// Sizes for text area text
+#[derive(Copy, Clone, PartialEq)]
+pub enum TextSize {
+    Small,
+    Regular,
+    /// Regular font at double scale
+    Large,
+}
+
+/// Status bar clock formats
+#[derive(Copy, Clone, PartialEq)]
+pub enum ClockFormat {
+    H24,
+    H12,
+}
+
+/// User settings other than the keyboard layout (which lives with the rest
+/// of the keyboard state)
+pub struct Settings {
+    pub theme: Theme,
+    pub text_size: TextSize,
+    pub clock: ClockFormat,
+}
+impl Settings {
+    /// Initialize default settings
+    pub const fn new() -> Settings {
+        Settings {
+            theme: Theme::Light,
+            text_size: TextSize::Regular,
+            clock: ClockFormat::H24,
+        }
+    }
+}
+
+/// Settings in the order they are painted (and focused)
+#[derive(Copy, Clone, PartialEq)]
+pub enum Field {
+    Layout,
+    TextSize,
+    Theme,
+    Clock,
+}
+
+/// Settings fields by focus index
+pub const FIELDS: [Field; 4] = [Field::Layout, Field::TextSize, Field::Theme, Field::Clock];
+
+/// Option labels for the layout and text size radio groups
+pub const LAYOUT_OPTIONS: [&str; kbd::LAYOUTS.len()] = kbd::LAYOUT_NAMES;
+pub const TEXT_SIZE_OPTIONS: [&str; 3] = ["Small", "Regular", "Large"];
+
+/// Font and integer scale for text area text
+pub fn text_style(ctx: &state::Context) -> (GlyphSet, usize) {
+    match ctx.settings.text_size {
+        TextSize::Small => (GlyphSet::Small, 1),
+        TextSize::Regular => (GlyphSet::Regular, 1),
+        TextSize::Large => (GlyphSet::Regular, 2),
+    }
+}
+
+/// Font for text area text
+pub fn text_font(ctx: &state::Context) -> Font {
+    Font::new(text_style(ctx).0)
+}
+
+/// Change keyboard layout, clearing any latched modifier keys
+pub fn set_layout(ctx: &mut state::Context, lo: Layout) {
+    kbd::set_layout(ctx, lo);
+    modifiers::reset(ctx);
+}
+
+/// Get the selected option index of a radio group field
+pub fn selected(ctx: &state::Context, field: Field) -> usize {
+    match field {
+        Field::Layout => ctx.kbd_layout as usize,
+        Field::TextSize => match ctx.settings.text_size {
+            TextSize::Small => 0,
+            TextSize::Regular => 1,
+            TextSize::Large => 2,
+        },
+        Field::Theme => (ctx.settings.theme == Theme::Dark) as usize,
+        Field::Clock => (ctx.settings.clock == ClockFormat::H12) as usize,
+    }
+}
+
+/// Number of options for a field (checkboxes have 2)
+fn option_count(field: Field) -> usize {
+    match field {
+        Field::Layout => LAYOUT_OPTIONS.len(),
+        Field::TextSize => TEXT_SIZE_OPTIONS.len(),
+        Field::Theme | Field::Clock => 2,
+    }
+}
+
+/// Apply option i of a field
+fn select(ctx: &mut state::Context, field: Field, i: usize) {
+    match field {
+        Field::Layout => set_layout(ctx, kbd::LAYOUTS[i]),
+        Field::TextSize => {
+            ctx.settings.text_size = match i {
+                0 => TextSize::Small,
+                1 => TextSize::Regular,
+                _ => TextSize::Large,
+            };
+            // Wrapping changes with the text size
+            ctx.text_area.top_line = 0;
+            ctx.text_area.goal_x = None;
+        }
+        Field::Theme => {
+            ctx.settings.theme = match i {
+                0 => Theme::Light,
+                _ => Theme::Dark,
+            }
+        }
+        Field::Clock => {
+            ctx.settings.clock = match i {
+                0 => ClockFormat::H24,
+                _ => ClockFormat::H12,
+            }
+        }
+    }
+}
+
+/// Open the settings view on top of the active view
+pub fn open(ctx: &mut state::Context) -> bool {
+    if ctx.views.active() == View::Settings {
+        return false;
+    }
+    ctx.views.push(View::Settings)
+}
+
+/// Close the settings view and return to the previous view
+pub fn close(ctx: &mut state::Context) -> bool {
+    ctx.views.pop()
+}
+
+/// Handle a keystroke while the settings view is active. Up and Down move
+/// through the options of the focused radio group, and move focus on to the
+/// next setting past the first or last option.
+/// Return: true if the keystroke was used (needs repaint)
+pub fn handle_key(ctx: &mut state::Context, r: &kbd::R) -> bool {
+    let field = match focus::focused(ctx) {
+        Some(i) => FIELDS[i],
+        None => return false,
+    };
+    if field == Field::Theme || field == Field::Clock {
+        return false;
+    }
+    let i = selected(ctx, field);
+    match r {
+        kbd::R::Up if i > 0 => select(ctx, field, i - 1),
+        kbd::R::Down if i + 1 < option_count(field) => select(ctx, field, i + 1),
+        _ => return false,
+    }
+    true
+}
+
+/// Activate the focused setting: toggle a checkbox, or select the next
+/// option of a radio group
+pub fn activate(ctx: &mut state::Context, i: usize) -> bool {
+    let field = FIELDS[i];
+    let next = (selected(ctx, field) + 1) % option_count(field);
+    select(ctx, field, next);
+    true
+}
+
+/// Format a 24 hour "HH:MM" time for the status bar clock. The 12 hour
+/// format drops a leading zero and adds "a" or "p".
+/// Return: formatted time in buf (the original time if it is not HH:MM)
+pub fn format_clock<'a>(ctx: &state::Context, time: &'a str, buf: &'a mut [u8; 8]) -> &'a str {
+    let b = time.as_bytes();
+    let valid = match b {
+        [h1, h2, b':', m1, m2] => [h1, h2, m1, m2].iter().all(|c| c.is_ascii_digit()),
+        _ => false,
+    };
+    if ctx.settings.clock == ClockFormat::H24 || !valid {
+        return time;
+    }
+    let h = ((b[0] - b'0') * 10 + (b[1] - b'0')) as usize;
+    let suffix = match h < 12 {
+        true => b'a',
+        false => b'p',
+    };
+    let h12 = match h % 12 {
+        0 => 12,
+        h => h,
+    };
+    let mut n = 0;
+    if h12 >= 10 {
+        buf[n] = b'1';
+        n += 1;
+    }
+    buf[n] = b'0' + (h12 % 10) as u8;
+    buf[n + 1..n + 4].copy_from_slice(&b[2..5]);
+    buf[n + 4] = suffix;
+    core::str::from_utf8(&buf[0..n + 5]).unwrap_or(time)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn twelve_hour_clock() {
+        let mut ctx = state::Context::new();
+        let mut buf = [0; 8];
+        assert_eq!(format_clock(&ctx, "13:05", &mut buf), "13:05");
+        ctx.settings.clock = ClockFormat::H12;
+        let times = [
+            ("13:05", "1:05p"),
+            ("00:30", "12:30a"),
+            ("11:59", "11:59a"),
+            ("12:00", "12:00p"),
+            ("9:00", "9:00"),
+        ];
+        for (t, want) in times.iter() {
+            let mut buf = [0; 8];
+            assert_eq!(format_clock(&ctx, t, &mut buf), *want);
+        }
+    }
+}
@@ -0,0 +1,370 @@
+//! Soft keys: per-view labels and actions for the F1-F4 keys, and popup
+//! menus that open from a soft key
+
+use super::dialog::{self, Dialog};
+use super::fontcosts;
+use super::kbd;
+use super::nav;
+use super::settings;
+use super::state::{self, View};
+use super::symbols;
+use super::toast;
+
+/// Maximum number of items in a popup menu
+pub const MENU_MAX: usize = 8;
+
+/// Number of soft keys (F1-F4)
+pub const SOFT_KEY_COUNT: usize = 4;
+
+/// Function that carries out a soft key or menu item action.
+/// Return: true if the action changed anything (needs repaint)
+pub type ActionFnPtr = fn(&mut state::Context) -> bool;
+
+/// What pressing a soft key does
+pub enum Action {
+    None,
+    Run(ActionFnPtr),
+    Menu(&'static Menu),
+}
+
+/// Label and action for one soft key
+pub struct SoftKey {
+    pub label: &'static str,
+    pub action: Action,
+}
+
+/// Labels and actions for F1, F2, F3, and F4
+pub type SoftKeys = [SoftKey; SOFT_KEY_COUNT];
+
+/// Popup menu of actions
+pub struct Menu {
+    pub title: &'static str,
+    pub items: &'static [MenuItem],
+}
+
+/// One action in a popup menu
+pub struct MenuItem {
+    pub label: &'static str,
+    pub run: ActionFnPtr,
+}
+
+/// Soft key with no label that does nothing
+pub const NONE: SoftKey = SoftKey {
+    label: "",
+    action: Action::None,
+};
+
+/// Soft keys for views that have none
+pub static NO_SOFT_KEYS: SoftKeys = [NONE, NONE, NONE, NONE];
+
+/// Popup menu that is open, the soft key it was opened from, and the
+/// selected item
+pub struct MenuState {
+    pub menu: Option<&'static Menu>,
+    pub key: usize,
+    pub selected: usize,
+}
+impl MenuState {
+    /// Initialize with no menu open
+    pub const fn new() -> MenuState {
+        MenuState {
+            menu: None,
+            key: 0,
+            selected: 0,
+        }
+    }
+}
+
+/// Map an F-key result to its soft key number (0 for F1 to 3 for F4)
+pub fn soft_key_number(r: &kbd::R) -> Option<usize> {
+    match r {
+        kbd::R::F1 => Some(0),
+        kbd::R::F2 => Some(1),
+        kbd::R::F3 => Some(2),
+        kbd::R::F4 => Some(3),
+        _ => None,
+    }
+}
+
+/// Get the soft key label that replaces a keycap label for an F-key
+pub fn label(ctx: &state::Context, r: &kbd::R) -> Option<&'static str> {
+    soft_key_number(r).map(|n| (nav::active(ctx).soft_keys)(ctx)[n].label)
+}
+
+/// Carry out the active view's action for an F-key
+/// Return: true if the action changed anything (needs repaint)
+pub fn press(ctx: &mut state::Context, r: &kbd::R) -> bool {
+    let n = match soft_key_number(r) {
+        Some(n) => n,
+        None => return false,
+    };
+    match (nav::active(ctx).soft_keys)(ctx)[n].action {
+        Action::None => false,
+        Action::Run(run) => run(ctx),
+        Action::Menu(menu) => open_menu(ctx, menu, n),
+    }
+}
+
+/// Open a popup menu above soft key n
+fn open_menu(ctx: &mut state::Context, menu: &'static Menu, n: usize) -> bool {
+    if menu.items.is_empty() || !ctx.views.push(View::Menu) {
+        return false;
+    }
+    ctx.menu = MenuState {
+        menu: Some(menu),
+        key: n,
+        selected: 0,
+    };
+    true
+}
+
+/// Close the popup menu and return to the view it was opened from
+pub fn close_menu(ctx: &mut state::Context) -> bool {
+    ctx.menu.menu = None;
+    ctx.views.pop()
+}
+
+/// Close the popup menu and run the selected item
+fn run_selected(ctx: &mut state::Context) -> bool {
+    let run = match ctx.menu.menu {
+        Some(menu) => menu.items[ctx.menu.selected].run,
+        None => return false,
+    };
+    close_menu(ctx);
+    run(ctx);
+    true
+}
+
+/// Title of the popup menu view
+pub fn menu_title(ctx: &state::Context) -> &str {
+    ctx.menu.menu.map_or("", |m| m.title)
+}
+
+/// Handle a keystroke while a popup menu is open. The menu is modal, so it
+/// uses every keystroke except modifier keys.
+/// Return: true if the keystroke was used (needs repaint)
+pub fn menu_handle_key(ctx: &mut state::Context, r: &kbd::R) -> bool {
+    let items = ctx.menu.menu.map_or(0, |m| m.items.len());
+    let count = core::cmp::min(items, MENU_MAX);
+    if count == 0 {
+        return false;
+    }
+    let i = ctx.menu.selected;
+    match r {
+        kbd::R::Up => ctx.menu.selected = (i + count - 1) % count,
+        kbd::R::Down => ctx.menu.selected = (i + 1) % count,
+        kbd::R::Click | kbd::R::Enter => return run_selected(ctx),
+        kbd::R::F1 | kbd::R::F2 | kbd::R::F3 | kbd::R::F4 => {
+            // The soft key that opened the menu closes it, and the others
+            // act on the menu through the menu view's own soft keys
+            if soft_key_number(r) == Some(ctx.menu.key) {
+                return close_menu(ctx);
+            }
+            return press(ctx, r);
+        }
+        kbd::R::AltL | kbd::R::AltR | kbd::R::Shift => return false,
+        _ => (),
+    }
+    true
+}
+
+/// Soft keys for the home screen
+static HOME_SOFT_KEYS: SoftKeys = [
+    SoftKey {
+        label: "edit",
+        action: Action::Menu(&EDIT_MENU),
+    },
+    NONE,
+    SoftKey {
+        label: "sym",
+        action: Action::Run(open_symbols),
+    },
+    SoftKey {
+        label: "setup",
+        action: Action::Run(settings::open),
+    },
+];
+
+/// Soft keys for the symbol picker
+static SYMBOL_PICKER_SOFT_KEYS: SoftKeys = [
+    SoftKey {
+        label: "back",
+        action: Action::Run(close_symbols),
+    },
+    NONE,
+    NONE,
+    SoftKey {
+        label: "insert",
+        action: Action::Run(symbols::insert_selected),
+    },
+];
+
+/// Soft keys for the settings view
+static SETTINGS_SOFT_KEYS: SoftKeys = [
+    SoftKey {
+        label: "back",
+        action: Action::Run(settings::close),
+    },
+    NONE,
+    NONE,
+    SoftKey {
+        label: "fonts",
+        action: Action::Run(fontcosts::open),
+    },
+];
+
+/// Soft keys for the font costs view
+static FONT_COSTS_SOFT_KEYS: SoftKeys = [
+    SoftKey {
+        label: "back",
+        action: Action::Run(fontcosts::close),
+    },
+    NONE,
+    NONE,
+    NONE,
+];
+
+/// Soft keys while a popup menu is open
+static MENU_SOFT_KEYS: SoftKeys = [
+    SoftKey {
+        label: "close",
+        action: Action::Run(close_menu),
+    },
+    NONE,
+    NONE,
+    SoftKey {
+        label: "ok",
+        action: Action::Run(run_selected),
+    },
+];
+
+/// Soft keys of views that always show the same soft keys
+pub fn home_soft_keys(_ctx: &state::Context) -> &'static SoftKeys {
+    &HOME_SOFT_KEYS
+}
+
+pub fn symbol_picker_soft_keys(_ctx: &state::Context) -> &'static SoftKeys {
+    &SYMBOL_PICKER_SOFT_KEYS
+}
+
+pub fn settings_soft_keys(_ctx: &state::Context) -> &'static SoftKeys {
+    &SETTINGS_SOFT_KEYS
+}
+
+pub fn font_costs_soft_keys(_ctx: &state::Context) -> &'static SoftKeys {
+    &FONT_COSTS_SOFT_KEYS
+}
+
+pub fn menu_soft_keys(_ctx: &state::Context) -> &'static SoftKeys {
+    &MENU_SOFT_KEYS
+}
+
+/// Home screen edit menu
+static EDIT_MENU: Menu = Menu {
+    title: "edit",
+    items: &[
+        MenuItem {
+            label: "Undo",
+            run: undo,
+        },
+        MenuItem {
+            label: "Redo",
+            run: redo,
+        },
+        MenuItem {
+            label: "Start of line",
+            run: home,
+        },
+        MenuItem {
+            label: "End of line",
+            run: end,
+        },
+        MenuItem {
+            label: "Clear note",
+            run: confirm_clear,
+        },
+    ],
+};
+
+/// Confirmation before clearing the note
+static CLEAR_DIALOG: Dialog = Dialog {
+    title: "clear",
+    message: "Clear the whole note? This cannot be undone.",
+    buttons: [
+        SoftKey {
+            label: "cancel",
+            action: Action::None,
+        },
+        NONE,
+        NONE,
+        SoftKey {
+            label: "clear",
+            action: Action::Run(clear),
+        },
+    ],
+};
+
+fn open_symbols(ctx: &mut state::Context) -> bool {
+    symbols::open(ctx);
+    true
+}
+
+fn close_symbols(ctx: &mut state::Context) -> bool {
+    symbols::close(ctx);
+    true
+}
+
+fn undo(ctx: &mut state::Context) -> bool {
+    ctx.text.undo()
+}
+
+fn redo(ctx: &mut state::Context) -> bool {
+    ctx.text.redo()
+}
+
+fn home(ctx: &mut state::Context) -> bool {
+    ctx.text.home()
+}
+
+fn end(ctx: &mut state::Context) -> bool {
+    ctx.text.end()
+}
+
+fn confirm_clear(ctx: &mut state::Context) -> bool {
+    dialog::open(ctx, &CLEAR_DIALOG)
+}
+
+fn clear(ctx: &mut state::Context) -> bool {
+    ctx.text.clear();
+    ctx.text_area.top_line = 0;
+    toast::show(ctx, "Note cleared");
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn soft_key_menu_runs_item() {
+        let mut ctx = state::Context::new();
+        for c in "ab".chars() {
+            ctx.text.insert(c);
+        }
+        assert!(label(&ctx, &kbd::R::F1) == Some("edit"));
+        assert!(press(&mut ctx, &kbd::R::F1));
+        assert!(ctx.views.active() == View::Menu);
+        assert!(label(&ctx, &kbd::R::F4) == Some("ok"));
+        // The opening soft key closes the menu again
+        assert!(menu_handle_key(&mut ctx, &kbd::R::F1));
+        assert!(ctx.views.active() == View::Home);
+        // Pick "Undo" with Up from the top (wraps to the bottom) and back
+        press(&mut ctx, &kbd::R::F1);
+        menu_handle_key(&mut ctx, &kbd::R::Up);
+        assert_eq!(ctx.menu.selected, EDIT_MENU.items.len() - 1);
+        menu_handle_key(&mut ctx, &kbd::R::Down);
+        assert!(menu_handle_key(&mut ctx, &kbd::R::Click));
+        assert!(ctx.views.active() == View::Home);
+        assert_eq!(ctx.text.as_str(), "");
+    }
+}
@@ -1,6 +1,23 @@
+use super::alternates;
 use super::blit;
+use super::compose;
+use super::dialog;
+use super::editor;
+use super::focus;
+use super::fontcosts;
 use super::fonts;
+use super::hid;
 use super::kbd;
+use super::modifiers;
+use super::nav;
+use super::repeat;
+use super::settings;
+use super::softkeys;
+use super::strbuf::StrBuf;
+use super::textarea;
+use super::toast;
+use super::touch;
+use super::widgets;
 
 /// Battery charge levels
 #[derive(Copy, Clone)]
@@ -12,6 +29,18 @@ pub enum Battery {
     B75,
     B99,
 }
+impl Battery {
+    /// Pick the charge level icon closest to a percentage
+    pub fn from_percent(percent: u32) -> Battery {
+        match percent {
+            0..=14 => Battery::B05,
+            15..=37 => Battery::B25,
+            38..=62 => Battery::B50,
+            63..=87 => Battery::B75,
+            _ => Battery::B99,
+        }
+    }
+}
 
 /// Radio signal strength levels
 #[derive(Copy, Clone)]
@@ -23,6 +52,31 @@ pub enum Radio {
     R0,
     ROff,
 }
+impl Radio {
+    /// Pick the signal strength icon for a number of bars (0 to 3), or
+    /// radio off for a negative number
+    pub fn from_bars(bars: i32) -> Radio {
+        match bars {
+            i32::MIN..=-1 => Radio::ROff,
+            0 => Radio::R0,
+            1 => Radio::R1,
+            2 => Radio::R2,
+            _ => Radio::R3,
+        }
+    }
+}
+
+/// Views that can be painted and receive keystrokes
+#[derive(Copy, Clone, PartialEq)]
+pub enum View {
+    Home,
+    SymbolPicker,
+    Menu,
+    Settings,
+    Dialog,
+    Alternates,
+    FontCosts,
+}
 
 /// LCD frame buffer
 pub struct FrameBuf {
@@ -53,45 +107,78 @@ impl FrameBuf {
     }
 }
 
-/// Size of the buffer backing TextBuf
-pub const MAX_CHARS: usize = 14;
-pub const CHAR_BUF_SIZE: usize = MAX_CHARS;
-pub const UTF8_BUF_SIZE: usize = MAX_CHARS * 4;
+/// Capacity in bytes of status bar title, status bar clock, and home screen
+/// placeholder text
+pub const STATUS_TITLE_SIZE: usize = 24;
+pub const STATUS_TIME_SIZE: usize = 8;
+pub const NOTE_SIZE: usize = 64;
 
 /// Status bar data, home screen text buffer, keyboard modkeys, etc.
-pub struct Context<'a> {
+pub struct Context {
     pub status_battery: Battery,
+    pub status_charging: bool,
     pub status_radio: Radio,
-    pub status_title: &'a str,
-    pub status_time: &'a str,
-    // Home screen sample text
-    pub note: &'a str,
-    // Character and string buffer for a minimalist FIFO string editor
-    pub char_buf: [char; CHAR_BUF_SIZE],
-    pub char_buf_end: usize,
-    pub utf8_buf: [u8; UTF8_BUF_SIZE],
-    pub utf8_buf_end: usize,
-    // Keyboard
+    pub status_title: StrBuf<STATUS_TITLE_SIZE>,
+    pub status_time: StrBuf<STATUS_TIME_SIZE>,
+    // Home screen placeholder text (shown while the text buffer is empty)
+    pub note: StrBuf<NOTE_SIZE>,
+    // Home screen text editing buffer and text area
+    pub text: editor::TextBuf,
+    pub text_area: textarea::TextArea,
+    // Keyboard, latched or held modifier keys, key repeat, pending dead key
+    // accent or Compose sequence, and keys held on a USB HID keyboard
     pub kbd_layout: kbd::Layout,
     pub kbd_modkey: kbd::ModKey,
+    pub modifiers: modifiers::Modifiers,
+    pub repeat: repeat::KeyRepeat,
+    pub compose: compose::ComposeState,
+    pub hid: hid::HidKeyboard,
+    // Long-pressed key and its strip of alternate characters
+    pub alternates: alternates::AlternatesState,
+    // Theme, text size, and clock format
+    pub settings: settings::Settings,
+    // View stack, focusable widgets of the active view, touch in progress,
+    // symbol picker selection, and font costs font list
+    pub views: nav::ViewStack,
+    pub focus: focus::FocusRing,
+    pub touch: touch::TouchState,
+    pub symbol_index: usize,
+    pub font_list: widgets::List<'static>,
+    // Popup menu opened from a soft key, modal dialog, and toast
+    pub menu: softkeys::MenuState,
+    pub dialog: dialog::DialogState,
+    pub toast: toast::Toast,
     // Demo Animation
     pub demo_frame: usize,
 }
-impl Context<'_> {
+impl Context {
     /// Initialize a GUI context object
-    pub const fn new() -> Context<'static> {
+    pub const fn new() -> Context {
         Context {
             status_battery: Battery::B75,
+            status_charging: false,
             status_radio: Radio::R3,
-            status_title: &"home",
-            status_time: &"12:34",
-            note: &"Hello, world!",
-            char_buf: ['\0'; CHAR_BUF_SIZE],
-            char_buf_end: 0,
-            utf8_buf: [0; UTF8_BUF_SIZE],
-            utf8_buf_end: 0,
+            status_title: StrBuf::new("home"),
+            status_time: StrBuf::new("12:34"),
+            note: StrBuf::new("Type a note"),
+            text: editor::TextBuf::new(),
+            text_area: textarea::TextArea::new(),
             kbd_layout: kbd::Layout::Azerty,
             kbd_modkey: kbd::ModKey::Base,
+            modifiers: modifiers::Modifiers::new(),
+            repeat: repeat::KeyRepeat::new(),
+            compose: compose::ComposeState::new(),
+            hid: hid::HidKeyboard::new(),
+            alternates: alternates::AlternatesState::new(),
+            settings: settings::Settings::new(),
+            views: nav::ViewStack::new(),
+            focus: focus::FocusRing::new(),
+            touch: touch::TouchState::new(),
+            symbol_index: 0,
+            font_list: fontcosts::new_list(),
+            menu: softkeys::MenuState::new(),
+            dialog: dialog::DialogState::new(),
+            toast: toast::Toast::new(),
             demo_frame: 0,
         }
     }
@@ -138,36 +225,9 @@ impl Context<'_> {
             Radio::ROff => fonts::pua::RADIO_OFF,
         }
     }
-
-    /// Accumulate buffer of recently typed characters
-    pub fn buffer_keystroke(&mut self, c: char) {
-        // Update the character buffer
-        if self.char_buf_end < CHAR_BUF_SIZE {
-            // Append character when character buffer is not yet full
-            self.char_buf[self.char_buf_end] = c;
-            self.char_buf_end += 1;
-        } else {
-            // When buffer is full, discard oldest, then append
-            for i in 0..CHAR_BUF_SIZE - 1 {
-                self.char_buf[i] = self.char_buf[i + 1];
-            }
-            self.char_buf[CHAR_BUF_SIZE - 1] = c;
-        }
-        // Encode the character buffer as utf-8 into the utf8 buffer
-        let mut end = 0;
-        for c in self.char_buf[0..self.char_buf_end].iter() {
-            let dest = &mut self.utf8_buf[end..end + 4];
-            let result = c.encode_utf8(dest);
-            end += result.len();
-        }
-        self.utf8_buf_end = end;
-    }
-
-    /// Get string slice for buffer of recently typed characters
-    pub fn buffer(&self) -> &str {
-        match core::str::from_utf8(&self.utf8_buf[0..self.utf8_buf_end]) {
-            Ok(s) => s,
-            Err(_) => &"",
-        }
+}
+impl Default for Context {
+    fn default() -> Self {
+        Self::new()
     }
 }
@@ -0,0 +1,65 @@
+//! Owned, fixed-capacity UTF-8 strings for text that hosts set at runtime
+
+/// String of up to N bytes of UTF-8. Text that does not fit is truncated at
+/// a char boundary.
+#[derive(Copy, Clone)]
+pub struct StrBuf<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+impl<const N: usize> StrBuf<N> {
+    /// Initialize from a string (usable in const context)
+    pub const fn new(s: &str) -> StrBuf<N> {
+        let bytes = s.as_bytes();
+        let len = fit(bytes, N);
+        let mut buf = [0; N];
+        let mut i = 0;
+        while i < len {
+            buf[i] = bytes[i];
+            i += 1;
+        }
+        StrBuf { buf, len }
+    }
+
+    /// Replace the contents with a string
+    pub fn set(&mut self, s: &str) {
+        let bytes = s.as_bytes();
+        self.len = fit(bytes, N);
+        self.buf[0..self.len].copy_from_slice(&bytes[0..self.len]);
+    }
+
+    /// Get string slice of the contents
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[0..self.len]).unwrap_or_default()
+    }
+}
+
+/// Length of the longest prefix of UTF-8 bytes that fits in n bytes without
+/// splitting a char
+const fn fit(bytes: &[u8], n: usize) -> usize {
+    if bytes.len() <= n {
+        return bytes.len();
+    }
+    let mut len = n;
+    // Back up over UTF-8 continuation bytes (0b10xx_xxxx)
+    while len > 0 && (bytes[len] & 0xc0) == 0x80 {
+        len -= 1;
+    }
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncates_at_char_boundary() {
+        const TITLE: StrBuf<8> = StrBuf::new("home");
+        assert_eq!(TITLE.as_str(), "home");
+        let mut s: StrBuf<4> = StrBuf::new("");
+        s.set("abc€");
+        assert_eq!(s.as_str(), "abc");
+        s.set("€uro");
+        assert_eq!(s.as_str(), "€u");
+    }
+}
@@ -0,0 +1,103 @@
+//! Symbol picker for inserting punctuation, currency, and math symbols
+
+use super::kbd;
+use super::state::{self, View};
+
+/// Number of columns in the symbol picker grid
+pub const COLS: usize = 8;
+
+/// Number of symbols in the symbol picker grid
+pub const SYMBOL_COUNT: usize = 44;
+
+/// Number of rows in the symbol picker grid
+pub const ROWS: usize = SYMBOL_COUNT.div_ceil(COLS);
+
+/// Symbols offered by the picker, in grid order (left to right, top to bottom).
+/// These come from the Latin 1, General Punctuation, and Currency Symbols
+/// blocks of the bitmap fonts, skipping letters and invisible characters.
+pub const SYMBOLS: [char; SYMBOL_COUNT] = [
+    '¡', '¿', '§', '¶', '©', '®', '°', 'º', // Latin 1 punctuation
+    'ª', '«', '»', '·', '¦', '¨', '´', '¸', //
+    '¯', '¬', '¢', '£', '¤', '¥', '€', 'µ', // Currency (and micro)
+    '±', '×', '÷', '¹', '²', '³', '¼', '½', // Math
+    '¾', '‘', '’', '‚', '‛', '“', '”', '„', // General Punctuation
+    '‟', '†', '‡', '•',
+];
+
+/// Open the symbol picker on top of the active view
+pub fn open(ctx: &mut state::Context) {
+    if ctx.views.active() == View::SymbolPicker {
+        return;
+    }
+    ctx.views.push(View::SymbolPicker);
+}
+
+/// Close the symbol picker and return to the previous view
+pub fn close(ctx: &mut state::Context) {
+    ctx.views.pop();
+}
+
+/// Insert the selected symbol into the text buffer and close the picker
+pub fn insert_selected(ctx: &mut state::Context) -> bool {
+    ctx.text.insert(SYMBOLS[ctx.symbol_index]);
+    close(ctx);
+    true
+}
+
+/// Handle a keystroke result while the symbol picker is active.
+/// Return: true if the picker used the keystroke (needs repaint)
+pub fn handle_key(ctx: &mut state::Context, r: &kbd::R) -> bool {
+    let i = ctx.symbol_index;
+    match r {
+        kbd::R::Up => ctx.symbol_index = (i + (ROWS * COLS) - COLS) % (ROWS * COLS),
+        kbd::R::Down => ctx.symbol_index = (i + COLS) % (ROWS * COLS),
+        kbd::R::Left => ctx.symbol_index = (i + SYMBOL_COUNT - 1) % SYMBOL_COUNT,
+        kbd::R::Right => ctx.symbol_index = (i + 1) % SYMBOL_COUNT,
+        kbd::R::Click => return insert_selected(ctx),
+        kbd::R::Symbol => {
+            close(ctx);
+            return true;
+        }
+        // The picker is modal, so swallow typed characters
+        kbd::R::C(_) => return true,
+        _ => return false,
+    }
+    // Moving up or down from a full row into the partial last row can land
+    // past the end of the symbol list, so clamp to the last symbol
+    if ctx.symbol_index >= SYMBOL_COUNT {
+        ctx.symbol_index = SYMBOL_COUNT - 1;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::fonts;
+    use super::SYMBOLS;
+
+    #[test]
+    #[cfg(all(
+        feature = "bold-latin-1",
+        feature = "bold-general-punctuation",
+        feature = "bold-currency-symbols",
+        feature = "regular-latin-1",
+        feature = "regular-general-punctuation",
+        feature = "regular-currency-symbols",
+        feature = "small-latin-1",
+        feature = "small-general-punctuation",
+        feature = "small-currency-symbols"
+    ))]
+    fn all_symbols_have_glyphs() {
+        let bold_missing = fonts::bold::get_glyph_pattern_offset('\u{FFFD}');
+        let regular_missing = fonts::regular::get_glyph_pattern_offset('\u{FFFD}');
+        let small_missing = fonts::small::get_glyph_pattern_offset('\u{FFFD}');
+        for c in SYMBOLS.iter() {
+            assert_ne!(fonts::bold::get_glyph_pattern_offset(*c), bold_missing);
+            assert_ne!(
+                fonts::regular::get_glyph_pattern_offset(*c),
+                regular_missing
+            );
+            assert_ne!(fonts::small::get_glyph_pattern_offset(*c), small_missing);
+        }
+    }
+}
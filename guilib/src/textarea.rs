@@ -0,0 +1,296 @@
+//! Multi-line text area with word wrap, scrolling, and cursor movement by
+//! visual line
+
+use super::blit;
+use super::editor;
+use super::fonts::{self, Font};
+use super::kbd;
+use super::settings;
+use super::state;
+use super::views;
+
+/// Scroll position and vertical cursor movement state for a text area
+pub struct TextArea {
+    /// Index of the first visible visual line
+    pub top_line: usize,
+    /// Caret x position to aim for while moving the cursor up or down
+    pub goal_x: Option<usize>,
+}
+impl TextArea {
+    /// Initialize a text area scrolled to the top
+    pub const fn new() -> TextArea {
+        TextArea {
+            top_line: 0,
+            goal_x: None,
+        }
+    }
+}
+
+/// Visual line of wrapped text as byte offsets: start..end is the text to
+/// show, and next is the start of the following line (after any newline or
+/// space that the line was broken on)
+#[derive(Copy, Clone)]
+pub struct Line {
+    pub start: usize,
+    pub end: usize,
+    pub next: usize,
+}
+
+/// Font used for text area text
+pub fn font(ctx: &state::Context) -> Font {
+    settings::text_font(ctx)
+}
+
+/// Height of a visual line in pixels
+pub fn line_h(ctx: &state::Context) -> usize {
+    let (gs, scale) = settings::text_style(ctx);
+    fonts::max_height(gs) * scale
+}
+
+/// Width in unscaled pixels to wrap text at for a region. Text at larger
+/// scales wraps as if the region were narrower, so that line breaking and
+/// cursor movement can work in unscaled font pixels.
+pub fn wrap_width(ctx: &state::Context, cr: blit::ClipRegion) -> usize {
+    (cr.x1 - cr.x0) / settings::text_style(ctx).1
+}
+
+/// Width in pixels that a string advances the x position
+pub fn advance(s: &str, f: Font) -> usize {
+    s.chars().map(|c| blit::char_width(c, f) + 3).sum()
+}
+
+/// Iterator over the visual lines of text wrapped to a width in pixels
+pub struct Lines<'a> {
+    s: &'a str,
+    pos: usize,
+    width: usize,
+    f: Font,
+    done: bool,
+}
+
+/// Wrap text to a width in pixels
+pub fn lines(s: &str, width: usize, f: Font) -> Lines<'_> {
+    Lines {
+        s,
+        pos: 0,
+        width,
+        f,
+        done: false,
+    }
+}
+
+impl Iterator for Lines<'_> {
+    type Item = Line;
+
+    fn next(&mut self) -> Option<Line> {
+        if self.done {
+            return None;
+        }
+        let start = self.pos;
+        let mut x = 0;
+        let mut last_space = None;
+        for (i, c) in self.s[start..].char_indices() {
+            let i = start + i;
+            if c == '\n' {
+                self.pos = i + 1;
+                return Some(Line {
+                    start,
+                    end: i,
+                    next: i + 1,
+                });
+            }
+            x += blit::char_width(c, self.f) + 3;
+            // A space that overflows the width breaks the line by itself
+            if c == ' ' {
+                last_space = Some(i);
+            }
+            if x > self.width && i > start {
+                // Break after the last space if there was one, otherwise
+                // break the word here
+                let line = match last_space {
+                    Some(sp) => Line {
+                        start,
+                        end: sp,
+                        next: sp + 1,
+                    },
+                    None => Line {
+                        start,
+                        end: i,
+                        next: i,
+                    },
+                };
+                self.pos = line.next;
+                return Some(line);
+            }
+        }
+        self.done = true;
+        let end = self.s.len();
+        Some(Line {
+            start,
+            end,
+            next: end,
+        })
+    }
+}
+
+/// Find the visual line that holds the cursor
+/// Return: (line index, line)
+pub fn cursor_line(s: &str, cursor: usize, width: usize, f: Font) -> (usize, Line) {
+    let mut found = (
+        0,
+        Line {
+            start: 0,
+            end: 0,
+            next: 0,
+        },
+    );
+    for (n, line) in lines(s, width, f).enumerate() {
+        found = (n, line);
+        if cursor < line.next {
+            break;
+        }
+    }
+    found
+}
+
+/// Scroll so the cursor's visual line is visible
+pub fn scroll_to_cursor(ctx: &mut state::Context, cr: blit::ClipRegion) {
+    let width = wrap_width(ctx, cr);
+    let (n, _) = cursor_line(ctx.text.as_str(), ctx.text.cursor(), width, font(ctx));
+    let visible = (cr.y1 - cr.y0) / line_h(ctx);
+    let area = &mut ctx.text_area;
+    if n < area.top_line {
+        area.top_line = n;
+    } else if n >= area.top_line + visible {
+        area.top_line = n + 1 - visible;
+    }
+}
+
+/// Move the cursor up or down by one visual line, keeping its x
+/// position as close as possible to where vertical movement started.
+/// Return: false if there was no line to move to
+fn move_vertical(ctx: &mut state::Context, down: bool, width: usize) -> bool {
+    let f = font(ctx);
+    let s = ctx.text.as_str();
+    let cursor = ctx.text.cursor();
+    let (n, line) = cursor_line(s, cursor, width, f);
+    let goal_x = match ctx.text_area.goal_x {
+        Some(x) => x,
+        None => advance(&s[line.start..cursor], f),
+    };
+    let target = match (down, n) {
+        (false, 0) => return false,
+        (false, n) => n - 1,
+        (true, n) => n + 1,
+    };
+    let line = match lines(s, width, f).nth(target) {
+        Some(line) => line,
+        None => return false,
+    };
+    // Pick the char boundary nearest to goal_x
+    let mut x = 0;
+    let mut pos = line.end;
+    for (i, c) in s[line.start..line.end].char_indices() {
+        let w = blit::char_width(c, f) + 3;
+        if x + w / 2 > goal_x {
+            pos = line.start + i;
+            break;
+        }
+        x += w;
+    }
+    // Where a long word was broken, the end of this line is the start of the
+    // next one, so stay before the last char to stay on this line
+    if pos == line.end && line.end == line.next && line.next < s.len() {
+        pos = s[line.start..line.end]
+            .char_indices()
+            .last()
+            .map_or(line.start, |(i, _)| line.start + i);
+    }
+    ctx.text.set_cursor(pos);
+    ctx.text_area.goal_x = Some(goal_x);
+    true
+}
+
+/// Handle a keystroke result for the text area, passing anything other than
+/// Up and Down along to the text editor.
+/// Return: true if the keystroke was used (needs repaint)
+pub fn handle_key(ctx: &mut state::Context, r: &kbd::R) -> bool {
+    let width = wrap_width(ctx, views::TEXT_AREA);
+    match r {
+        kbd::R::Up => move_vertical(ctx, false, width),
+        kbd::R::Down => move_vertical(ctx, true, width),
+        _ => {
+            ctx.text_area.goal_x = None;
+            editor::handle_key(ctx, r)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::state;
+    use super::*;
+
+    fn typed(ctx: &mut state::Context, s: &str) {
+        for c in s.chars() {
+            ctx.text.insert(c);
+        }
+    }
+
+    #[test]
+    fn wraps_at_spaces_and_newlines() {
+        let f = font(&state::Context::new());
+        let width = advance("hello world", f);
+        let s = "hello world again\nx";
+        let mut it = lines(s, width, f);
+        let l = it.next().unwrap();
+        assert_eq!(&s[l.start..l.end], "hello world");
+        let l = it.next().unwrap();
+        assert_eq!(&s[l.start..l.end], "again");
+        let l = it.next().unwrap();
+        assert_eq!(&s[l.start..l.end], "x");
+        assert!(it.next().is_none());
+    }
+
+    #[test]
+    fn breaks_long_words() {
+        let f = font(&state::Context::new());
+        let width = advance("aaaa", f);
+        let s = "aaaaaaaaaa";
+        let widths: [usize; 3] = [4, 4, 2];
+        for (l, w) in lines(s, width, f).zip(widths.iter()) {
+            assert_eq!(l.end - l.start, *w);
+        }
+    }
+
+    #[test]
+    fn up_down_by_visual_line() {
+        let mut ctx = state::Context::new();
+        typed(
+            &mut ctx,
+            "one\nthree\nfive five five five five five five five five",
+        );
+        let width = wrap_width(&ctx, views::TEXT_AREA);
+        assert_eq!(lines(ctx.text.as_str(), width, font(&ctx)).count(), 4);
+        // From the start of the wrapped line, go up to the top
+        handle_key(&mut ctx, &kbd::R::Home);
+        assert!(handle_key(&mut ctx, &kbd::R::Up));
+        assert_eq!(ctx.text.cursor(), 4);
+        assert!(handle_key(&mut ctx, &kbd::R::Up));
+        assert_eq!(ctx.text.cursor(), 0);
+        assert!(!handle_key(&mut ctx, &kbd::R::Up));
+        // From the end of "three", go down through the wrapped lines and back
+        assert!(handle_key(&mut ctx, &kbd::R::Down));
+        handle_key(&mut ctx, &kbd::R::End);
+        assert!(handle_key(&mut ctx, &kbd::R::Down));
+        assert!(handle_key(&mut ctx, &kbd::R::Down));
+        assert!(!handle_key(&mut ctx, &kbd::R::Down));
+        assert_eq!(
+            cursor_line(ctx.text.as_str(), ctx.text.cursor(), width, font(&ctx)).0,
+            3
+        );
+        handle_key(&mut ctx, &kbd::R::Up);
+        handle_key(&mut ctx, &kbd::R::Up);
+        assert_eq!(ctx.text.cursor(), 9);
+    }
+}
@@ -0,0 +1,133 @@
+//! Toast notifications: short messages drawn over the bottom of the main
+//! area that dismiss themselves after a few ticks
+
+use super::blit::{self, ClipRegion};
+use super::state::{self, FrameBuf};
+use super::strbuf::StrBuf;
+use super::{settings, views, widgets};
+
+/// Ticks that a toast stays on screen (see api::tick for the tick rate)
+pub const TOAST_TICKS: usize = 20;
+
+/// Capacity in bytes of toast text
+pub const TOAST_TEXT_SIZE: usize = 40;
+
+/// Toast height and Y bounds (just above the bottom of the main area)
+pub const TOAST_H: usize = 36;
+pub const TOAST_Y1: usize = views::MAIN_Y1 - 8;
+pub const TOAST_Y0: usize = TOAST_Y1 - TOAST_H;
+
+/// Frame buffer words covered by a toast (it spans whole lines)
+const SAVED_WORDS: usize = TOAST_H * blit::LCD_WORDS_PER_LINE;
+
+/// Region of the toast box
+const TOAST_CR: ClipRegion = ClipRegion {
+    x0: 20,
+    x1: views::SCREEN_W - 20,
+    y0: TOAST_Y0,
+    y1: TOAST_Y1,
+};
+
+/// Toast message, time left to show it, and the pixels that it covers
+pub struct Toast {
+    text: StrBuf<TOAST_TEXT_SIZE>,
+    ticks: usize,
+    shown: bool,
+    saved: [u32; SAVED_WORDS],
+}
+impl Toast {
+    /// Initialize with no toast showing
+    pub const fn new() -> Toast {
+        Toast {
+            text: StrBuf::new(""),
+            ticks: 0,
+            shown: false,
+            saved: [0; SAVED_WORDS],
+        }
+    }
+
+    /// Return true if a toast is waiting to be dismissed
+    pub fn is_active(&self) -> bool {
+        self.ticks > 0
+    }
+}
+
+/// Show a toast over the active view, replacing any toast that is showing.
+/// The toast appears on the next repaint.
+pub fn show(ctx: &mut state::Context, text: &str) {
+    ctx.toast.text.set(text);
+    ctx.toast.ticks = TOAST_TICKS;
+}
+
+/// Paint the active toast on top of whatever was just painted, saving the
+/// pixels underneath so they can be restored when it is dismissed. This
+/// runs at the end of every repaint.
+pub fn paint(fb: &mut FrameBuf, ctx: &mut state::Context) {
+    if !ctx.toast.is_active() {
+        return;
+    }
+    let words = TOAST_Y0 * blit::LCD_WORDS_PER_LINE..TOAST_Y1 * blit::LCD_WORDS_PER_LINE;
+    ctx.toast.saved.copy_from_slice(&fb.buf[words]);
+    ctx.toast.shown = true;
+    // Black box with clear text, so it stands out from the view underneath
+    blit::clear_region(&mut fb.buf, TOAST_CR);
+    let f = widgets::font();
+    let text = ctx.toast.text.as_str();
+    let w = blit::string_width(text, f);
+    let text_cr = ClipRegion {
+        x0: TOAST_CR.x0 + ((TOAST_CR.x1 - TOAST_CR.x0).saturating_sub(w) >> 1),
+        y0: TOAST_CR.y0 + ((TOAST_H - widgets::line_h()) >> 1),
+        ..TOAST_CR
+    };
+    blit::string_left(&mut fb.buf, text_cr, text, f);
+    if ctx.settings.theme == settings::Theme::Light {
+        blit::invert_region(&mut fb.buf, TOAST_CR);
+    }
+    fb.set_dirty();
+}
+
+/// Put back the pixels that the toast covered
+fn restore(fb: &mut FrameBuf, ctx: &mut state::Context) {
+    if !ctx.toast.shown {
+        return;
+    }
+    let words = TOAST_Y0 * blit::LCD_WORDS_PER_LINE..TOAST_Y1 * blit::LCD_WORDS_PER_LINE;
+    fb.buf[words].copy_from_slice(&ctx.toast.saved);
+    ctx.toast.shown = false;
+    fb.set_dirty();
+}
+
+/// Count down the active toast, dismissing it when its time is up
+pub fn tick(fb: &mut FrameBuf, ctx: &mut state::Context) {
+    if !ctx.toast.is_active() {
+        return;
+    }
+    ctx.toast.ticks -= 1;
+    if ctx.toast.ticks == 0 {
+        restore(fb, ctx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::api;
+    use super::*;
+
+    #[test]
+    fn toast_restores_covered_pixels() {
+        let mut fb = FrameBuf::new();
+        let mut ctx = state::Context::new();
+        api::repaint(&mut fb, &mut ctx);
+        let before = fb.buf;
+        show(&mut ctx, "Saved");
+        api::repaint(&mut fb, &mut ctx);
+        assert!(fb.buf[..] != before[..]);
+        for _ in 0..TOAST_TICKS - 1 {
+            tick(&mut fb, &mut ctx);
+        }
+        assert!(ctx.toast.is_active());
+        tick(&mut fb, &mut ctx);
+        assert!(!ctx.toast.is_active());
+        assert!(fb.buf[..] == before[..]);
+    }
+}
@@ -0,0 +1,81 @@
+//! Touch and pointer input: hit-test the on-screen keyboard and the active
+//! view's focusable widgets
+//!
+//! A touch on a keycap presses that key just like the physical key, so it
+//! types, repeats, and long-presses the same way, and the key shows as
+//! pressed until the touch ends or slides off it. A touch on a widget
+//! focuses it, and lifting the touch over the same widget activates it like
+//! Click, so sliding off a widget cancels.
+
+use super::api;
+use super::nav;
+use super::state::{self, FrameBuf};
+use super::views;
+
+/// What a touch started on
+#[derive(Copy, Clone, PartialEq)]
+pub enum Target {
+    /// Key index of a pressed on-screen key
+    Key(usize),
+    /// Focus ring index of a widget, and whether the touch is still on it
+    Widget(usize, bool),
+}
+
+/// Touch in progress
+pub struct TouchState {
+    pub target: Option<Target>,
+}
+impl TouchState {
+    /// Initialize with no touch in progress
+    pub const fn new() -> TouchState {
+        TouchState { target: None }
+    }
+}
+
+/// Start a touch at a screen position
+pub fn down(fb: &mut FrameBuf, ctx: &mut state::Context, x: usize, y: usize) {
+    // A touch that never ended (no up event) ends now
+    cancel(fb, ctx);
+    if let Some(key) = views::keyboard_key_at(x, y) {
+        ctx.touch.target = Some(Target::Key(key));
+        api::keydown(fb, ctx, key as u32);
+    } else if let Some(i) = ctx.focus.hit(x, y) {
+        ctx.touch.target = Some(Target::Widget(i, true));
+        ctx.views.set_focus(i);
+        api::repaint(fb, ctx);
+    }
+}
+
+/// Follow a touch as it moves. Sliding off a key releases it, and the touch
+/// does not press other keys it slides over.
+pub fn move_to(fb: &mut FrameBuf, ctx: &mut state::Context, x: usize, y: usize) {
+    match ctx.touch.target {
+        Some(Target::Key(key)) if views::keyboard_key_at(x, y) != Some(key) => cancel(fb, ctx),
+        Some(Target::Widget(i, _)) => {
+            let on = ctx.focus.hit(x, y) == Some(i);
+            ctx.touch.target = Some(Target::Widget(i, on));
+        }
+        _ => (),
+    }
+}
+
+/// End a touch at a screen position, releasing its key or activating its
+/// widget
+pub fn up(fb: &mut FrameBuf, ctx: &mut state::Context, x: usize, y: usize) {
+    move_to(fb, ctx, x, y);
+    match ctx.touch.target.take() {
+        Some(Target::Key(key)) => api::keyup(fb, ctx, key as u32),
+        Some(Target::Widget(i, true)) if (nav::active(ctx).activate)(ctx, i) => {
+            api::repaint(fb, ctx)
+        }
+        _ => (),
+    }
+}
+
+/// End a touch without activating anything (when the host cancels it, or a
+/// new touch starts before it ended)
+pub fn cancel(fb: &mut FrameBuf, ctx: &mut state::Context) {
+    if let Some(Target::Key(key)) = ctx.touch.target.take() {
+        api::keyup(fb, ctx, key as u32);
+    }
+}
@@ -0,0 +1,156 @@
+//! Bounded, allocation-free undo/redo history for the text editor
+
+/// Maximum number of edits remembered (oldest edits are dropped first)
+pub const UNDO_DEPTH: usize = 32;
+
+/// Maximum bytes of text held by one edit (longer runs split into more edits)
+pub const EDIT_MAX: usize = 32;
+
+/// Kinds of text edits
+#[derive(Copy, Clone, PartialEq)]
+pub enum EditKind {
+    Insert,
+    Backspace,
+    Delete,
+}
+
+/// One undoable edit: bytes inserted or removed at byte offset pos
+#[derive(Copy, Clone)]
+pub struct Edit {
+    pub kind: EditKind,
+    pub pos: usize,
+    len: usize,
+    bytes: [u8; EDIT_MAX],
+}
+impl Edit {
+    const EMPTY: Edit = Edit {
+        kind: EditKind::Insert,
+        pos: 0,
+        len: 0,
+        bytes: [0; EDIT_MAX],
+    };
+
+    /// Bytes of text that were inserted or removed
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes[0..self.len]
+    }
+
+    /// Try to extend this edit with an adjacent edit of the same kind.
+    /// Groups break where typing moves from whitespace into a new word, so
+    /// "hello world" undoes as "world" then "hello ".
+    /// Return: true if the edit was merged
+    fn merge(&mut self, kind: EditKind, pos: usize, bytes: &[u8]) -> bool {
+        if kind != self.kind || self.len + bytes.len() > EDIT_MAX || bytes.is_empty() {
+            return false;
+        }
+        let n = bytes.len();
+        match kind {
+            EditKind::Insert | EditKind::Delete => {
+                let adjacent = match kind {
+                    EditKind::Insert => pos == self.pos + self.len,
+                    _ => pos == self.pos,
+                };
+                if !adjacent || (is_space(self.bytes[self.len - 1]) && !is_space(bytes[0])) {
+                    return false;
+                }
+                self.bytes[self.len..self.len + n].copy_from_slice(bytes);
+            }
+            EditKind::Backspace => {
+                if pos + n != self.pos || (is_space(bytes[0]) && !is_space(self.bytes[0])) {
+                    return false;
+                }
+                self.bytes.copy_within(0..self.len, n);
+                self.bytes[0..n].copy_from_slice(bytes);
+                self.pos = pos;
+            }
+        }
+        self.len += n;
+        true
+    }
+}
+
+/// Undo/redo history kept in a ring buffer of edits. Entries before undo_len
+/// can be undone, and entries from undo_len to len can be redone.
+pub struct History {
+    edits: [Edit; UNDO_DEPTH],
+    first: usize,
+    len: usize,
+    undo_len: usize,
+    sealed: bool,
+}
+impl History {
+    /// Initialize an empty history
+    pub const fn new() -> History {
+        History {
+            edits: [Edit::EMPTY; UNDO_DEPTH],
+            first: 0,
+            len: 0,
+            undo_len: 0,
+            sealed: true,
+        }
+    }
+
+    /// Stop the current edit group so the next edit starts a new one (for
+    /// cursor movement and the like)
+    pub fn seal(&mut self) {
+        self.sealed = true;
+    }
+
+    /// Remember an edit, grouping it with the previous edit when they are
+    /// adjacent parts of the same word. This discards anything to redo.
+    pub fn record(&mut self, kind: EditKind, pos: usize, bytes: &[u8]) {
+        self.len = self.undo_len;
+        if !self.sealed && self.undo_len > 0 {
+            let top = self.ring_index(self.undo_len - 1);
+            if self.edits[top].merge(kind, pos, bytes) {
+                return;
+            }
+        }
+        if self.len == UNDO_DEPTH {
+            // Drop the oldest edit to make room
+            self.first = (self.first + 1) % UNDO_DEPTH;
+            self.len -= 1;
+        }
+        let mut edit = Edit {
+            kind,
+            pos,
+            len: bytes.len(),
+            bytes: [0; EDIT_MAX],
+        };
+        edit.bytes[0..bytes.len()].copy_from_slice(bytes);
+        let i = self.ring_index(self.len);
+        self.edits[i] = edit;
+        self.len += 1;
+        self.undo_len = self.len;
+        self.sealed = false;
+    }
+
+    /// Take the most recent edit to undo
+    pub fn undo(&mut self) -> Option<Edit> {
+        self.sealed = true;
+        if self.undo_len == 0 {
+            return None;
+        }
+        self.undo_len -= 1;
+        Some(self.edits[self.ring_index(self.undo_len)])
+    }
+
+    /// Take the most recently undone edit to redo
+    pub fn redo(&mut self) -> Option<Edit> {
+        self.sealed = true;
+        if self.undo_len == self.len {
+            return None;
+        }
+        self.undo_len += 1;
+        Some(self.edits[self.ring_index(self.undo_len - 1)])
+    }
+
+    fn ring_index(&self, i: usize) -> usize {
+        (self.first + i) % UNDO_DEPTH
+    }
+}
+
+/// Whitespace that separates words for grouping edits
+fn is_space(b: u8) -> bool {
+    b == b' ' || b == b'\n'
+}
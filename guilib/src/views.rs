@@ -1,5 +1,7 @@
 use super::fonts::{pua, Font};
-use super::{blit, fonts, kbd, state};
+use super::widgets::{self, Widget};
+use super::{blit, compose, dialog, fontcosts, fonts, kbd, modifiers, nav, settings, softkeys};
+use super::{state, symbols, textarea};
 
 /// Screen bounds
 pub const SCREEN_W: usize = blit::LCD_PX_PER_LINE;
@@ -17,48 +19,494 @@ pub const KBD_Y0: usize = SCREEN_H - KBD_H;
 pub const KBD_Y1: usize = SCREEN_H;
 
 /// Main content area height and Y bounds
-#[allow(dead_code)]
 pub const MAIN_H: usize = KBD_Y0 - STATUS_Y1;
 pub const MAIN_Y0: usize = STATUS_Y1;
 pub const MAIN_Y1: usize = KBD_Y0;
 
+/// Text area bounds within the main content box, leaving room at the
+/// right for the scroll indicator
+pub const TEXT_AREA: blit::ClipRegion = blit::ClipRegion {
+    x0: 5,
+    x1: SCREEN_W - 5 - SCROLL_W - 2,
+    y0: MAIN_Y0 + 5,
+    y1: MAIN_Y1 - 5,
+};
+
+/// Width of the text area scroll indicator
+const SCROLL_W: usize = 3;
+
+/// Width of the text editing caret
+const CARET_W: usize = 2;
+
 /// Home screen with status bar, main content box, and keyboard
 pub fn home_screen(fb: &mut state::FrameBuf, ctx: &mut state::Context) {
-    // Status bar: view title, battery level icon, wifi strength icon, clock
-    let mut cr = blit::ClipRegion {
-        x0: 0,
-        x1: SCREEN_W,
-        y0: STATUS_Y0,
-        y1: STATUS_Y1,
+    status_bar(fb, ctx);
+    // Main content area: 2px clear pad, 1px black border, clear fill, text area
+    let yr = blit::YRegion(MAIN_Y0, MAIN_Y1);
+    blit::outline_region(&mut fb.buf, yr);
+    text_area(fb, ctx, TEXT_AREA);
+    // Onscreen keyboard
+    keyboard(fb, ctx, blit::YRegion(KBD_Y0, KBD_Y1));
+    fb.set_dirty();
+}
+
+/// Multi-line text area: wrapped text scrolled to keep the caret visible,
+/// with a scroll indicator at the right when the text does not fit
+fn text_area(fb: &mut state::FrameBuf, ctx: &mut state::Context, cr: blit::ClipRegion) {
+    textarea::scroll_to_cursor(ctx, cr);
+    let f = textarea::font(ctx);
+    let scale = settings::text_style(ctx).1;
+    let line_h = textarea::line_h(ctx);
+    let s = ctx.text.as_str();
+    let cursor = ctx.text.cursor();
+    let width = textarea::wrap_width(ctx, cr);
+    let visible = (cr.y1 - cr.y0) / line_h;
+    let top = ctx.text_area.top_line;
+    let (cursor_n, _) = textarea::cursor_line(s, cursor, width, f);
+    if s.is_empty() {
+        let hint = blit::ClipRegion {
+            x0: cr.x0 + CARET_W,
+            ..cr
+        };
+        blit::string_small_left(&mut fb.buf, hint, ctx.note.as_str());
+    }
+    let mut total = 0;
+    for (n, line) in textarea::lines(s, width, f).enumerate() {
+        total += 1;
+        if n < top || n >= top + visible {
+            continue;
+        }
+        let mut lcr = blit::ClipRegion {
+            y0: cr.y0 + (n - top) * line_h,
+            ..cr
+        };
+        lcr.y1 = lcr.y0 + line_h;
+        blit::string_scaled_left(&mut fb.buf, lcr, &s[line.start..line.end], f, scale);
+        if n == cursor_n {
+            // Caret straddles the padding between the previous and next chars
+            let x = lcr.x0 + textarea::advance(&s[line.start..cursor], f) * scale;
+            let caret = blit::ClipRegion {
+                x0: x.saturating_sub(CARET_W / 2),
+                x1: x.saturating_sub(CARET_W / 2) + CARET_W,
+                ..lcr
+            };
+            blit::invert_region(&mut fb.buf, caret);
+        }
+    }
+    // Scroll indicator: thumb spans the visible fraction of the lines
+    if total > visible {
+        let track_h = cr.y1 - cr.y0;
+        let thumb_h = core::cmp::max(track_h * visible / total, 4);
+        let thumb_y0 = cr.y0 + (track_h - thumb_h) * top / (total - visible);
+        let thumb = blit::ClipRegion {
+            x0: SCREEN_W - 5 - SCROLL_W,
+            x1: SCREEN_W - 5,
+            y0: thumb_y0,
+            y1: thumb_y0 + thumb_h,
+        };
+        blit::invert_region(&mut fb.buf, thumb);
+    }
+}
+
+/// Symbol picker cell size
+const SYMBOL_CELL_W: usize = 40;
+const SYMBOL_CELL_H: usize = 40;
+
+/// Symbol picker with status bar, grid of symbols, and keyboard
+pub fn symbol_picker(fb: &mut state::FrameBuf, ctx: &mut state::Context) {
+    status_bar(fb, ctx);
+    // Main content area: outline box with grid of symbols centered inside
+    let yr = blit::YRegion(MAIN_Y0, MAIN_Y1);
+    blit::outline_region(&mut fb.buf, yr);
+    let grid_x0 = (SCREEN_W - symbols::COLS * SYMBOL_CELL_W) >> 1;
+    let grid_y0 = yr.0 + ((MAIN_H - symbols::ROWS * SYMBOL_CELL_H) >> 1);
+    let f = Font::new(fonts::GlyphSet::Bold);
+    let glyph_h = fonts::bold::MAX_HEIGHT as usize;
+    for (i, c) in symbols::SYMBOLS.iter().enumerate() {
+        let cell = blit::ClipRegion {
+            x0: grid_x0 + (i % symbols::COLS) * SYMBOL_CELL_W,
+            x1: grid_x0 + (i % symbols::COLS + 1) * SYMBOL_CELL_W,
+            y0: grid_y0 + (i / symbols::COLS) * SYMBOL_CELL_H,
+            y1: grid_y0 + (i / symbols::COLS + 1) * SYMBOL_CELL_H,
+        };
+        let w = blit::char_width(*c, f);
+        let cr = blit::ClipRegion {
+            x0: cell.x0 + ((SYMBOL_CELL_W - w) >> 1),
+            x1: cell.x1,
+            y0: cell.y0 + ((SYMBOL_CELL_H - glyph_h) >> 1),
+            y1: cell.y1,
+        };
+        blit::xor_char(&mut fb.buf, cr, *c, f);
+        if i == ctx.symbol_index {
+            blit::invert_region(&mut fb.buf, cell);
+        }
+    }
+    // Onscreen keyboard
+    keyboard(fb, ctx, blit::YRegion(KBD_Y0, KBD_Y1));
+    fb.set_dirty();
+}
+
+/// Settings with status bar, radio groups and checkboxes, and keyboard
+pub fn settings(fb: &mut state::FrameBuf, ctx: &mut state::Context) {
+    status_bar(fb, ctx);
+    let yr = blit::YRegion(MAIN_Y0, MAIN_Y1);
+    blit::outline_region(&mut fb.buf, yr);
+    let cr = blit::ClipRegion {
+        x0: 10,
+        x1: SCREEN_W - 10,
+        y0: MAIN_Y0 + 6,
+        y1: MAIN_Y1 - 6,
+    };
+    let mut stack = widgets::Stack::vertical(cr, 4);
+    let heading = |text| widgets::Label {
+        text,
+        font: fonts::GlyphSet::Small,
+    };
+    let layout = widgets::RadioGroup {
+        options: &settings::LAYOUT_OPTIONS,
+        selected: settings::selected(ctx, settings::Field::Layout),
+        columns: 2,
+    };
+    let text_size = widgets::RadioGroup {
+        options: &settings::TEXT_SIZE_OPTIONS,
+        selected: settings::selected(ctx, settings::Field::TextSize),
+        columns: 3,
+    };
+    let theme = widgets::Checkbox {
+        label: "Dark theme",
+        checked: settings::selected(ctx, settings::Field::Theme) == 1,
+    };
+    let clock = widgets::Checkbox {
+        label: "12 hour clock",
+        checked: settings::selected(ctx, settings::Field::Clock) == 1,
+    };
+    // Focusable widgets go in settings::FIELDS order
+    stack.add(&mut fb.buf, &heading("Keyboard layout"));
+    ctx.focus.add(stack.add(&mut fb.buf, &layout));
+    stack.add(&mut fb.buf, &heading("Text size"));
+    ctx.focus.add(stack.add(&mut fb.buf, &text_size));
+    stack.add(&mut fb.buf, &widgets::Separator);
+    ctx.focus.add(stack.add(&mut fb.buf, &theme));
+    ctx.focus.add(stack.add(&mut fb.buf, &clock));
+    keyboard(fb, ctx, blit::YRegion(KBD_Y0, KBD_Y1));
+    fb.set_dirty();
+}
+
+/// Font costs with status bar, font list, and keyboard. Each Unicode block
+/// of the selected font gets a row with its size in bytes and a bar for its
+/// share of the font.
+pub fn font_costs(fb: &mut state::FrameBuf, ctx: &mut state::Context) {
+    status_bar(fb, ctx);
+    let yr = blit::YRegion(MAIN_Y0, MAIN_Y1);
+    blit::outline_region(&mut fb.buf, yr);
+    let cr = blit::ClipRegion {
+        x0: 10,
+        x1: SCREEN_W - 10,
+        y0: MAIN_Y0 + 6,
+        y1: MAIN_Y1 - 6,
+    };
+    let mut stack = widgets::Stack::vertical(cr, 4);
+    stack.add(&mut fb.buf, &ctx.font_list);
+    stack.add(&mut fb.buf, &widgets::Separator);
+    let font = fontcosts::selected(ctx);
+    let label = |text| widgets::Label {
+        text,
+        font: fonts::GlyphSet::Small,
+    };
+    let name_w = font.blocks.iter().map(|b| label(b.block).width()).max();
+    let mono = Font::new(fonts::GlyphSet::Mono);
+    let bytes_w = blit::string_width("0", mono) * fontcosts::BYTES_W + 1;
+    let row_h = core::cmp::max(widgets::line_h(), fonts::max_height(fonts::GlyphSet::Mono));
+    // Show as many blocks as fit below the font list
+    let mut rows = widgets::Stack::vertical(stack.remaining(), 2);
+    for block in font.blocks.iter() {
+        let space = rows.remaining();
+        if space.y1 - space.y0 < row_h {
+            break;
+        }
+        let bytes = match font.enabled && block.enabled {
+            true => Some(block.bytes),
+            false => None,
+        };
+        let mut row = widgets::Stack::horizontal(rows.take(row_h), widgets::PAD);
+        let name = row.take(name_w.unwrap_or(0));
+        label(block.block).paint(&mut fb.buf, name);
+        let digits = row.take(bytes_w);
+        blit::clear_region(&mut fb.buf, digits);
+        let mut buf = [0; fontcosts::BYTES_W];
+        let text = fontcosts::format_bytes(bytes, &mut buf);
+        blit::string_mono_left(&mut fb.buf, digits, text);
+        // Center the bar on the row
+        let mut bar = row.remaining();
+        bar.y0 += (row_h - widgets::PROGRESS_H) >> 1;
+        bar.y1 = bar.y0 + widgets::PROGRESS_H;
+        let progress = widgets::ProgressBar {
+            value: bytes.unwrap_or(0),
+            max: font.bytes(),
+        };
+        progress.paint(&mut fb.buf, bar);
+    }
+    keyboard(fb, ctx, blit::YRegion(KBD_Y0, KBD_Y1));
+    fb.set_dirty();
+}
+
+/// Dialog box width and padding
+const DIALOG_W: usize = SCREEN_W - 40;
+const DIALOG_PAD: usize = 8;
+
+/// Dialog box centered over the view it was opened from: bold title,
+/// wrapped message, and a row of buttons
+pub fn dialog(fb: &mut state::FrameBuf, ctx: &mut state::Context) {
+    (nav::handler(ctx.views.below()).paint)(fb, ctx);
+    ctx.focus.clear();
+    let d = match ctx.dialog.dialog {
+        Some(d) => d,
+        None => return,
     };
+    let f = Font::new(fonts::GlyphSet::Regular);
+    let line_h = widgets::line_h();
+    let text_w = DIALOG_W - 2 * DIALOG_PAD;
+    let lines = textarea::lines(d.message, text_w, f).count();
+    let button_h = (widgets::Button {
+        label: "",
+        pressed: false,
+    })
+    .height();
+    let h = 2 * DIALOG_PAD + STATUS_H + lines * line_h + DIALOG_PAD + button_h;
+    let x0 = (SCREEN_W - DIALOG_W) >> 1;
+    let y0 = MAIN_Y0 + ((MAIN_H.saturating_sub(h)) >> 1);
+    let frame = blit::ClipRegion {
+        x0,
+        x1: x0 + DIALOG_W,
+        y0,
+        y1: core::cmp::min(y0 + h, MAIN_Y1),
+    };
+    // Double border to set the dialog apart from the view underneath
+    blit::clear_region(&mut fb.buf, frame);
+    widgets::outline(&mut fb.buf, frame);
+    let inner = blit::ClipRegion {
+        x0: frame.x0 + 2,
+        x1: frame.x1 - 2,
+        y0: frame.y0 + 2,
+        y1: frame.y1 - 2,
+    };
+    widgets::outline(&mut fb.buf, inner);
+    let content = blit::ClipRegion {
+        x0: frame.x0 + DIALOG_PAD,
+        x1: frame.x1 - DIALOG_PAD,
+        y0: frame.y0 + DIALOG_PAD,
+        y1: frame.y1 - DIALOG_PAD,
+    };
+    let mut stack = widgets::Stack::vertical(content, 0);
+    let title_cr = stack.take(STATUS_H);
+    blit::string_bold_left(&mut fb.buf, title_cr, d.title);
+    for line in textarea::lines(d.message, text_w, f) {
+        let line_cr = stack.take(line_h);
+        blit::string_left(&mut fb.buf, line_cr, &d.message[line.start..line.end], f);
+    }
+    stack.take(DIALOG_PAD);
+    // Buttons share the row equally, in soft key order
+    let n = dialog::buttons(d).count();
+    let row = stack.take(button_h);
+    let gap = DIALOG_PAD;
+    let button_w = (row.x1 - row.x0 - gap * n.saturating_sub(1)) / core::cmp::max(n, 1);
+    let mut buttons = widgets::Stack::horizontal(row, gap);
+    for key in dialog::buttons(d) {
+        let button = widgets::Button {
+            label: d.buttons[key].label,
+            pressed: false,
+        };
+        let cr = buttons.take(button_w);
+        button.paint(&mut fb.buf, cr);
+        ctx.focus.add(cr);
+    }
+    fb.set_dirty();
+}
+
+/// Invert the whole screen for the dark theme
+pub fn apply_theme(fb: &mut state::FrameBuf, ctx: &state::Context) {
+    if ctx.settings.theme == settings::Theme::Dark {
+        let screen = blit::ClipRegion {
+            x0: 0,
+            x1: SCREEN_W,
+            y0: 0,
+            y1: SCREEN_H,
+        };
+        blit::invert_region(&mut fb.buf, screen);
+        fb.set_dirty();
+    }
+}
+
+/// Width of a soft key popup menu
+const MENU_W: usize = 180;
+
+/// Key indexes of the F1-F4 soft keys, for placing popup menus
+const SOFT_KEY_INDEX: [usize; softkeys::SOFT_KEY_COUNT] = [4, 5, 7, 8];
+
+/// Popup menu over the view it was opened from, just above its soft key
+pub fn menu(fb: &mut state::FrameBuf, ctx: &mut state::Context) {
+    (nav::handler(ctx.views.below()).paint)(fb, ctx);
+    // The view underneath is not interactive while the menu is open
+    ctx.focus.clear();
+    let menu = match ctx.menu.menu {
+        Some(menu) => menu,
+        None => return,
+    };
+    let mut labels = [""; softkeys::MENU_MAX];
+    for (label, item) in labels.iter_mut().zip(menu.items.iter()) {
+        *label = item.label;
+    }
+    let count = core::cmp::min(menu.items.len(), softkeys::MENU_MAX);
+    let list = widgets::List {
+        items: &labels[0..count],
+        selected: ctx.menu.selected,
+        top: 0,
+        rows: count,
+    };
+    let key_x0 = match KEY_LABEL_CR_LUT[SOFT_KEY_INDEX[ctx.menu.key]] {
+        KeyL::CR(key_cr) => key_cr.x0,
+        KeyL::None => 0,
+    };
+    let x0 = core::cmp::min(key_x0 + 2, SCREEN_W - 2 - MENU_W);
+    let popup = blit::ClipRegion {
+        x0,
+        x1: x0 + MENU_W,
+        y0: MAIN_Y1 - 2 - list.height() - 2 * (widgets::PAD + 1),
+        y1: MAIN_Y1 - 2,
+    };
+    blit::clear_region(&mut fb.buf, popup);
+    widgets::outline(&mut fb.buf, popup);
+    let inner = blit::ClipRegion {
+        x0: popup.x0 + 1,
+        x1: popup.x1 - 1,
+        y0: popup.y0 + widgets::PAD + 1,
+        y1: popup.y1 - widgets::PAD - 1,
+    };
+    list.paint(&mut fb.buf, inner);
+    fb.set_dirty();
+}
+
+/// Width and height of a cell in the alternates strip
+const ALTERNATE_W: usize = KBD_KEY_H;
+const ALTERNATE_H: usize = KBD_KEY_H;
+
+/// Strip of alternate characters over the home screen, just above the
+/// long-pressed key, with the selected one inverted
+pub fn alternates(fb: &mut state::FrameBuf, ctx: &mut state::Context) {
+    (nav::handler(ctx.views.below()).paint)(fb, ctx);
+    ctx.focus.clear();
+    let key_cr = match KEY_LABEL_CR_LUT.get(ctx.alternates.key) {
+        Some(KeyL::CR(key_cr)) => *key_cr,
+        _ => return,
+    };
+    let chars = ctx.alternates.chars;
+    let w = chars.len() * ALTERNATE_W + 2;
+    let key_mid = (key_cr.x0 + key_cr.x1) >> 1;
+    let x0 = core::cmp::min(key_mid.saturating_sub(w >> 1), SCREEN_W - 2 - w);
+    let y1 = KBD_Y0 + key_cr.y0 - 2;
+    let strip = blit::ClipRegion {
+        x0,
+        x1: x0 + w,
+        y0: y1 - ALTERNATE_H - 2,
+        y1,
+    };
+    blit::clear_region(&mut fb.buf, strip);
+    widgets::outline(&mut fb.buf, strip);
+    let f = Font::new(fonts::GlyphSet::Regular);
+    for (i, c) in chars.iter().enumerate() {
+        let cell = blit::ClipRegion {
+            x0: strip.x0 + 1 + i * ALTERNATE_W,
+            x1: strip.x0 + 1 + (i + 1) * ALTERNATE_W,
+            y0: strip.y0 + 1,
+            y1: strip.y1 - 1,
+        };
+        let cw = blit::char_width(*c, f);
+        let label_cr = blit::ClipRegion {
+            x0: cell.x0 + ((ALTERNATE_W - cw) >> 1),
+            y0: cell.y0 + 2,
+            ..cell
+        };
+        blit::xor_char(&mut fb.buf, label_cr, *c, f);
+        if i == ctx.alternates.selected {
+            blit::invert_region(&mut fb.buf, cell);
+        }
+    }
+    fb.set_dirty();
+}
+
+/// Status bar bounds
+const STATUS_CR: blit::ClipRegion = blit::ClipRegion {
+    x0: 0,
+    x1: SCREEN_W,
+    y0: STATUS_Y0,
+    y1: STATUS_Y1,
+};
+
+/// Lightning bolt shown left of the battery icon while charging (rows of
+/// pixels, LSB is leftmost)
+const CHARGING_BOLT: [u8; 13] = [
+    0x30, 0x18, 0x18, 0x0c, 0x0c, 0x06, 0x7f, 0x30, 0x18, 0x18, 0x0c, 0x0c, 0x06,
+];
+
+/// Repaint only the status bar (for updates to the status fields)
+pub fn status_bar_repaint(fb: &mut state::FrameBuf, ctx: &state::Context) {
+    status_bar(fb, ctx);
+    if ctx.settings.theme == settings::Theme::Dark {
+        blit::invert_region(&mut fb.buf, STATUS_CR);
+    }
+    fb.set_dirty();
+}
+
+/// Status bar: active view title, battery level icon (with a bolt while
+/// charging), wifi strength icon, clock
+fn status_bar(fb: &mut state::FrameBuf, ctx: &state::Context) {
+    let title = (nav::active(ctx).title)(ctx);
+    let mut cr = STATUS_CR;
     blit::clear_region(&mut fb.buf, cr);
     cr.x0 = 4;
-    blit::string_bold_left(&mut fb.buf, cr, ctx.status_title);
+    blit::string_bold_left(&mut fb.buf, cr, title);
+    // Pending dead key accent or Compose sequence, inverted after the title
+    let mut buf = [0; 12];
+    let pending = compose::pending_text(ctx, &mut buf);
+    if !pending.is_empty() {
+        let bold = Font::new(fonts::GlyphSet::Bold);
+        let x0 = cr.x0 + blit::string_width(title, bold) + 6;
+        let pending_cr = blit::ClipRegion { x0: x0 + 3, ..cr };
+        blit::string_bold_left(&mut fb.buf, pending_cr, pending);
+        let w = blit::string_width(pending, bold);
+        let box_cr = blit::ClipRegion {
+            x0,
+            x1: x0 + w + 6,
+            ..cr
+        };
+        blit::invert_region(&mut fb.buf, box_cr);
+    }
     cr.x0 = 33 * 6 - 6;
     blit::string_bold_left(&mut fb.buf, cr, ctx.battery_icon());
+    if ctx.status_charging {
+        let y0 = STATUS_Y0 + ((STATUS_H - CHARGING_BOLT.len()) >> 1);
+        for (y, row) in CHARGING_BOLT.iter().enumerate() {
+            for x in 0..8 {
+                if (row >> x) & 1 == 1 {
+                    let px = blit::ClipRegion {
+                        x0: cr.x0 - 9 + x,
+                        x1: cr.x0 - 8 + x,
+                        y0: y0 + y,
+                        y1: y0 + y + 1,
+                    };
+                    blit::invert_region(&mut fb.buf, px);
+                }
+            }
+        }
+    }
     cr.x0 = 33 * 7 - 3;
     blit::string_bold_left(&mut fb.buf, cr, ctx.radio_icon());
-    cr.x0 = 33 * 8 - 2;
-    blit::string_bold_left(&mut fb.buf, cr, ctx.status_time);
-    // Main content area: 2px clear pad, 1px black border, clear fill, note in center
-    let yr = blit::YRegion(MAIN_Y0, MAIN_Y1);
-    blit::outline_region(&mut fb.buf, yr);
-    let mut cr = blit::ClipRegion {
-        x0: 5,
-        x1: SCREEN_W,
-        y0: yr.0 + 5,
-        y1: MAIN_Y1,
-    };
-    blit::string_bold_left(&mut fb.buf, cr, ctx.note);
-    cr.y0 += fonts::bold::MAX_HEIGHT as usize;
-    blit::string_regular_left(&mut fb.buf, cr, ctx.note);
-    cr.y0 += fonts::regular::MAX_HEIGHT as usize;
-    blit::string_small_left(&mut fb.buf, cr, ctx.note);
-    cr.y0 += fonts::small::MAX_HEIGHT as usize * 2;
-    blit::string_regular_left(&mut fb.buf, cr, ctx.buffer());
-    // Onscreen keyboard
-    keyboard(fb, ctx, blit::YRegion(KBD_Y0, KBD_Y1));
-    fb.set_dirty();
+    let mut buf = [0; 8];
+    let time = settings::format_clock(ctx, ctx.status_time.as_str(), &mut buf);
+    // Shift longer 12 hour times left so they fit
+    let time_w = blit::string_width(time, Font::new(fonts::GlyphSet::Bold));
+    cr.x0 = core::cmp::min(33 * 8 - 2, SCREEN_W - 2 - time_w);
+    blit::string_bold_left(&mut fb.buf, cr, time);
 }
 
 /// Fill a full width screen region bounded by y0..y1 with a blank keyboard
@@ -125,22 +573,28 @@ fn keyboard_key_caps(fb: &mut state::FrameBuf, ctx: &mut state::Context, yr: bli
             cr.y0 = y0 + key_cr.y0;
             // And the current key map gives a label for this key
             // ...then blit the label
-            if let kbd::R::C(c) = lut[i] {
-                let w = blit::char_width(c, f);
+            let r = &lut[i];
+            if let kbd::R::C(c) | kbd::R::Dead(c) = r {
+                let w = blit::char_width(*c, f);
                 cr.x0 = key_cr.x0 + ((key_cr.x1 - key_cr.x0) >> 1) - (w >> 1);
-                blit::xor_char(&mut fb.buf, cr, c, f);
+                blit::xor_char(&mut fb.buf, cr, *c, f);
             } else {
-                let label = match lut[i] {
-                    kbd::R::Shift => &"shift",
-                    kbd::R::AltL | kbd::R::AltR => pua::SHIFT_ARROW,
-                    kbd::R::Enter => pua::ENTER_SYMBOL,
-                    kbd::R::Bksp => pua::BACKSPACE_SYMBOL,
-                    _ => &"",
+                // F-keys are labeled by the active view's soft keys
+                let label = match softkeys::label(ctx, r) {
+                    Some(label) => label,
+                    None => match r {
+                        kbd::R::Shift => modifiers::shift_label(ctx),
+                        kbd::R::AltL | kbd::R::AltR => pua::SHIFT_ARROW,
+                        kbd::R::Enter => pua::ENTER_SYMBOL,
+                        kbd::R::Bksp => pua::BACKSPACE_SYMBOL,
+                        kbd::R::Compose => "•",
+                        _ => "",
+                    },
                 };
-                let w = blit::string_width(&label, f);
+                let w = blit::string_width(label, f);
                 cr.x0 = key_cr.x0 + ((key_cr.x1 - key_cr.x0) >> 1) - (w >> 1);
                 cr.y0 = y0 + key_cr.y0;
-                blit::string_regular_left(&mut fb.buf, cr, &label);
+                blit::string_regular_left(&mut fb.buf, cr, label);
             }
         }
     }
@@ -163,6 +617,19 @@ pub fn keyboard_invert_key(fb: &mut state::FrameBuf, key_index: usize) {
     fb.set_dirty();
 }
 
+/// Find the on-screen key at a screen position (keys without a keycap on
+/// screen, like the arrow keys, cannot be touched)
+pub fn keyboard_key_at(x: usize, y: usize) -> Option<usize> {
+    if y < KBD_Y0 {
+        return None;
+    }
+    let y = y - KBD_Y0;
+    KEY_LABEL_CR_LUT.iter().position(|k| match k {
+        KeyL::CR(cr) => x >= cr.x0 && x < cr.x1 && y >= cr.y0 && y < cr.y1,
+        KeyL::None => false,
+    })
+}
+
 /// Draw test patern of stripes
 #[allow(dead_code)]
 pub fn stripes(fb: &mut state::FrameBuf) {
@@ -0,0 +1,497 @@
+//! Retained widgets built on blit, and a layout helper for stacking them
+//! inside a ClipRegion
+
+use super::blit::{self, ClipRegion, LcdFB};
+use super::fonts::{self, Font, GlyphSet};
+
+/// Padding between a widget's border or marker and its text
+pub const PAD: usize = 4;
+
+/// Size of checkbox and radio button markers
+pub const MARKER_SIZE: usize = 16;
+
+/// Height of a progress bar
+pub const PROGRESS_H: usize = 14;
+
+/// Height of a separator (1px line with space above and below)
+pub const SEPARATOR_H: usize = 9;
+
+/// Common behavior of widgets
+pub trait Widget {
+    /// Natural width in pixels (0 to fill the available space)
+    fn width(&self) -> usize;
+    /// Natural height in pixels
+    fn height(&self) -> usize;
+    /// Clear the region and paint the widget into it
+    fn paint(&self, fb: &mut LcdFB, cr: ClipRegion);
+}
+
+/// Directions for stacking widgets
+#[derive(Copy, Clone)]
+pub enum Direction {
+    Vertical,
+    Horizontal,
+}
+
+/// Layout helper that hands out consecutive regions of a ClipRegion, top to
+/// bottom or left to right, with a gap between them
+pub struct Stack {
+    cr: ClipRegion,
+    dir: Direction,
+    gap: usize,
+}
+impl Stack {
+    /// Stack regions top to bottom
+    pub fn vertical(cr: ClipRegion, gap: usize) -> Stack {
+        Stack {
+            cr,
+            dir: Direction::Vertical,
+            gap,
+        }
+    }
+
+    /// Stack regions left to right
+    pub fn horizontal(cr: ClipRegion, gap: usize) -> Stack {
+        Stack {
+            cr,
+            dir: Direction::Horizontal,
+            gap,
+        }
+    }
+
+    /// Take the next region of size px along the stacking direction (clamped
+    /// to the space that is left), spanning the full cross direction
+    pub fn take(&mut self, size: usize) -> ClipRegion {
+        let mut region = self.cr;
+        match self.dir {
+            Direction::Vertical => {
+                region.y1 = core::cmp::min(self.cr.y0 + size, self.cr.y1);
+                self.cr.y0 = core::cmp::min(region.y1 + self.gap, self.cr.y1);
+            }
+            Direction::Horizontal => {
+                region.x1 = core::cmp::min(self.cr.x0 + size, self.cr.x1);
+                self.cr.x0 = core::cmp::min(region.x1 + self.gap, self.cr.x1);
+            }
+        }
+        region
+    }
+
+    /// Region that has not been taken yet
+    pub fn remaining(&self) -> ClipRegion {
+        self.cr
+    }
+
+    /// Take a region sized for a widget and paint the widget into it. Widgets
+    /// with a natural width of 0 fill the rest of a horizontal stack.
+    /// Return: region the widget was painted into
+    pub fn add(&mut self, fb: &mut LcdFB, w: &dyn Widget) -> ClipRegion {
+        let size = match self.dir {
+            Direction::Vertical => w.height(),
+            Direction::Horizontal => match w.width() {
+                0 => self.cr.x1 - self.cr.x0,
+                width => width,
+            },
+        };
+        let region = self.take(size);
+        w.paint(fb, region);
+        region
+    }
+}
+
+/// Single line of text
+pub struct Label<'a> {
+    pub text: &'a str,
+    pub font: GlyphSet,
+}
+impl Widget for Label<'_> {
+    fn width(&self) -> usize {
+        blit::string_width(self.text, Font::new(self.font))
+    }
+
+    fn height(&self) -> usize {
+        fonts::max_height(self.font)
+    }
+
+    fn paint(&self, fb: &mut LcdFB, cr: ClipRegion) {
+        blit::clear_region(fb, cr);
+        blit::string_left(fb, cr, self.text, Font::new(self.font));
+    }
+}
+
+/// Bordered button with a centered label, inverted while pressed
+pub struct Button<'a> {
+    pub label: &'a str,
+    pub pressed: bool,
+}
+impl Widget for Button<'_> {
+    fn width(&self) -> usize {
+        blit::string_width(self.label, font()) + 2 * (PAD + 1)
+    }
+
+    fn height(&self) -> usize {
+        line_h() + 2 * (PAD + 1)
+    }
+
+    fn paint(&self, fb: &mut LcdFB, cr: ClipRegion) {
+        blit::clear_region(fb, cr);
+        outline(fb, cr);
+        let text_w = blit::string_width(self.label, font());
+        let text_cr = ClipRegion {
+            x0: cr.x0 + ((cr.x1 - cr.x0).saturating_sub(text_w) >> 1),
+            y0: cr.y0 + PAD + 1,
+            ..cr
+        };
+        blit::string_left(fb, text_cr, self.label, font());
+        if self.pressed {
+            blit::invert_region(fb, inset(cr, 1));
+        }
+    }
+}
+
+/// Checkbox marker followed by a label
+pub struct Checkbox<'a> {
+    pub label: &'a str,
+    pub checked: bool,
+}
+impl Widget for Checkbox<'_> {
+    fn width(&self) -> usize {
+        MARKER_SIZE + PAD + blit::string_width(self.label, font())
+    }
+
+    fn height(&self) -> usize {
+        line_h()
+    }
+
+    fn paint(&self, fb: &mut LcdFB, cr: ClipRegion) {
+        blit::clear_region(fb, cr);
+        let marker = marker_region(cr);
+        outline(fb, marker);
+        if self.checked {
+            blit::invert_region(fb, inset(marker, 4));
+        }
+        label_after_marker(fb, cr, self.label);
+    }
+}
+
+/// Group of options where exactly one is selected
+pub struct RadioGroup<'a> {
+    pub options: &'a [&'a str],
+    pub selected: usize,
+    /// Number of columns to arrange the options in (row by row)
+    pub columns: usize,
+}
+impl Widget for RadioGroup<'_> {
+    fn width(&self) -> usize {
+        let widths = self.options.iter().map(|s| blit::string_width(s, font()));
+        let column_w = MARKER_SIZE + PAD + widths.max().unwrap_or(0);
+        let columns = self.columns.max(1);
+        columns * column_w + (columns - 1) * PAD
+    }
+
+    fn height(&self) -> usize {
+        let columns = self.columns.max(1);
+        self.options.len().div_ceil(columns) * line_h()
+    }
+
+    fn paint(&self, fb: &mut LcdFB, cr: ClipRegion) {
+        blit::clear_region(fb, cr);
+        let columns = self.columns.max(1);
+        let column_w = (cr.x1 - cr.x0) / columns;
+        let mut rows = Stack::vertical(cr, 0);
+        for (n, options) in self.options.chunks(columns).enumerate() {
+            let row = rows.take(line_h());
+            for (c, option) in options.iter().enumerate() {
+                let x0 = row.x0 + c * column_w;
+                let cell = ClipRegion {
+                    x0,
+                    x1: x0 + column_w,
+                    ..row
+                };
+                let marker = marker_region(cell);
+                let r = MARKER_SIZE / 2;
+                let (cx, cy) = (marker.x0 + r, marker.y0 + r);
+                // Ring from XOR of two disks, plus a dot when selected
+                xor_disk(fb, cell, cx, cy, r);
+                xor_disk(fb, cell, cx, cy, r - 1);
+                if n * columns + c == self.selected {
+                    xor_disk(fb, cell, cx, cy, r - 4);
+                }
+                label_after_marker(fb, cell, option);
+            }
+        }
+    }
+}
+
+/// Single-select list that scrolls to keep the selected item visible
+pub struct List<'a> {
+    pub items: &'a [&'a str],
+    pub selected: usize,
+    /// Index of the first visible item
+    pub top: usize,
+    /// Number of rows to show
+    pub rows: usize,
+}
+impl List<'_> {
+    /// Select the next item (stops at the end), scrolling down if it was
+    /// below the last visible row
+    pub fn select_next(&mut self) -> bool {
+        if self.selected + 1 >= self.items.len() {
+            return false;
+        }
+        self.selected += 1;
+        if self.selected >= self.top + self.rows {
+            self.top = self.selected + 1 - self.rows;
+        }
+        true
+    }
+
+    /// Select the previous item (stops at the start), scrolling up if it
+    /// was above the first visible row
+    pub fn select_prev(&mut self) -> bool {
+        if self.selected == 0 {
+            return false;
+        }
+        self.selected -= 1;
+        self.top = core::cmp::min(self.top, self.selected);
+        true
+    }
+}
+impl Widget for List<'_> {
+    fn width(&self) -> usize {
+        0
+    }
+
+    fn height(&self) -> usize {
+        self.rows * line_h()
+    }
+
+    fn paint(&self, fb: &mut LcdFB, cr: ClipRegion) {
+        blit::clear_region(fb, cr);
+        let mut rows = Stack::vertical(cr, 0);
+        let visible = self.items.iter().enumerate().skip(self.top);
+        for (i, item) in visible.take(self.rows) {
+            let row = rows.take(line_h());
+            let text_cr = ClipRegion {
+                x0: row.x0 + PAD,
+                ..row
+            };
+            blit::string_left(fb, text_cr, item, font());
+            if i == self.selected {
+                blit::invert_region(fb, row);
+            }
+        }
+    }
+}
+
+/// Horizontal bar that fills in proportion to value / max
+pub struct ProgressBar {
+    pub value: usize,
+    pub max: usize,
+}
+impl Widget for ProgressBar {
+    fn width(&self) -> usize {
+        0
+    }
+
+    fn height(&self) -> usize {
+        PROGRESS_H
+    }
+
+    fn paint(&self, fb: &mut LcdFB, cr: ClipRegion) {
+        blit::clear_region(fb, cr);
+        outline(fb, cr);
+        let mut fill = inset(cr, 2);
+        if self.max == 0 || fill.x0 >= fill.x1 {
+            return;
+        }
+        let value = core::cmp::min(self.value, self.max);
+        fill.x1 = fill.x0 + (fill.x1 - fill.x0) * value / self.max;
+        blit::invert_region(fb, fill);
+    }
+}
+
+/// 1px line across the middle of its region: horizontal when the region is
+/// wider than it is tall, otherwise vertical
+pub struct Separator;
+impl Widget for Separator {
+    fn width(&self) -> usize {
+        SEPARATOR_H
+    }
+
+    fn height(&self) -> usize {
+        SEPARATOR_H
+    }
+
+    fn paint(&self, fb: &mut LcdFB, cr: ClipRegion) {
+        blit::clear_region(fb, cr);
+        let mut line = cr;
+        if cr.x1 - cr.x0 >= cr.y1 - cr.y0 {
+            line.y0 = (cr.y0 + cr.y1) >> 1;
+            line.y1 = line.y0 + 1;
+        } else {
+            line.x0 = (cr.x0 + cr.x1) >> 1;
+            line.x1 = line.x0 + 1;
+        }
+        blit::invert_region(fb, line);
+    }
+}
+
+/// Font for widget text
+pub fn font() -> Font {
+    Font::new(GlyphSet::Regular)
+}
+
+/// Height of a line of widget text
+pub fn line_h() -> usize {
+    fonts::max_height(GlyphSet::Regular)
+}
+
+/// Shrink a region by n px on each side
+fn inset(cr: ClipRegion, n: usize) -> ClipRegion {
+    ClipRegion {
+        x0: cr.x0 + n,
+        x1: core::cmp::max(cr.x1.saturating_sub(n), cr.x0 + n),
+        y0: cr.y0 + n,
+        y1: core::cmp::max(cr.y1.saturating_sub(n), cr.y0 + n),
+    }
+}
+
+/// Draw a 1px border just inside a clear region
+pub fn outline(fb: &mut LcdFB, cr: ClipRegion) {
+    blit::invert_region(fb, cr);
+    blit::invert_region(fb, inset(cr, 1));
+}
+
+/// Square marker region at the left of a row, centered vertically
+fn marker_region(row: ClipRegion) -> ClipRegion {
+    let y0 = row.y0 + ((row.y1 - row.y0).saturating_sub(MARKER_SIZE) >> 1);
+    ClipRegion {
+        x0: row.x0,
+        x1: core::cmp::min(row.x0 + MARKER_SIZE, row.x1),
+        y0,
+        y1: core::cmp::min(y0 + MARKER_SIZE, row.y1),
+    }
+}
+
+/// Blit a label to the right of a row's marker
+fn label_after_marker(fb: &mut LcdFB, row: ClipRegion, label: &str) {
+    let text_cr = ClipRegion {
+        x0: row.x0 + MARKER_SIZE + PAD,
+        ..row
+    };
+    blit::string_left(fb, text_cr, label, font());
+}
+
+/// XOR a filled disk of radius r centered at (cx, cy), clipped to cr
+fn xor_disk(fb: &mut LcdFB, cr: ClipRegion, cx: usize, cy: usize, r: usize) {
+    for dy in 0..r {
+        // Half width of the row at this distance from the center
+        let mut hw = r;
+        while hw * hw + dy * dy > r * r {
+            hw -= 1;
+        }
+        for y in [cy - dy - 1, cy + dy].iter() {
+            let row = ClipRegion {
+                x0: core::cmp::max(cx - hw, cr.x0),
+                x1: core::cmp::min(cx + hw, cr.x1),
+                y0: core::cmp::max(*y, cr.y0),
+                y1: core::cmp::min(*y + 1, cr.y1),
+            };
+            blit::invert_region(fb, row);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use blit::{LCD_FRAME_BUF_SIZE, LCD_LINES, LCD_PX_PER_LINE};
+
+    const SCREEN: ClipRegion = ClipRegion {
+        x0: 0,
+        x1: LCD_PX_PER_LINE,
+        y0: 0,
+        y1: LCD_LINES,
+    };
+
+    /// Count black pixels (0 bits) in a region of the frame buffer
+    fn black_px(fb: &LcdFB, cr: ClipRegion) -> usize {
+        let mut n = 0;
+        for y in cr.y0..cr.y1 {
+            for x in cr.x0..cr.x1 {
+                if (fb[y * blit::LCD_WORDS_PER_LINE + (x >> 5)] >> (x & 0x1f)) & 1 == 0 {
+                    n += 1;
+                }
+            }
+        }
+        n
+    }
+
+    #[test]
+    fn stack_takes_consecutive_regions() {
+        let mut v = Stack::vertical(SCREEN, 2);
+        let a = v.take(10);
+        let b = v.take(20);
+        assert_eq!((a.y0, a.y1, b.y0, b.y1), (0, 10, 12, 32));
+        let mut h = Stack::horizontal(b, 4);
+        let c = h.take(100);
+        assert_eq!((c.x0, c.x1, c.y0, c.y1), (0, 100, 12, 32));
+        let d = h.take(1000);
+        assert_eq!((d.x0, d.x1), (104, LCD_PX_PER_LINE));
+        assert_eq!(h.remaining().x0, h.remaining().x1);
+    }
+
+    #[test]
+    fn checked_checkbox_fills_marker() {
+        let mut fb: LcdFB = [0; LCD_FRAME_BUF_SIZE];
+        let mut cb = Checkbox {
+            label: "Wrap",
+            checked: false,
+        };
+        let cr = Stack::vertical(SCREEN, 0).take(cb.height());
+        cb.paint(&mut fb, cr);
+        let marker = marker_region(cr);
+        let empty = black_px(&fb, marker);
+        assert_eq!(empty, 4 * (MARKER_SIZE - 1));
+        cb.checked = true;
+        cb.paint(&mut fb, cr);
+        assert_eq!(black_px(&fb, marker), empty + 8 * 8);
+    }
+
+    #[test]
+    fn list_scrolls_to_selection() {
+        let items = ["a", "b", "c", "d", "e"];
+        let mut list = List {
+            items: &items,
+            selected: 0,
+            top: 0,
+            rows: 3,
+        };
+        while list.select_next() {}
+        assert_eq!((list.selected, list.top), (4, 2));
+        let mut fb: LcdFB = [0; LCD_FRAME_BUF_SIZE];
+        let cr = Stack::vertical(SCREEN, 0).take(list.height());
+        list.paint(&mut fb, cr);
+        // Selected row is last and inverted (mostly black)
+        let row = Stack::vertical(cr, 0).take(2 * line_h()).y1;
+        let last = ClipRegion { y0: row, ..cr };
+        assert!(black_px(&fb, last) > (cr.x1 - cr.x0) * line_h() / 2);
+        // Moving up within the visible rows does not scroll
+        list.select_prev();
+        assert_eq!((list.selected, list.top), (3, 2));
+    }
+
+    #[test]
+    fn progress_fill_is_proportional() {
+        let mut fb: LcdFB = [0; LCD_FRAME_BUF_SIZE];
+        let cr = ClipRegion {
+            x0: 10,
+            x1: 114,
+            y0: 0,
+            y1: PROGRESS_H,
+        };
+        ProgressBar { value: 1, max: 4 }.paint(&mut fb, cr);
+        let fill = inset(cr, 2);
+        assert_eq!(black_px(&fb, fill), 25 * (PROGRESS_H - 4));
+    }
+}
@@ -1,4 +1,6 @@
 //! UI Simulation for handheld device with LCD and switchable keyboard layouts
+use guilib::hid;
+use guilib::scancode::Scancode;
 use std::io::Read;
 use std::net::{TcpListener, TcpStream};
 use std::sync::mpsc;
@@ -56,15 +58,32 @@ fn event_loop(in_rx: EventLoopRx, in_tx: EventLoopTx, mqs_to_servers: &mut Vec<m
     let loopback = |msg| {
         let _ = in_tx.send(msg);
     };
+    let mut hid_keyboard = hid::HidKeyboard::new();
     for message in in_rx.iter() {
         match message {
             Message::LogError(msg) => println!("ERR: {}", msg),
             Message::LogInfo(msg) => println!("{}", msg),
             Message::KbdScanCode(sc) => {
-                // TODO: route to keyboard driver
-                loopback(Message::KbdUnicode(sc.clone()));
                 loopback(Message::RemoteTrace(format!("KbdScanCode {}", sc)));
                 println!("KbdScanCode: {}", sc);
+                loopback(Message::KbdKey(sc));
+            }
+            Message::KbdHidReport(report) => {
+                // An external USB keyboard reports its whole state, so
+                // compare it with the last report to find key changes
+                loopback(Message::RemoteTrace(format!(
+                    "KbdHidReport {:02x?}",
+                    report
+                )));
+                for ev in hid_keyboard.report(&report).iter().flatten() {
+                    loopback(Message::KbdKey(*ev));
+                }
+            }
+            Message::KbdKey(ev) => {
+                // TODO: route to keyboard driver
+                loopback(Message::KbdUnicode(ev.to_string()));
+                loopback(Message::RemoteTrace(format!("KbdKey {:?}", ev)));
+                println!("KbdKey: {:?} ({})", ev, ev);
             }
             Message::KbdUnicode(text) => {
                 // TODO: route to UI view controller
@@ -190,6 +209,8 @@ fn handle_request(mq: &mq::Mq, mut sse_rx: &mut SseRx, stream: TcpStream, reques
 ///   for (const k of ['P13p', P13r', 'P14p', 'P14r']) {
 ///      fetch('http://localhost:8000/io/kbd/scancode?'+k, {method: 'POST'});
 ///   }
+/// - USB HID endpoint works the same way, with a boot protocol keyboard report
+///   as 16 hex digits (shift+h is 02000b0000000000).
 fn handle_route(mut r: &mut http::Request, mut sse_rx: &mut SseRx) {
     match r.method {
         "HEAD" | "GET" => match r.path {
@@ -203,6 +224,7 @@ fn handle_route(mut r: &mut http::Request, mut sse_rx: &mut SseRx) {
         },
         "POST" => match (r.path, r.query) {
             ("/io/kbd/scancode", sc) => handle_io_scancode(&mut r, sc),
+            ("/io/kbd/hid", report) => handle_io_hid(r, report),
             _ => http::send_404(&mut r),
         },
         _ => http::send_404(&mut r),
@@ -218,10 +240,34 @@ fn handle_io_screen(mut r: &mut http::Request, mut sse_mq: &mut SseRx) {
 
 /// Handle POST request for a keyboard scancode
 fn handle_io_scancode(mut r: &mut http::Request, scancode: &str) {
-    if scancode.len() == 4 {
-        r.mq.kbd_scancode(scancode);
-        http::send_200(&mut r, http::TEXT_PLAIN, &"OK");
-    } else {
-        http::send_400(&mut r, &"Bad Scancode");
+    match Scancode::parse(scancode) {
+        Some(sc) => {
+            r.mq.kbd_scancode(sc);
+            http::send_200(&mut r, http::TEXT_PLAIN, &"OK");
+        }
+        None => http::send_400(&mut r, &"Bad Scancode"),
+    }
+}
+
+/// Handle POST request for a USB HID keyboard report
+fn handle_io_hid(r: &mut http::Request, hex: &str) {
+    match parse_hid_report(hex) {
+        Some(report) => {
+            r.mq.kbd_hid_report(report);
+            http::send_200(r, http::TEXT_PLAIN, "OK");
+        }
+        None => http::send_400(r, "Bad HID Report"),
+    }
+}
+
+/// Parse a boot protocol keyboard report from hex digits, 2 per byte
+fn parse_hid_report(hex: &str) -> Option<[u8; hid::REPORT_SIZE]> {
+    if hex.len() != 2 * hid::REPORT_SIZE || !hex.is_ascii() {
+        return None;
+    }
+    let mut report = [0; hid::REPORT_SIZE];
+    for (i, byte) in report.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).ok()?;
     }
+    Some(report)
 }
@@ -1,4 +1,6 @@
 //! Provides channel message queue communciation for coordinating threads
+use guilib::hid;
+use guilib::scancode::Scancode;
 use std::sync::mpsc::{Receiver, Sender};
 
 /// Message types
@@ -6,7 +8,9 @@ use std::sync::mpsc::{Receiver, Sender};
 pub enum Message {
     LogError(String),
     LogInfo(String),
-    KbdScanCode(String),
+    KbdScanCode(Scancode),
+    KbdHidReport([u8; hid::REPORT_SIZE]),
+    KbdKey(Scancode),
     KbdUnicode(String),
     RemoteTrace(String),
     RemoteTerm(String),
@@ -47,8 +51,12 @@ impl Mq {
         }
     }
     /// Send keyboard scancode message to keyboard scancode sink
-    pub fn kbd_scancode(&self, scancode: &str) {
-        self.send(Message::KbdScanCode(String::from(scancode)));
+    pub fn kbd_scancode(&self, scancode: Scancode) {
+        self.send(Message::KbdScanCode(scancode));
+    }
+    /// Send USB HID keyboard report message to keyboard scancode sink
+    pub fn kbd_hid_report(&self, report: [u8; hid::REPORT_SIZE]) {
+        self.send(Message::KbdHidReport(report));
     }
     /// Send string to the error log sink
     pub fn error(&self, message: &str) {
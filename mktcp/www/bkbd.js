@@ -66,15 +66,17 @@ function translate(keyCode) {
 //     P53  P54  P55_____P55____P55  P56  P57
 //
 const LookupTable = {
-    ArrowUp: 'P2_',
-    ArrowLeft: 'P5_',
-    Escape: 'PC_',
-    ArrowRight: 'P6_',
-    F1: 'P3_',
-    F2: 'P4_',
-    ArrowDown: 'P9_',
-    F3: 'P7_',
-    F4: 'P8_',
+    ArrowUp: 'P2',
+    ArrowLeft: 'P5',
+    Escape: 'PC',
+    ArrowRight: 'P6',
+    F1: 'P3',
+    F2: 'P4',
+    ShiftLeft: 'P43',
+    ShiftRight: 'P43',
+    ArrowDown: 'P9',
+    F3: 'P7',
+    F4: 'P8',
     Digit1: 'P13',
     Digit2: 'P14',
     Digit3: 'P15',
@@ -104,8 +106,7 @@ const LookupTable = {
     KeyJ: 'P39',
     KeyK: 'P40',
     KeyL: 'P41',
-    Enter: 'P42',
-    ShiftLeft: 'P43',
+    Backspace: 'P42',
     KeyZ: 'P44',
     KeyX: 'P45',
     KeyC: 'P46',
@@ -114,7 +115,7 @@ const LookupTable = {
     KeyN: 'P49',
     KeyM: 'P50',
     Slash: 'P51',
-    ShiftRight: 'P52',
+    Enter: 'P52',
     AltLeft: 'P53',
     MetaLeft: 'P54',
     Space: 'P55',
@@ -2,11 +2,21 @@
 extern crate guilib;
 extern crate trace;
 
-use guilib::state::{Context, FrameBuf};
+use guilib::state::{Battery, Context, FrameBuf, Radio};
 
 static mut FB: FrameBuf = FrameBuf::new();
 static mut CTX: Context = Context::new();
 
+/// Capacity in bytes of the buffer that javascript writes UTF-8 strings into
+const STR_BUF_SIZE: usize = 128;
+static mut STR_BUF: [u8; STR_BUF_SIZE] = [0; STR_BUF_SIZE];
+
+/// Get the first len bytes of the string buffer as a string slice
+fn str_buf(len: i32) -> &'static str {
+    let len = core::cmp::min(len.max(0) as usize, STR_BUF_SIZE);
+    core::str::from_utf8(unsafe { &STR_BUF[0..len] }).unwrap_or("")
+}
+
 /// For building wasm32 no_std, add panic handler and functions to let
 /// javascript check shared buffer pointers. This panic handler conflicts with
 /// test panic handler and therefore cannot be included during `cargo test`.
@@ -31,6 +41,12 @@ pub extern "C" fn lcd_clear_dirty() {
     unsafe { FB.clear_dirty() }
 }
 
+/// Advance UI timers (call every guilib::api::TICK_MS milliseconds)
+#[no_mangle]
+pub extern "C" fn tick() {
+    guilib::api::tick(unsafe { &mut FB }, unsafe { &mut CTX });
+}
+
 /// Step the UI demo animation
 #[no_mangle]
 pub extern "C" fn demo_tick() {
@@ -49,16 +65,119 @@ pub extern "C" fn keyup(key_index: i32) {
     guilib::api::keyup(unsafe { &mut FB }, unsafe { &mut CTX }, key_index as u32);
 }
 
-/// Substitute for using (non-existant) menu to select azerty keyboard layout
+/// Respond to a touch or pointer press at screen position x, y
+#[no_mangle]
+pub extern "C" fn touch_down(x: i32, y: i32) {
+    let (x, y) = (x.max(0) as u32, y.max(0) as u32);
+    guilib::api::touch_down(unsafe { &mut FB }, unsafe { &mut CTX }, x, y);
+}
+
+/// Respond to a pressed touch or pointer moving to screen position x, y
+#[no_mangle]
+pub extern "C" fn touch_move(x: i32, y: i32) {
+    let (x, y) = (x.max(0) as u32, y.max(0) as u32);
+    guilib::api::touch_move(unsafe { &mut FB }, unsafe { &mut CTX }, x, y);
+}
+
+/// Respond to a touch or pointer release at screen position x, y
+#[no_mangle]
+pub extern "C" fn touch_up(x: i32, y: i32) {
+    let (x, y) = (x.max(0) as u32, y.max(0) as u32);
+    guilib::api::touch_up(unsafe { &mut FB }, unsafe { &mut CTX }, x, y);
+}
+
+/// Respond to a touch or pointer that the browser cancelled
+#[no_mangle]
+pub extern "C" fn touch_cancel() {
+    guilib::api::touch_cancel(unsafe { &mut FB }, unsafe { &mut CTX });
+}
+
+/// Respond to a USB HID boot protocol keyboard report, from the first 8 bytes
+/// of the string buffer
+#[no_mangle]
+pub extern "C" fn hid_report() {
+    let mut report = [0; guilib::hid::REPORT_SIZE];
+    report.copy_from_slice(unsafe { &STR_BUF[..guilib::hid::REPORT_SIZE] });
+    guilib::api::hid_report(unsafe { &mut FB }, unsafe { &mut CTX }, &report);
+}
+
+/// Key index of the key named by len bytes of the string buffer (like "P13"),
+/// or -1 if there is no such key
+#[no_mangle]
+pub extern "C" fn key_index(len: i32) -> i32 {
+    match guilib::scancode::Key::from_name(str_buf(len)) {
+        Some(key) => key.index() as i32,
+        None => -1,
+    }
+}
+
+/// Index of the current keyboard map, for choosing the on-screen keyboard
+/// overlay (layouts are selected from the settings view)
+#[no_mangle]
+pub extern "C" fn kbd_map_index() -> i32 {
+    guilib::api::kbd_map_index(unsafe { &CTX })
+}
+
+/// Copy the name of the current keyboard layout into the string buffer,
+/// returning its length in bytes
+#[no_mangle]
+pub extern "C" fn kbd_layout_name() -> i32 {
+    let name = guilib::api::kbd_layout_name(unsafe { &CTX }).as_bytes();
+    let len = core::cmp::min(name.len(), STR_BUF_SIZE);
+    unsafe { STR_BUF[..len].copy_from_slice(&name[..len]) };
+    len as i32
+}
+
+/// Set the home screen title from len bytes of the string buffer
+#[no_mangle]
+pub extern "C" fn set_title(len: i32) {
+    guilib::api::set_title(unsafe { &mut FB }, unsafe { &mut CTX }, str_buf(len));
+}
+
+/// Set the status bar clock ("HH:MM") from len bytes of the string buffer
+#[no_mangle]
+pub extern "C" fn set_time(len: i32) {
+    guilib::api::set_time(unsafe { &mut FB }, unsafe { &mut CTX }, str_buf(len));
+}
+
+/// Set the home screen placeholder text from len bytes of the string buffer
+#[no_mangle]
+pub extern "C" fn set_note(len: i32) {
+    guilib::api::set_note(unsafe { &mut FB }, unsafe { &mut CTX }, str_buf(len));
+}
+
+/// Set the battery level (0-100 percent) and charging state (0=>no, _=>yes)
+#[no_mangle]
+pub extern "C" fn set_battery(percent: i32, charging: i32) {
+    let (fb, ctx) = unsafe { (&mut FB, &mut CTX) };
+    let level = Battery::from_percent(percent.max(0) as u32);
+    guilib::api::set_battery(fb, ctx, level, charging != 0);
+}
+
+/// Set the radio signal strength in bars (0-3, negative for radio off)
+#[no_mangle]
+pub extern "C" fn set_radio(bars: i32) {
+    let level = Radio::from_bars(bars);
+    guilib::api::set_radio(unsafe { &mut FB }, unsafe { &mut CTX }, level);
+}
+
+/// Set key auto-repeat delay and interval in milliseconds (delay 0 => off)
+#[no_mangle]
+pub extern "C" fn set_key_repeat(delay_ms: i32, interval_ms: i32) {
+    let (delay_ms, interval_ms) = (delay_ms.max(0) as u32, interval_ms.max(0) as u32);
+    guilib::api::set_key_repeat(unsafe { &mut CTX }, delay_ms, interval_ms);
+}
+
+/// Export pointer to string buffer shared memory for javascript + wasm32
 #[no_mangle]
-pub extern "C" fn set_layout_azerty() {
-    guilib::api::kbd_set_layout_azerty(unsafe { &mut FB }, unsafe { &mut CTX });
+pub extern "C" fn str_buf_ptr() -> *const u8 {
+    unsafe { STR_BUF.as_ptr() }
 }
 
-/// Substitute for using (non-existant) menu to select qwerty keyboard layout
+/// Export capacity of the string buffer
 #[no_mangle]
-pub extern "C" fn set_layout_qwerty() {
-    guilib::api::kbd_set_layout_qwerty(unsafe { &mut FB }, unsafe { &mut CTX });
+pub extern "C" fn str_buf_size() -> i32 {
+    STR_BUF_SIZE as i32
 }
 
 /// Export pointer to frame buffer shared memory for javascript + wasm32
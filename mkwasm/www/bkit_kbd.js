@@ -98,6 +98,8 @@ const KeyCodeLookupTable = {
     ArrowRight: 'P6',
     F1: 'P3',
     F2: 'P4',
+    ShiftLeft: 'P43',
+    ShiftRight: 'P43',
     ArrowDown: 'P9',
     F3: 'P7',
     F4: 'P8',
@@ -130,8 +132,7 @@ const KeyCodeLookupTable = {
     KeyJ: 'P39',
     KeyK: 'P40',
     KeyL: 'P41',
-    Enter: 'P42',
-    ShiftLeft: 'P43',
+    Backspace: 'P42',
     KeyZ: 'P44',
     KeyX: 'P45',
     KeyC: 'P46',
@@ -140,7 +141,7 @@ const KeyCodeLookupTable = {
     KeyN: 'P49',
     KeyM: 'P50',
     Slash: 'P51',
-    ShiftRight: 'P52',
+    Enter: 'P52',
     AltLeft: 'P53',
     MetaLeft: 'P54',
     ControlLeft: 'P54',
@@ -154,17 +155,11 @@ const KeyCodeLookupTable = {
 
 // Stuff below this line is for clickable SVG on-screen keyboard
 
-// Replace contents of svgElement with Azerty on-screen keyboard
-export function showAzertyOSK(svgElement) {
+// Replace contents of svgElement with the on-screen keyboard for a layout
+// name from guilib (unknown layouts get the Qwerty key caps)
+export function showOSK(svgElement, layout) {
     pruneSVG(svgElement);
-    let osk = buildAzertyOSK();
-    svgElement.appendChild(osk);
-}
-
-// Replace contents of svgElement with a Qwerty on-screen keyboard
-export function showQwertyOSK(svgElement) {
-    pruneSVG(svgElement);
-    let osk = buildQwertyOSK();
+    let osk = buildOSK(OSKLabels[layout] || QwertyKeys);
     svgElement.appendChild(osk);
 }
 
@@ -178,30 +173,13 @@ function pruneSVG(svgElement) {
 // SVG namespace is required to make document.createElementNS work for SVG
 const SVG_NS = 'http://www.w3.org/2000/svg';
 
-// Return an svg group with Azerty key rectangles and labels
-function buildAzertyOSK() {
-    let g = document.createElementNS(SVG_NS, 'g');
-    for (const [keyId, col, row, width] of keyBoxBounds) {
-        let r = keyRect(keyId, col, row, width);
-        g.appendChild(r);
-        let labels = AzertyKeys[keyId];
-        appendKeyLabels(g, labels, col, row, width);
-        // Power button icons need special handling
-        if (['P3', 'P8'].includes(keyId) /*F1,F4*/) {
-            appendPowerIcon(g, keyId, col, row, width);
-        }
-    }
-    return g;
-}
-
-// Return an svg group with Qwerty key rectangles and labels
-function buildQwertyOSK() {
+// Return an svg group with key rectangles and labels from a key caps table
+function buildOSK(keyLabels) {
     let g = document.createElementNS(SVG_NS, 'g');
     for (const [keyId, col, row, width] of keyBoxBounds) {
         let r = keyRect(keyId, col, row, width);
         g.appendChild(r);
-        g.appendChild(r);
-        let labels = QwertyKeys[keyId];
+        let labels = keyLabels[keyId];
         appendKeyLabels(g, labels, col, row, width);
         // Power button icons need special handling
         if (['P3', 'P8'].includes(keyId) /*F1,F4*/) {
@@ -358,8 +336,8 @@ const AzertyKeys = {
     'P7': ['F3', '', ''],
     'P8': ['F4', '', 'Ctrl'],
 
-    'P13': ['1', 'à', '§'],
-    'P14': ['2', 'é'],
+    'P13': ['1', 'à'],
+    'P14': ['2', 'é', '§'],
     'P15': ['3', 'è'],
     'P16': ['4', 'ê'],
     'P17': ['5', '(', '['],
@@ -391,21 +369,21 @@ const AzertyKeys = {
     'P41': ['l', '|'],
     'P42': ['m', '#'],
 
-    'P43': ['', '⌫'],
+    'P43': ['⇧', '⇧'],
     'P44': ['w', '<'],
     'P45': ['x', '>'],
     'P46': ['c', 'ç'],
     'P47': ['v', '^'],
     'P48': ['b', '='],
     'P49': ['n', '~'],
-    'P50': [':', '¿', '?'],
+    'P50': [',', '¿', '?'],
     'P51': [';', '¡', '!'],
-    'P52': ['', '⏎'],
+    'P52': [':', '⏎'],
 
     'P53': ['', '', '↑'],
-    'P54': [',', 'SYM'],
+    'P54': ['⌫', 'SYM'],
     'P55': ['', '', ''],
-    'P56': ['.', '㋡'],
+    'P56': ['⏎', '㋡'],
     'P57': ['', '↑', ''],
 };
 
@@ -455,7 +433,7 @@ const QwertyKeys = {
     'P41': ['l', ')'],
     'P42': ['⌫', ''],
 
-    'P43': ['!', '`'],
+    'P43': ['⇧', '`'],
     'P44': ['z', '_'],
     'P45': ['x', '$'],
     'P46': ['c', '"'],
@@ -473,6 +451,204 @@ const QwertyKeys = {
     'P57': ['', '↑'],
 };
 
+const QwertzKeys = {
+    'P2': ['', ''],
+    'P5': ['', ''],
+    'PC': ['', ''],
+    'P6': ['', ''],
+    'P9': ['', ''],
+
+    'P3': ['F1', 'Tab'],
+    'P4': ['F2', ''],
+    'P7': ['F3', ''],
+    'P8': ['F4', 'Ctrl'],
+
+    'P13': ['1', ''],
+    'P14': ['2', '²'],
+    'P15': ['3', '³'],
+    'P16': ['4', '¼'],
+    'P17': ['5', '½'],
+    'P18': ['6', '¬'],
+    'P19': ['7', '{'],
+    'P20': ['8', '['],
+    'P21': ['9', ']'],
+    'P22': ['0', '}'],
+
+    'P23': ['q', '@'],
+    'P24': ['w', '\''],
+    'P25': ['e', '€'],
+    'P26': ['r', '#'],
+    'P27': ['t', '+'],
+    'P28': ['z', '*'],
+    'P29': ['u', '~'],
+    'P30': ['i', '^'],
+    'P31': ['o', '`'],
+    'P32': ['p', '°'],
+
+    'P33': ['a', '<'],
+    'P34': ['s', 'ß'],
+    'P35': ['d', '|'],
+    'P36': ['f', '\\'],
+    'P37': ['g', '-'],
+    'P38': ['h', '_'],
+    'P39': ['j', '´'],
+    'P40': ['k', '«'],
+    'P41': ['l', '»'],
+    'P42': ['ö', '©'],
+
+    'P43': ['⇧', '>'],
+    'P44': ['y', '£'],
+    'P45': ['x', '¥'],
+    'P46': ['c', '¢'],
+    'P47': ['v', '¤'],
+    'P48': ['b', '?'],
+    'P49': ['n', ';'],
+    'P50': ['m', ':'],
+    'P51': ['ä', ','],
+    'P52': ['ü', '.'],
+
+    'P53': ['', '↑'],
+    'P54': ['⌫', 'SYM'],
+    'P55': ['', ''],
+    'P56': ['⏎', '㋡'],
+    'P57': ['', '↑'],
+};
+
+const DvorakKeys = {
+    'P2': ['', ''],
+    'P5': ['', ''],
+    'PC': ['', ''],
+    'P6': ['', ''],
+    'P9': ['', ''],
+
+    'P3': ['F1', 'Tab'],
+    'P4': ['F2', ''],
+    'P7': ['F3', ''],
+    'P8': ['F4', 'Ctrl'],
+
+    'P13': ['1', ''],
+    'P14': ['2', '!'],
+    'P15': ['3', '?'],
+    'P16': ['4', '€'],
+    'P17': ['5', '£'],
+    'P18': ['6', '¥'],
+    'P19': ['7', '¢'],
+    'P20': ['8', '§'],
+    'P21': ['9', '«'],
+    'P22': ['0', '»'],
+
+    'P23': ['\'', '%'],
+    'P24': [',', '^'],
+    'P25': ['.', '~'],
+    'P26': ['p', '|'],
+    'P27': ['y', '['],
+    'P28': ['f', ']'],
+    'P29': ['g', '<'],
+    'P30': ['c', '>'],
+    'P31': ['r', '{'],
+    'P32': ['l', '}'],
+
+    'P33': ['a', '@'],
+    'P34': ['o', '#'],
+    'P35': ['e', '&'],
+    'P36': ['u', '*'],
+    'P37': ['i', '-'],
+    'P38': ['d', '+'],
+    'P39': ['h', '='],
+    'P40': ['t', '('],
+    'P41': ['n', ')'],
+    'P42': ['s', '/'],
+
+    'P43': ['⇧', '`'],
+    'P44': ['q', '_'],
+    'P45': ['j', '$'],
+    'P46': ['k', '"'],
+    'P47': ['x', '\''],
+    'P48': ['b', ':'],
+    'P49': ['m', ';'],
+    'P50': ['w', '¿'],
+    'P51': ['v', '¡'],
+    'P52': ['z', '\\'],
+
+    'P53': ['', '↑'],
+    'P54': ['⌫', 'SYM'],
+    'P55': ['', ''],
+    'P56': ['⏎', '㋡'],
+    'P57': ['', '↑'],
+};
+
+const ColemakKeys = {
+    'P2': ['', ''],
+    'P5': ['', ''],
+    'PC': ['', ''],
+    'P6': ['', ''],
+    'P9': ['', ''],
+
+    'P3': ['F1', 'Tab'],
+    'P4': ['F2', ''],
+    'P7': ['F3', ''],
+    'P8': ['F4', 'Ctrl'],
+
+    'P13': ['1', ''],
+    'P14': ['2', '€'],
+    'P15': ['3', '£'],
+    'P16': ['4', '¥'],
+    'P17': ['5', '¢'],
+    'P18': ['6', '§'],
+    'P19': ['7', '°'],
+    'P20': ['8', '«'],
+    'P21': ['9', '»'],
+    'P22': ['0', '¿'],
+
+    'P23': ['q', '%'],
+    'P24': ['w', '^'],
+    'P25': ['f', '~'],
+    'P26': ['p', '|'],
+    'P27': ['g', '['],
+    'P28': ['j', ']'],
+    'P29': ['l', '<'],
+    'P30': ['u', '>'],
+    'P31': ['y', '{'],
+    'P32': ['o', '}'],
+
+    'P33': ['a', '@'],
+    'P34': ['r', '#'],
+    'P35': ['s', '&'],
+    'P36': ['t', '*'],
+    'P37': ['d', '-'],
+    'P38': ['h', '+'],
+    'P39': ['n', '='],
+    'P40': ['e', '('],
+    'P41': ['i', ')'],
+    'P42': ['⌫', ''],
+
+    'P43': ['⇧', '`'],
+    'P44': ['z', '_'],
+    'P45': ['x', '$'],
+    'P46': ['c', '"'],
+    'P47': ['v', '\''],
+    'P48': ['b', ':'],
+    'P49': ['k', ';'],
+    'P50': ['m', '/'],
+    'P51': ['?', '\\'],
+    'P52': ['⏎', ''],
+
+    'P53': ['', '↑'],
+    'P54': [',', 'SYM'],
+    'P55': ['', ''],
+    'P56': ['.', '㋡'],
+    'P57': ['', '↑'],
+};
+
+// Key caps tables by guilib layout name
+const OSKLabels = {
+    Azerty: AzertyKeys,
+    Colemak: ColemakKeys,
+    Dvorak: DvorakKeys,
+    Qwerty: QwertyKeys,
+    Qwertz: QwertzKeys,
+};
+
 // Key location list format: [Id, Column, Row, Width]
 const keyBoxBounds = [
     ['P2', 4.5, 0, 1], // Up
@@ -3,12 +3,14 @@ import * as wasm from './mkwasm_wasm.js';
 import * as kbd from './bkit_kbd.js';
 
 const backlightBtn = document.querySelector('#backlightBtn');
-const kbdSelect = document.querySelector('#kbdSelect');
 const demoModeBtn = document.querySelector('#demoMode');
 const keyboard = document.querySelector('#keyboard');
 const screen = document.querySelector('#screen');
 const screenCtx = screen.getContext('2d');
 
+// Milliseconds between UI timer ticks (matches guilib::api::TICK_MS)
+const TICK_MS = 100;
+
 // Load wasm module with callback to continue initialization
 let loadSuccessCallback = initialize;
 wasm.loadModule(loadSuccessCallback);
@@ -33,10 +35,6 @@ function initialize() {
             demoModeBtn.classList.remove('on');
         } else {
             demoModeBtn.classList.add('on');
-            kbdSelect.value = 'Qwerty';
-            kbd.showQwertyOSK(keyboard);
-            wasm.setLayoutQwerty();
-            repaintLCD();
             recurringDemoTick();
         }
         demoModeBtn.blur();
@@ -53,25 +51,88 @@ function initialize() {
     };
     kbd.setKeyscanCallbacks(pressFn, releaseFn);
     kbd.addKeyboardListener(document);
-    kbd.showAzertyOSK(keyboard);
-    kbdSelect.addEventListener('change', e => {
-        if (e.target.value === 'Azerty') {
-            kbd.showAzertyOSK(keyboard);
-            wasm.setLayoutAzerty();
-            repaintLCD();
-        } else if (e.target.value === 'Qwerty') {
-            kbd.showQwertyOSK(keyboard);
-            wasm.setLayoutQwerty();
-            repaintLCD();
-        }
-        kbdSelect.blur();
-    });
 
     // Initialize LCD screen
     screen.height = 536;
     screen.width = 336;
+
+    // Let the screen be clicked or touched like a touch panel
+    addTouchListeners(screen);
     wasm.init();
+    syncClock();
+    syncBattery();
     repaintLCD();
+
+    // Drive UI timers (toasts, etc.) at the rate guilib expects
+    setInterval(() => {
+        wasm.tick();
+        repaintLCD();
+    }, TICK_MS);
+
+    // Keep the status bar clock in step with the local time
+    setInterval(() => {
+        syncClock();
+        repaintLCD();
+    }, 1000);
+}
+
+// Send pointer events on the screen to guilib in screen pixel coordinates
+function addTouchListeners(element) {
+    let toScreen = e => {
+        let rect = element.getBoundingClientRect();
+        let x = Math.floor((e.clientX - rect.left) * element.width / rect.width);
+        let y = Math.floor((e.clientY - rect.top) * element.height / rect.height);
+        return [x, y];
+    };
+    element.addEventListener('pointerdown', e => {
+        element.setPointerCapture(e.pointerId);
+        wasm.touchDown(...toScreen(e));
+        repaintLCD();
+    });
+    element.addEventListener('pointermove', e => {
+        if (element.hasPointerCapture(e.pointerId)) {
+            wasm.touchMove(...toScreen(e));
+            repaintLCD();
+        }
+    });
+    element.addEventListener('pointerup', e => {
+        wasm.touchUp(...toScreen(e));
+        repaintLCD();
+    });
+    // A touch the browser takes over (to scroll, etc.) must not leave a key
+    // held down and repeating
+    element.addEventListener('pointercancel', e => {
+        wasm.touchCancel();
+        repaintLCD();
+    });
+}
+
+// Show the local time ("HH:MM") in the status bar clock if it changed
+let clockText = null;
+function syncClock() {
+    let now = new Date();
+    let pad = n => String(n).padStart(2, '0');
+    let text = pad(now.getHours()) + ':' + pad(now.getMinutes());
+    if (text !== clockText) {
+        clockText = text;
+        wasm.setTime(text);
+    }
+}
+
+// Show the host battery level in the status bar, where the browser offers it
+function syncBattery() {
+    if (!('getBattery' in navigator)) {
+        return;
+    }
+    navigator.getBattery().then(battery => {
+        let update = () => {
+            wasm.setBattery(Math.round(battery.level * 100), battery.charging);
+            repaintLCD();
+        };
+        battery.addEventListener('levelchange', update);
+        battery.addEventListener('chargingchange', update);
+        update();
+    });
 }
 
 // Send a demo tick event and conditionally schedule the next one
@@ -84,11 +145,23 @@ function recurringDemoTick() {
     }
 }
 
+// Show the on-screen keyboard for the layout picked in the settings view
+let oskLayout = null;
+function syncOSK() {
+    let layout = wasm.kbdLayoutName();
+    if (layout === oskLayout) {
+        return;
+    }
+    oskLayout = layout;
+    kbd.showOSK(keyboard, layout);
+}
+
 // Paint the frame buffer (wasm shared memory) to the screen (canvas element)
 function repaintLCD() {
     if (!wasm.lcdDirty()) {
         return;
     }
+    syncOSK();
     wasm.lcdClearDirty();
     let lcdData = wasm.lcdFrameBuf();
     let imageData = screenCtx.getImageData(0, 0, screen.width, screen.height);
@@ -120,15 +193,3 @@ function repaintLCD() {
     }
     screenCtx.putImageData(imageData, 0, 0);
 }
-
-// Keyboard overlay index to rom function lookup table
-const KbdOverlay = [
-    'kAzerty',
-    'kAzertyS',
-    'kAzertyAltL',
-    'kAzertyAltR',
-    'kAzertyAltRS',
-    'kQwerty',
-    'kQwertyS',
-    'kQwertyAlt',
-];
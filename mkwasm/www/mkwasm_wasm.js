@@ -29,8 +29,9 @@ var wasmShared;
 var wasmExports;
 var wasmInstanceReady = false;
 
-// UTF8 decoder
+// UTF8 decoder and encoder
 let decoder = new TextDecoder();
+let encoder = new TextEncoder();
 
 // Callback to initialize shared memory IPC bindings once WASM module is instantiated
 function initSharedMemBindings(result) {
@@ -55,22 +56,57 @@ export function demoTick() {
     wasmExports.demo_tick();
 }
 
+export function tick() {
+    wasmExports.tick();
+}
+
+// Key index of a key name like "P13" (guilib owns the key table), or -1
+function keyIndex(keyCode) {
+    return wasmExports.key_index(putStr(keyCode));
+}
+
 export function keydown(keyCode) {
-    if (!wasmInstanceReady) {throw "wasm instance is not ready";}
-    let kci = KeyCodeIndex[keyCode];
-    if (kci && kci >= 0) {
-        wasmExports.keydown(KeyCodeIndex[keyCode]);
+    let kci = keyIndex(keyCode);
+    if (kci >= 0) {
+        wasmExports.keydown(kci);
     }
 }
 
 export function keyup(keyCode) {
-    if (!wasmInstanceReady) {throw "wasm instance is not ready";}
-    let kci = KeyCodeIndex[keyCode];
-    if (kci && kci >= 0) {
-        wasmExports.keyup(KeyCodeIndex[keyCode]);
+    let kci = keyIndex(keyCode);
+    if (kci >= 0) {
+        wasmExports.keyup(kci);
     }
 }
 
+export function touchDown(x, y) {
+    if (!wasmInstanceReady) {throw "wasm instance is not ready";}
+    wasmExports.touch_down(x, y);
+}
+
+export function touchMove(x, y) {
+    if (!wasmInstanceReady) {throw "wasm instance is not ready";}
+    wasmExports.touch_move(x, y);
+}
+
+export function touchUp(x, y) {
+    if (!wasmInstanceReady) {throw "wasm instance is not ready";}
+    wasmExports.touch_up(x, y);
+}
+
+export function touchCancel() {
+    if (!wasmInstanceReady) {throw "wasm instance is not ready";}
+    wasmExports.touch_cancel();
+}
+
+// Forward a USB HID boot protocol keyboard report (8 bytes)
+export function hidReport(report) {
+    if (!wasmInstanceReady) {throw "wasm instance is not ready";}
+    let start = wasmExports.str_buf_ptr();
+    wasmShared.set(report.slice(0, 8), start);
+    wasmExports.hid_report();
+}
+
 export function lcdFrameBuf() {
     if (!wasmInstanceReady) {throw "wasm instance is not ready";}
     const lcdLines = 536;
@@ -87,70 +123,51 @@ export function lcdFrameBuf() {
     };
 }
 
-export function setLayoutAzerty() {
+export function kbdMapIndex() {
+    if (!wasmInstanceReady) {throw "wasm instance is not ready";}
+    return wasmExports.kbd_map_index();
+}
+
+// Name of the current keyboard layout, like "Qwerty"
+export function kbdLayoutName() {
+    if (!wasmInstanceReady) {throw "wasm instance is not ready";}
+    let len = wasmExports.kbd_layout_name();
+    let start = wasmExports.str_buf_ptr();
+    return decoder.decode(wasmShared.subarray(start, start + len));
+}
+
+// Copy a string into the wasm string buffer as UTF-8, returning its length
+function putStr(text) {
+    if (!wasmInstanceReady) {throw "wasm instance is not ready";}
+    let start = wasmExports.str_buf_ptr();
+    let size = wasmExports.str_buf_size();
+    let dest = wasmShared.subarray(start, start + size);
+    return encoder.encodeInto(text, dest).written;
+}
+
+export function setTitle(text) {
+    wasmExports.set_title(putStr(text));
+}
+
+export function setTime(text) {
+    wasmExports.set_time(putStr(text));
+}
+
+export function setNote(text) {
+    wasmExports.set_note(putStr(text));
+}
+
+export function setBattery(percent, charging) {
     if (!wasmInstanceReady) {throw "wasm instance is not ready";}
-    wasmExports.set_layout_azerty();
+    wasmExports.set_battery(percent, charging ? 1 : 0);
 }
 
-export function setLayoutQwerty() {
+export function setRadio(bars) {
     if (!wasmInstanceReady) {throw "wasm instance is not ready";}
-    wasmExports.set_layout_qwerty();
-}
-
-// Lookup table to translate from keycode to u8
-const KeyCodeIndex = {
-    P2: 0,
-    P5: 1,
-    PC: 2,
-    P6: 3,
-    P3: 4,
-    P4: 5,
-    P9: 6,
-    P7: 7,
-    P8: 8,
-    P13: 9,
-    P14: 10,
-    P15: 11,
-    P16: 12,
-    P17: 13,
-    P18: 14,
-    P19: 15,
-    P20: 16,
-    P21: 17,
-    P22: 18,
-    P23: 19,
-    P24: 20,
-    P25: 21,
-    P26: 22,
-    P27: 23,
-    P28: 24,
-    P29: 25,
-    P30: 26,
-    P31: 27,
-    P32: 28,
-    P33: 29,
-    P34: 30,
-    P35: 31,
-    P36: 32,
-    P37: 33,
-    P38: 34,
-    P39: 35,
-    P40: 36,
-    P41: 37,
-    P42: 38,
-    P43: 39,
-    P44: 40,
-    P45: 41,
-    P46: 42,
-    P47: 43,
-    P48: 44,
-    P49: 45,
-    P50: 46,
-    P51: 47,
-    P52: 48,
-    P53: 49,
-    P54: 50,
-    P55: 51,
-    P56: 52,
-    P57: 53,
-};
+    wasmExports.set_radio(bars);
+}
+
+export function setKeyRepeat(delayMs, intervalMs) {
+    if (!wasmInstanceReady) {throw "wasm instance is not ready";}
+    wasmExports.set_key_repeat(delayMs, intervalMs);
+}